@@ -214,6 +214,38 @@ async fn test_fn_arrow_cast() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fn_arrow_cast_to_type_of() -> Result<()> {
+    // the type of `a` is known while planning, before `arrow_typeof` is evaluated
+    let expr = arrow_cast(col("b"), arrow_typeof(col("a"))).alias("b");
+    let df = create_test_table().await?.select(vec![expr])?;
+    assert_eq!(df.schema().field(0).data_type(), &DataType::Utf8);
+
+    // the output of `arrow_typeof` is a plain string column
+    let type_of = create_test_table()
+        .await?
+        .select(vec![arrow_typeof(col("a"))])?;
+    assert!(type_of.schema().field(0).metadata().is_empty());
+
+    let batches = df.collect().await?;
+
+    assert_snapshot!(
+        batches_to_string(&batches),
+        @r"
+    +-----+
+    | b   |
+    +-----+
+    | 1   |
+    | 10  |
+    | 10  |
+    | 100 |
+    +-----+
+    "
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_nvl() -> Result<()> {
     let lit_null = lit(ScalarValue::Utf8(None));
@@ -1285,6 +1317,63 @@ async fn test_fn_map() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fn_map_to_struct() -> Result<()> {
+    let keys = |keys: &[&str]| {
+        let keys = keys
+            .iter()
+            .map(|k| ScalarValue::from(*k))
+            .collect::<Vec<_>>();
+        lit(ScalarValue::List(ScalarValue::new_list_nullable(
+            &keys,
+            &DataType::Utf8,
+        )))
+    };
+    let test_map = || {
+        map(
+            vec![lit("a"), lit("b"), lit("c")],
+            vec![lit(1), lit(2), lit(3)],
+        )
+    };
+
+    // extract a subset of the keys
+    let expr = map_to_struct(test_map(), keys(&["c", "a"])).alias("s");
+    let batches = get_batches_with_limit(expr, 1).await?;
+
+    assert_snapshot!(
+        batches_to_string(&batches),
+        @r"
+    +--------------+
+    | s            |
+    +--------------+
+    | {c: 3, a: 1} |
+    +--------------+
+    "
+    );
+
+    // a key that is never present gives an all-null field
+    let expr = get_field(map_to_struct(test_map(), keys(&["a", "z"])), "z")
+        .is_null()
+        .alias("z_is_null");
+    let batches = get_batches(expr).await?;
+
+    assert_snapshot!(
+        batches_to_string(&batches),
+        @r"
+    +-----------+
+    | z_is_null |
+    +-----------+
+    | true      |
+    | true      |
+    | true      |
+    | true      |
+    +-----------+
+    "
+    );
+
+    Ok(())
+}
+
 /// Call count wildcard from dataframe API
 #[tokio::test]
 async fn test_count_wildcard() -> Result<()> {
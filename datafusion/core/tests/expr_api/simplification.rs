@@ -524,6 +524,27 @@ fn multiple_now() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn coalesce_truncated_after_non_null_literal() -> Result<()> {
+    let table_scan = test_table_scan();
+    let proj = vec![coalesce(vec![col("e"), lit(0u32), col("d")])];
+    let plan = LogicalPlanBuilder::from(table_scan)
+        .project(proj)?
+        .build()?;
+
+    // d is never returned, as the literal is not NULL
+    let config = OptimizerContext::new();
+    let optimizer = Optimizer::with_rules(vec![Arc::new(SimplifyExpressions::new())]);
+    let optimized_plan = optimizer.optimize(plan, &config, |_, _| {})?;
+    assert_eq!(
+        format!("{optimized_plan}"),
+        "Projection: coalesce(test.e, UInt32(0)) AS coalesce(test.e,UInt32(0),test.d)\
+            \n  TableScan: test"
+    );
+    assert!(!optimized_plan.schema().field(0).is_nullable());
+    Ok(())
+}
+
 // ------------------------------
 // --- Simplifier tests -----
 // ------------------------------
@@ -745,6 +766,94 @@ fn test_simplify_concat() -> Result<()> {
     test_simplify(expr, expected);
     Ok(())
 }
+#[test]
+fn test_simplify_get_field_of_named_struct() {
+    // get_field(named_struct('a', 1, 'b', c3), 'a') ===> 1
+    let expr = get_field(
+        named_struct(vec![lit("a"), lit(1), lit("b"), col("c3")]),
+        "a",
+    );
+    test_simplify(expr, lit(1));
+
+    // get_field(named_struct('a', 1, 'b', c3), 'b') ===> c3
+    let expr = get_field(
+        named_struct(vec![lit("a"), lit(1), lit("b"), col("c3")]),
+        "b",
+    );
+    test_simplify(expr, col("c3"));
+
+    // get_field(get_field(named_struct('a', named_struct('b', c1)), 'a'), 'b') ===> c1
+    let expr = get_field(
+        get_field(
+            named_struct(vec![lit("a"), named_struct(vec![lit("b"), col("c1")])]),
+            "a",
+        ),
+        "b",
+    );
+    test_simplify(expr, col("c1"));
+}
+
+#[test]
+fn test_simplify_nvl2_literal() {
+    // nvl2(1, c3, c3_non_null) ===> c3
+    let expr = nvl2(lit(1), col("c3"), col("c3_non_null"));
+    test_simplify(expr, col("c3"));
+
+    // nvl2(NULL, c3, c3_non_null) ===> c3_non_null
+    let expr = nvl2(lit(ScalarValue::Int64(None)), col("c3"), col("c3_non_null"));
+    test_simplify(expr, col("c3_non_null"));
+
+    // nvl2(c1, c3, c3_non_null) is only known at execution
+    let expr = nvl2(col("c1"), col("c3"), col("c3_non_null"));
+    test_simplify(expr.clone(), expr);
+}
+
+#[test]
+fn test_simplify_greatest_least_dominated_args() {
+    // c4 is a UInt32, which can't beat 5000000000, so it is not evaluated
+    // greatest(CAST(c4 AS Int64), 5000000000, c3) ===> greatest(5000000000, c3)
+    let expr = greatest(vec![
+        cast(col("c4"), DataType::Int64),
+        lit(5_000_000_000i64),
+        col("c3"),
+    ]);
+    test_simplify(expr, greatest(vec![lit(5_000_000_000i64), col("c3")]));
+
+    // greatest(CAST(c4 AS Int64), 5000000000) ===> 5000000000
+    let expr = greatest(vec![
+        cast(col("c4"), DataType::Int64),
+        lit(5_000_000_000i64),
+    ]);
+    test_simplify(expr, lit(5_000_000_000i64));
+
+    // least(CAST(c4 AS Int64), NULL, -1) ===> -1
+    let expr = least(vec![
+        cast(col("c4"), DataType::Int64),
+        lit(ScalarValue::Int64(None)),
+        lit(-1i64),
+    ]);
+    test_simplify(expr, lit(-1i64));
+
+    // c4 can be greater than 100
+    let expr = greatest(vec![cast(col("c4"), DataType::Int64), lit(100i64)]);
+    test_simplify(expr.clone(), expr);
+}
+
+#[test]
+fn test_simplify_coalesce_non_null_argument() {
+    // coalesce(c3, 0, c3_non_null) ===> coalesce(c3, 0)
+    let expr = coalesce(vec![col("c3"), lit(0i64), col("c3_non_null")]);
+    test_simplify(expr, coalesce(vec![col("c3"), lit(0i64)]));
+
+    // coalesce(c3_non_null, c3) ===> c3_non_null
+    let expr = coalesce(vec![col("c3_non_null"), col("c3")]);
+    test_simplify(expr, col("c3_non_null"));
+
+    // NULL literals don't stop the arguments
+    let expr = coalesce(vec![col("c3"), lit(ScalarValue::Int64(None)), col("c3")]);
+    test_simplify(expr.clone(), expr);
+}
+
 #[test]
 fn test_simplify_cycles() {
     // cast(now() as int64) < cast(to_timestamp(0) as int64) + i64::MAX
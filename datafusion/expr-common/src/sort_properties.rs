@@ -174,3 +174,24 @@ impl ExprProperties {
         self
     }
 }
+
+/// Returns whether casting values of type `from` to type `to` keeps their
+/// relative order, so that casting an ordered expression yields an expression
+/// ordered the same way.
+///
+/// This holds for casts within the numeric or temporal families. A dictionary
+/// array is ordered by its values rather than by its keys, so encoding to or
+/// decoding from a dictionary keeps the order if casting the values does.
+pub fn cast_preserves_ordering(from: &DataType, to: &DataType) -> bool {
+    match (from, to) {
+        _ if from == to => true,
+        (DataType::Dictionary(_, from_value), _) => {
+            cast_preserves_ordering(from_value, to)
+        }
+        (_, DataType::Dictionary(_, to_value)) => cast_preserves_ordering(from, to_value),
+        _ => {
+            (from.is_numeric() || from == &DataType::Boolean) && to.is_numeric()
+                || from.is_temporal() && to.is_temporal()
+        }
+    }
+}
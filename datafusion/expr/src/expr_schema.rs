@@ -529,11 +529,23 @@ impl ExprSchemable for Expr {
                     .map(Arc::new)
                     .collect::<Vec<FieldRef>>();
 
-                let arguments = args
+                // calls whose value is known from the schema are passed like
+                // literals, see `ScalarUDFImpl::value_from_args`
+                let values = args
                     .iter()
                     .map(|e| match e {
+                        Expr::ScalarFunction(ScalarFunction { func, args }) => {
+                            func.value_from_args(args, schema)
+                        }
+                        _ => Ok(None),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let arguments = args
+                    .iter()
+                    .zip(&values)
+                    .map(|(e, value)| match e {
                         Expr::Literal(sv, _) => Some(sv),
-                        _ => None,
+                        _ => value.as_ref(),
                     })
                     .collect::<Vec<_>>();
                 let args = ReturnFieldArgs {
@@ -250,6 +250,18 @@ pub trait ExprPlanner: Debug + Send + Sync {
     fn plan_window(&self, expr: RawWindowExpr) -> Result<PlannerResult<RawWindowExpr>> {
         Ok(PlannerResult::Original(expr))
     }
+
+    /// Plans scalar functions called with named arguments, such as
+    /// `least(a, b, nulls => 'first')`
+    ///
+    /// Returns original expression if not possible, in which case the names
+    /// of the arguments are ignored
+    fn plan_named_arguments(
+        &self,
+        expr: RawScalarFunctionExpr,
+    ) -> Result<PlannerResult<RawScalarFunctionExpr>> {
+        Ok(PlannerResult::Original(expr))
+    }
 }
 
 /// An operator with two arguments to plan
@@ -311,6 +323,18 @@ pub struct RawWindowExpr {
     pub distinct: bool,
 }
 
+/// A scalar function call with named arguments, such as `least(a, b, nulls => 'first')`
+///
+/// This structure is used by [`ExprPlanner`] to plan operators with
+/// custom expressions.
+#[derive(Debug, Clone)]
+pub struct RawScalarFunctionExpr {
+    pub func: Arc<ScalarUDF>,
+    pub args: Vec<Expr>,
+    /// The name of each argument in `args`, `None` for positional arguments
+    pub arg_names: Vec<Option<String>>,
+}
+
 /// Result of planning a raw expr with [`ExprPlanner`]
 #[derive(Debug, Clone)]
 pub enum PlannerResult<T> {
@@ -301,6 +301,24 @@ impl ScalarUDF {
         self.inner.coerce_types(arg_types)
     }
 
+    /// See [`ScalarUDFImpl::coerce_types_for_cast`] for more details.
+    pub fn coerce_types_for_cast(
+        &self,
+        arg_types: &[DataType],
+        cast_type: &DataType,
+    ) -> Result<Option<Vec<DataType>>> {
+        self.inner.coerce_types_for_cast(arg_types, cast_type)
+    }
+
+    /// See [`ScalarUDFImpl::value_from_args`] for more details.
+    pub fn value_from_args(
+        &self,
+        args: &[Expr],
+        schema: &dyn ExprSchema,
+    ) -> Result<Option<ScalarValue>> {
+        self.inner.value_from_args(args, schema)
+    }
+
     /// Returns the documentation for this Scalar UDF.
     ///
     /// Documentation can be accessed programmatically as well as
@@ -367,6 +385,9 @@ pub struct ReturnFieldArgs<'a> {
     ///
     /// For example, if a function is called like `my_function(column_a, 5)`
     /// this field will be `[None, Some(ScalarValue::Int32(Some(5)))]`
+    ///
+    /// A call whose value is known from the schema is a scalar too, see
+    /// [`ScalarUDFImpl::value_from_args`]
     pub scalar_arguments: &'a [Option<&'a ScalarValue>],
 }
 
@@ -738,6 +759,45 @@ pub trait ScalarUDFImpl: Debug + DynEq + DynHash + Send + Sync {
         not_impl_err!("Function {} does not implement coerce_types", self.name())
     }
 
+    /// Coerce the arguments of a function call whose result is cast to
+    /// `cast_type`, such as `greatest(a, b)::decimal(20, 4)`.
+    ///
+    /// This lets a function whose result is one of its arguments evaluate
+    /// them in the type of the cast, rather than in the type they are coerced
+    /// to otherwise, which may not represent all their values.
+    ///
+    /// # Return value
+    /// `None` (the default) to coerce the arguments as usual, or a Vec the
+    /// same length as `arg_types`, the types DataFusion will `CAST` the
+    /// function call arguments to
+    fn coerce_types_for_cast(
+        &self,
+        _arg_types: &[DataType],
+        _cast_type: &DataType,
+    ) -> Result<Option<Vec<DataType>>> {
+        Ok(None)
+    }
+
+    /// Returns the value of a call of this function with `args` when it is
+    /// known from the schema alone, such as `arrow_typeof(b)`, which is the
+    /// name of the type of `b`.
+    ///
+    /// The value is passed like a literal argument, in
+    /// [`ReturnFieldArgs::scalar_arguments`], to the function the call is an
+    /// argument of, so that function can use it while planning, before the
+    /// call is simplified.
+    ///
+    /// # Return value
+    /// `None` (the default) if the value is only known when the function is
+    /// evaluated
+    fn value_from_args(
+        &self,
+        _args: &[Expr],
+        _schema: &dyn ExprSchema,
+    ) -> Result<Option<ScalarValue>> {
+        Ok(None)
+    }
+
     /// Returns the documentation for this Scalar UDF.
     ///
     /// Documentation can be accessed programmatically as well as generating
@@ -849,6 +909,22 @@ impl ScalarUDFImpl for AliasedScalarUDFImpl {
         self.inner.coerce_types(arg_types)
     }
 
+    fn coerce_types_for_cast(
+        &self,
+        arg_types: &[DataType],
+        cast_type: &DataType,
+    ) -> Result<Option<Vec<DataType>>> {
+        self.inner.coerce_types_for_cast(arg_types, cast_type)
+    }
+
+    fn value_from_args(
+        &self,
+        args: &[Expr],
+        schema: &dyn ExprSchema,
+    ) -> Result<Option<ScalarValue>> {
+        self.inner.value_from_args(args, schema)
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.inner.documentation()
     }
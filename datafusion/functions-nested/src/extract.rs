@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`ScalarUDFImpl`] definitions for array_element, array_slice, array_pop_front, array_pop_back, and array_any_value functions.
+//! [`ScalarUDFImpl`] definitions for array_element, array_slice, array_pop_front, array_pop_back, array_any_value, array_first and array_last functions.
 
 use arrow::array::{
     Array, ArrayRef, ArrowNativeTypeOp, Capacities, GenericListArray, Int64Array,
@@ -82,6 +82,22 @@ make_udf_expr_and_func!(
     array_any_value_udf
 );
 
+make_udf_expr_and_func!(
+    ArrayFirst,
+    array_first,
+    array,
+    "returns the first element of the array.",
+    array_first_udf
+);
+
+make_udf_expr_and_func!(
+    ArrayLast,
+    array_last,
+    array,
+    "returns the last element of the array.",
+    array_last_udf
+);
+
 #[user_doc(
     doc_section(label = "Array Functions"),
     description = "Extracts the element with the index n from the array.",
@@ -996,6 +1012,189 @@ where
     Ok(arrow::array::make_array(data))
 }
 
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns the first element of the array, or NULL if the array is empty.",
+    syntax_example = "array_first(array)",
+    sql_example = r#"```sql
+> select array_first([1, 2, 3]);
++----------------------------+
+| array_first(List([1,2,3])) |
++----------------------------+
+| 1                          |
++----------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct ArrayFirst {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl ArrayFirst {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::array(Volatility::Immutable),
+            aliases: vec![String::from("list_first")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayFirst {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "array_first"
+    }
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        array_element_type(self.name(), &arg_types[0])
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(|args| array_first_last_inner(args, "array_first", false))(
+            &args.args,
+        )
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns the last element of the array, or NULL if the array is empty.",
+    syntax_example = "array_last(array)",
+    sql_example = r#"```sql
+> select array_last([1, 2, 3]);
++---------------------------+
+| array_last(List([1,2,3])) |
++---------------------------+
+| 3                         |
++---------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(super) struct ArrayLast {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl ArrayLast {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::array(Volatility::Immutable),
+            aliases: vec![String::from("list_last")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayLast {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "array_last"
+    }
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        array_element_type(self.name(), &arg_types[0])
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(|args| array_first_last_inner(args, "array_last", true))(
+            &args.args,
+        )
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn array_element_type(name: &str, arg_type: &DataType) -> Result<DataType> {
+    match arg_type {
+        List(field) | LargeList(field) | FixedSizeList(field, _) => {
+            Ok(field.data_type().clone())
+        }
+        _ => plan_err!(
+            "{name} can only accept List, LargeList or FixedSizeList as the argument"
+        ),
+    }
+}
+
+fn array_first_last_inner(args: &[ArrayRef], name: &str, last: bool) -> Result<ArrayRef> {
+    let [array] = take_function_args(name, args)?;
+
+    match &array.data_type() {
+        List(_) => {
+            let array = as_list_array(&array)?;
+            general_array_first_last::<i32>(array, last)
+        }
+        LargeList(_) => {
+            let array = as_large_list_array(&array)?;
+            general_array_first_last::<i64>(array, last)
+        }
+        data_type => exec_err!("{name} does not support type: {:?}", data_type),
+    }
+}
+
+/// Takes the first or the last element of each list, NULL for empty or NULL lists
+fn general_array_first_last<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    last: bool,
+) -> Result<ArrayRef> {
+    let original_data = array.values().to_data();
+    let capacity = Capacities::Array(array.len());
+
+    let mut mutable =
+        MutableArrayData::with_capacities(vec![&original_data], true, capacity);
+
+    for (row_index, offset_window) in array.offsets().windows(2).enumerate() {
+        let start = offset_window[0].as_usize();
+        let end = offset_window[1].as_usize();
+
+        if array.is_null(row_index) || start == end {
+            mutable.extend_nulls(1);
+            continue;
+        }
+
+        let index = if last { end - 1 } else { start };
+        mutable.extend(0, index, index + 1);
+    }
+
+    let data = mutable.freeze();
+    Ok(arrow::array::make_array(data))
+}
+
 #[cfg(test)]
 mod tests {
     use super::array_element_udf;
@@ -0,0 +1,248 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for flatten_field function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{make_array, Array, ArrayRef, GenericListArray, OffsetSizeTrait};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::DataType::{LargeList, List};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::cast::{as_generic_list_array, as_struct_array};
+use datafusion_common::{exec_err, internal_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    FlattenField,
+    flatten_field,
+    array field,
+    "returns a list of the values of a field of the structs in the array.",
+    flatten_field_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns an array of the values of the given field of the struct elements of the array, keeping the elements in place. A null struct element gives a null value, and a null array returns NULL.",
+    syntax_example = "flatten_field(array, field)",
+    sql_example = r#"```sql
+> select flatten_field([named_struct('x', 1, 'y', 'a'), named_struct('x', 2, 'y', 'b')], 'x');
++------------------------------------------------------+
+| flatten_field(List([{x:1,y:a},{x:2,y:b}]),Utf8("x")) |
++------------------------------------------------------+
+| [1, 2]                                               |
++------------------------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array of structs expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "field",
+        description = "Constant string naming the field of the structs to extract."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct FlattenField {
+    signature: Signature,
+}
+
+impl Default for FlattenField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FlattenField {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for FlattenField {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "flatten_field"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!(
+            "flatten_field: return_type called instead of return_field_from_args"
+        )
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let array_field = &args.arg_fields[0];
+        let element_field = match array_field.data_type() {
+            List(field) | LargeList(field) => field,
+            other => {
+                return plan_err!(
+                    "flatten_field requires an array of structs, got {other}"
+                )
+            }
+        };
+        let DataType::Struct(fields) = element_field.data_type() else {
+            return plan_err!(
+                "flatten_field requires an array of structs, got {}",
+                array_field.data_type()
+            );
+        };
+
+        // the field gives the element type, so it must be known at planning
+        let Some(name) =
+            args.scalar_arguments[1].and_then(|name| name.try_as_str().flatten())
+        else {
+            return plan_err!(
+                "flatten_field requires the field name to be a constant string"
+            );
+        };
+        let Some((_, field)) = fields.find(name) else {
+            return plan_err!("flatten_field: field {name} not found in struct");
+        };
+
+        let value_field =
+            Arc::new(Field::new_list_field(field.data_type().clone(), true));
+        let data_type = match array_field.data_type() {
+            LargeList(_) => LargeList(value_field),
+            _ => List(value_field),
+        };
+        Ok(Arc::new(Field::new(
+            self.name(),
+            data_type,
+            array_field.is_nullable(),
+        )))
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        let return_type = args.return_type().clone();
+        make_scalar_function(move |arrays| flatten_field_inner(arrays, &return_type))(
+            &args.args,
+        )
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// flatten_field SQL function
+fn flatten_field_inner(args: &[ArrayRef], return_type: &DataType) -> Result<ArrayRef> {
+    let [array, name] = args else {
+        return exec_err!("flatten_field expects two arguments");
+    };
+    let name = datafusion_common::ScalarValue::try_from_array(name, 0)?;
+    let Some(name) = name.try_as_str().flatten() else {
+        return exec_err!(
+            "flatten_field requires the field name to be a constant string"
+        );
+    };
+
+    match return_type {
+        List(field) => general_flatten_field::<i32>(array, name, field),
+        LargeList(field) => general_flatten_field::<i64>(array, name, field),
+        _ => internal_err!("flatten_field returns a list, got {return_type}"),
+    }
+}
+
+/// Returns a list with the same offsets and nulls as `array`, whose values are
+/// the `name` column of its struct values
+fn general_flatten_field<O: OffsetSizeTrait>(
+    array: &ArrayRef,
+    name: &str,
+    value_field: &FieldRef,
+) -> Result<ArrayRef> {
+    let list = as_generic_list_array::<O>(array)?;
+    let structs = as_struct_array(list.values())?;
+    let Some(column) = structs.column_by_name(name) else {
+        return exec_err!("flatten_field: field {name} not found in struct");
+    };
+
+    // a null struct element has a null field value
+    let nulls = NullBuffer::union(structs.nulls(), column.nulls());
+    let values = make_array(column.to_data().into_builder().nulls(nulls).build()?);
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        Arc::clone(value_field),
+        list.offsets().clone(),
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{AsArray, Int32Array, ListArray, StringArray, StructArray};
+    use arrow::buffer::OffsetBuffer;
+    use arrow::datatypes::{Fields, Int32Type};
+
+    #[test]
+    fn flatten_field_keeps_offsets() -> Result<()> {
+        // [{x: 1, y: a}, {x: 2, y: b}], NULL, [], [NULL, {x: NULL, y: c}, {x: 4, y: d}]
+        let fields = Fields::from(vec![
+            Field::new("x", DataType::Int32, true),
+            Field::new("y", DataType::Utf8, true),
+        ]);
+        let structs = StructArray::try_new(
+            fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                    None,
+                    Some(4),
+                ])),
+                Arc::new(StringArray::from(vec!["a", "b", "z", "c", "d"])),
+            ],
+            Some(NullBuffer::from(vec![true, true, false, true, true])),
+        )?;
+        let list: ArrayRef = Arc::new(ListArray::try_new(
+            Arc::new(Field::new_list_field(DataType::Struct(fields), true)),
+            OffsetBuffer::new(vec![0, 2, 2, 2, 5].into()),
+            Arc::new(structs),
+            Some(NullBuffer::from(vec![true, false, true, true])),
+        )?);
+        let name: ArrayRef = Arc::new(StringArray::from(vec!["x"; 4]));
+
+        let value_field = Arc::new(Field::new_list_field(DataType::Int32, true));
+        let result = flatten_field_inner(&[Arc::clone(&list), name], &List(value_field))?;
+        let result = result.as_list::<i32>();
+
+        assert_eq!(result.offsets(), list.as_list::<i32>().offsets());
+        assert_eq!(result.nulls(), list.nulls());
+        let expected = Int32Array::from(vec![Some(1), Some(2), None, None, Some(4)]);
+        assert_eq!(result.values().as_primitive::<Int32Type>(), &expected);
+        Ok(())
+    }
+}
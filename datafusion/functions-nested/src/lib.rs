@@ -47,12 +47,15 @@ pub mod except;
 pub mod expr_ext;
 pub mod extract;
 pub mod flatten;
+pub mod flatten_field;
 pub mod length;
 pub mod make_array;
 pub mod map;
 pub mod map_entries;
 pub mod map_extract;
 pub mod map_keys;
+pub mod map_merge;
+pub mod map_to_struct;
 pub mod map_values;
 pub mod min_max;
 pub mod planner;
@@ -66,7 +69,9 @@ pub mod reverse;
 pub mod set_ops;
 pub mod sort;
 pub mod string;
+pub mod struct_to_map;
 pub mod utils;
+pub mod zip;
 
 use datafusion_common::Result;
 use datafusion_execution::FunctionRegistry;
@@ -90,15 +95,21 @@ pub mod expr_fn {
     pub use super::except::array_except;
     pub use super::extract::array_any_value;
     pub use super::extract::array_element;
+    pub use super::extract::array_first;
+    pub use super::extract::array_last;
     pub use super::extract::array_pop_back;
     pub use super::extract::array_pop_front;
     pub use super::extract::array_slice;
     pub use super::flatten::flatten;
+    pub use super::flatten_field::flatten_field;
     pub use super::length::array_length;
     pub use super::make_array::make_array;
     pub use super::map_entries::map_entries;
     pub use super::map_extract::map_extract;
+    pub use super::map_extract::map_extract_all;
     pub use super::map_keys::map_keys;
+    pub use super::map_merge::map_merge;
+    pub use super::map_to_struct::map_to_struct;
     pub use super::map_values::map_values;
     pub use super::min_max::array_max;
     pub use super::min_max::array_min;
@@ -121,6 +132,8 @@ pub mod expr_fn {
     pub use super::sort::array_sort;
     pub use super::string::array_to_string;
     pub use super::string::string_to_array;
+    pub use super::struct_to_map::struct_to_map;
+    pub use super::zip::array_zip;
 }
 
 /// Return all default nested type functions
@@ -142,6 +155,8 @@ pub fn all_default_nested_functions() -> Vec<Arc<ScalarUDF>> {
         extract::array_pop_front_udf(),
         extract::array_slice_udf(),
         extract::array_any_value_udf(),
+        extract::array_first_udf(),
+        extract::array_last_udf(),
         make_array::make_array_udf(),
         array_has::array_has_udf(),
         array_has::array_has_all_udf(),
@@ -150,6 +165,7 @@ pub fn all_default_nested_functions() -> Vec<Arc<ScalarUDF>> {
         length::array_length_udf(),
         distance::array_distance_udf(),
         flatten::flatten_udf(),
+        flatten_field::flatten_field_udf(),
         min_max::array_max_udf(),
         min_max::array_min_udf(),
         sort::array_sort_udf(),
@@ -170,8 +186,13 @@ pub fn all_default_nested_functions() -> Vec<Arc<ScalarUDF>> {
         map::map_udf(),
         map_entries::map_entries_udf(),
         map_extract::map_extract_udf(),
+        map_extract::map_extract_all_udf(),
         map_keys::map_keys_udf(),
+        map_merge::map_merge_udf(),
+        map_to_struct::map_to_struct_udf(),
         map_values::map_values_udf(),
+        struct_to_map::struct_to_map_udf(),
+        zip::array_zip_udf(),
     ]
 }
 
@@ -22,9 +22,13 @@ use arrow::array::{
     make_array, Array, ArrayRef, Capacities, ListArray, MapArray, MutableArrayData,
 };
 use arrow::buffer::OffsetBuffer;
+use arrow::compute::cast;
 use arrow::datatypes::{DataType, Field};
 use datafusion_common::utils::take_function_args;
-use datafusion_common::{cast::as_map_array, exec_err, Result};
+use datafusion_common::{
+    cast::as_map_array, exec_err, plan_datafusion_err, plan_err, Result,
+};
+use datafusion_expr::binary::type_union_resolution;
 use datafusion_expr::{
     ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
 };
@@ -41,11 +45,18 @@ make_udf_expr_and_func!(
     "Return a list containing the value for a given key or an empty list if the key is not contained in the map.",
     map_extract_udf
 );
+make_udf_expr_and_func!(
+    MapExtractAll,
+    map_extract_all,
+    map key,
+    "Return a list containing all the values for a given key, in the order of the map, or an empty list if the key is not contained in the map.",
+    map_extract_all_udf
+);
 
 #[user_doc(
     doc_section(label = "Map Functions"),
     description = "Returns a list containing the value for the given key or an empty list if the key is not present in the map.",
-    syntax_example = "map_extract(map, key)",
+    syntax_example = "map_extract(map, key[, default])",
     sql_example = r#"```sql
 SELECT map_extract(MAP {'a': 1, 'b': NULL, 'c': 3}, 'a');
 ----
@@ -58,6 +69,10 @@ SELECT map_extract(MAP {1: 'one', 2: 'two'}, 2);
 SELECT map_extract(MAP {'x': 10, 'y': NULL, 'z': 30}, 'y');
 ----
 []
+
+SELECT map_extract(MAP {'x': 10, 'y': 20}, 'z', 0);
+----
+[0]
 ```"#,
     argument(
         name = "map",
@@ -66,6 +81,10 @@ SELECT map_extract(MAP {'x': 10, 'y': NULL, 'z': 30}, 'y');
     argument(
         name = "key",
         description = "Key to extract from the map. Can be a constant, column, or function, any combination of arithmetic or string operators, or a named expression of the previously listed."
+    ),
+    argument(
+        name = "default",
+        description = "Optional value returned in the list instead of NULL when the key is not present in the map. The map values and the default are coerced to a common type."
     )
 )]
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -102,10 +121,11 @@ impl ScalarUDFImpl for MapExtract {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        let [map_type, _] = take_function_args(self.name(), arg_types)?;
+        let (map_type, default_type) = map_and_default_types(self.name(), arg_types)?;
         let map_fields = get_map_entry_field(map_type)?;
+        let value_type = map_fields.last().unwrap().data_type();
         Ok(DataType::List(Arc::new(Field::new_list_field(
-            map_fields.last().unwrap().data_type().clone(),
+            unify_value_type(value_type, default_type)?,
             true,
         ))))
     }
@@ -122,8 +142,96 @@ impl ScalarUDFImpl for MapExtract {
     }
 
     fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let (map_type, default_type) = map_and_default_types(self.name(), arg_types)?;
+
+        let field = get_map_entry_field(map_type)?;
+        let mut coerced_types =
+            vec![map_type.clone(), field.first().unwrap().data_type().clone()];
+        // the values of the map are cast to the type of the default when extracted
+        if let Some(default_type) = default_type {
+            let value_type = field.last().unwrap().data_type();
+            coerced_types.push(unify_value_type(value_type, Some(default_type))?);
+        }
+        Ok(coerced_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns a list containing all the values for the given key, in the order of the map, or an empty list if the key is not present in the map. Unlike `map_extract`, which only returns the first value, it returns every value of a key repeated in the map.",
+    syntax_example = "map_extract_all(map, key)",
+    sql_example = r#"```sql
+SELECT map_extract_all(MAP {'a': 1, 'b': NULL, 'c': 3}, 'a');
+----
+[1]
+
+SELECT map_extract_all(MAP {'x': 10, 'y': 20}, 'z');
+----
+[]
+```"#,
+    argument(
+        name = "map",
+        description = "Map expression. Can be a constant, column, or function, and any combination of map operators."
+    ),
+    argument(
+        name = "key",
+        description = "Key to extract from the map. Can be a constant, column, or function, any combination of arithmetic or string operators, or a named expression of the previously listed."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct MapExtractAll {
+    signature: Signature,
+}
+
+impl Default for MapExtractAll {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapExtractAll {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MapExtractAll {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn name(&self) -> &str {
+        "map_extract_all"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
         let [map_type, _] = take_function_args(self.name(), arg_types)?;
+        let map_fields = get_map_entry_field(map_type)?;
+        let value_type = map_fields.last().unwrap().data_type();
+        Ok(DataType::List(Arc::new(Field::new_list_field(
+            value_type.clone(),
+            true,
+        ))))
+    }
 
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(map_extract_all_inner)(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [map_type, _] = take_function_args(self.name(), arg_types)?;
         let field = get_map_entry_field(map_type)?;
         Ok(vec![
             map_type.clone(),
@@ -136,19 +244,57 @@ impl ScalarUDFImpl for MapExtract {
     }
 }
 
+/// Returns the map type and the type of the optional default
+fn map_and_default_types<'a>(
+    name: &str,
+    arg_types: &'a [DataType],
+) -> Result<(&'a DataType, Option<&'a DataType>)> {
+    match arg_types {
+        [map_type, _] => Ok((map_type, None)),
+        [map_type, _, default_type] => Ok((map_type, Some(default_type))),
+        _ => plan_err!("{name} expects 2 or 3 arguments, got {}", arg_types.len()),
+    }
+}
+
+/// Returns the type holding both the values of the map and the default
+fn unify_value_type(
+    value_type: &DataType,
+    default_type: Option<&DataType>,
+) -> Result<DataType> {
+    let Some(default_type) = default_type else {
+        return Ok(value_type.clone());
+    };
+
+    type_union_resolution(&[value_type.clone(), default_type.clone()]).ok_or_else(|| {
+        plan_datafusion_err!(
+            "map_extract cannot coerce the default of type {default_type} to the map value type {value_type}"
+        )
+    })
+}
+
 fn general_map_extract_inner(
     map_array: &MapArray,
     query_keys_array: &dyn Array,
+    default_array: Option<&ArrayRef>,
 ) -> Result<ArrayRef> {
     let keys = map_array.keys();
     let mut offsets = vec![0_i32];
 
-    let values = map_array.values();
+    let values = match default_array {
+        Some(default_array) => cast(map_array.values(), default_array.data_type())?,
+        None => Arc::clone(map_array.values()),
+    };
     let original_data = values.to_data();
+    let default_data = default_array.map(|array| array.to_data());
     let capacity = Capacities::Array(original_data.len());
 
-    let mut mutable =
-        MutableArrayData::with_capacities(vec![&original_data], true, capacity);
+    let mut mutable = MutableArrayData::with_capacities(
+        std::iter::once(&original_data)
+            .chain(default_data.as_ref())
+            .collect(),
+        true,
+        capacity,
+    );
 
     for (row_index, offset_window) in map_array.value_offsets().windows(2).enumerate() {
         let start = offset_window[0] as usize;
@@ -164,6 +310,10 @@ fn general_map_extract_inner(
             Some(index) => {
                 mutable.extend(0, start + index, start + index + 1);
             }
+            // a missing key takes the default of its row
+            None if default_data.is_some() => {
+                mutable.extend(1, row_index, row_index + 1);
+            }
             None => {
                 mutable.extend_nulls(1);
             }
@@ -174,15 +324,85 @@ fn general_map_extract_inner(
     let data = mutable.freeze();
 
     Ok(Arc::new(ListArray::new(
-        Arc::new(Field::new_list_field(map_array.value_type().clone(), true)),
+        Arc::new(Field::new_list_field(values.data_type().clone(), true)),
         OffsetBuffer::<i32>::new(offsets.into()),
         Arc::new(make_array(data)),
         None,
     )))
 }
 
+/// Returns per row a list with the values of all the entries of the map whose
+/// key equals the query key, or a NULL list for a NULL map
+fn general_map_extract_all_inner(
+    map_array: &MapArray,
+    query_keys_array: &dyn Array,
+) -> Result<ArrayRef> {
+    let keys = map_array.keys();
+    let mut offsets = vec![0_i32];
+
+    let original_data = map_array.values().to_data();
+    let capacity = Capacities::Array(original_data.len());
+    let mut mutable =
+        MutableArrayData::with_capacities(vec![&original_data], true, capacity);
+
+    for (row_index, offset_window) in map_array.value_offsets().windows(2).enumerate() {
+        let start = offset_window[0] as usize;
+        let end = offset_window[1] as usize;
+
+        let query_key = query_keys_array.slice(row_index, 1);
+
+        let mut matched = 0;
+        for index in start..end {
+            if keys.slice(index, 1).as_ref() == query_key.as_ref() {
+                mutable.extend(0, index, index + 1);
+                matched += 1;
+            }
+        }
+        offsets.push(offsets[row_index] + matched);
+    }
+
+    let data = mutable.freeze();
+
+    Ok(Arc::new(ListArray::new(
+        Arc::new(Field::new_list_field(
+            map_array.values().data_type().clone(),
+            true,
+        )),
+        OffsetBuffer::<i32>::new(offsets.into()),
+        Arc::new(make_array(data)),
+        map_array.nulls().cloned(),
+    )))
+}
+
+fn map_extract_all_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [map_arg, key_arg] = take_function_args("map_extract_all", args)?;
+
+    let map_array = match map_arg.data_type() {
+        DataType::Map(_, _) => as_map_array(&map_arg)?,
+        _ => return exec_err!("The first argument in map_extract_all must be a map"),
+    };
+
+    let key_type = map_array.key_type();
+
+    if key_type != key_arg.data_type() {
+        return exec_err!(
+            "The key type {} does not match the map key type {}",
+            key_arg.data_type(),
+            key_type
+        );
+    }
+
+    general_map_extract_all_inner(map_array, key_arg)
+}
+
 fn map_extract_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
-    let [map_arg, key_arg] = take_function_args("map_extract", args)?;
+    let (map_arg, key_arg, default_arg) = match args {
+        [map_arg, key_arg] => (map_arg, key_arg, None),
+        [map_arg, key_arg, default_arg] => (map_arg, key_arg, Some(default_arg)),
+        _ => {
+            return exec_err!("map_extract expects 2 or 3 arguments, got {}", args.len())
+        }
+    };
 
     let map_array = match map_arg.data_type() {
         DataType::Map(_, _) => as_map_array(&map_arg)?,
@@ -199,5 +419,50 @@ fn map_extract_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
         );
     }
 
-    general_map_extract_inner(map_array, key_arg)
+    general_map_extract_inner(map_array, key_arg, default_arg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{AsArray, Int32Builder, MapBuilder, StringArray, StringBuilder};
+    use arrow::datatypes::Int32Type;
+
+    #[test]
+    fn map_extract_all_repeated_keys() -> Result<()> {
+        // {a: 1, b: 2, a: 3}, {b: 4}, {c: 5}, NULL
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for entries in [
+            vec![("a", 1), ("b", 2), ("a", 3)],
+            vec![("b", 4)],
+            vec![("c", 5)],
+        ] {
+            for (key, value) in entries {
+                builder.keys().append_value(key);
+                builder.values().append_value(value);
+            }
+            builder.append(true)?;
+        }
+        builder.append(false)?;
+        let map: ArrayRef = Arc::new(builder.finish());
+        let keys: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "a", "a"]));
+
+        let result = map_extract_all_inner(&[map, keys])?;
+        let result = result.as_list::<i32>();
+
+        let values = |row: usize| {
+            result
+                .value(row)
+                .as_primitive::<Int32Type>()
+                .iter()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(0), vec![Some(1), Some(3)]);
+        assert_eq!(values(1), vec![Some(4)]);
+        assert_eq!(values(2), vec![]);
+        assert!(result.is_null(3));
+
+        Ok(())
+    }
 }
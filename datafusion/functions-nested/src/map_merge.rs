@@ -0,0 +1,289 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for map_merge function.
+
+use crate::utils::{get_map_entry_field, make_scalar_function};
+use arrow::array::{
+    make_array, make_comparator, Array, ArrayRef, Capacities, MapArray, MutableArrayData,
+    StructArray,
+};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::compute::SortOptions;
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{cast::as_map_array, exec_err, plan_err, Result};
+use datafusion_expr::binary::type_union_resolution;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    MapMergeFunc,
+    map_merge,
+    map_a map_b,
+    "Return a map with the entries of both maps, the entries of the second map overriding the first.",
+    map_merge_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns a map with the entries of both maps. When both maps contain the same key, the value of the second map is used. A null map is treated as an empty map, the result is null only if both maps are null.",
+    syntax_example = "map_merge(map_a, map_b)",
+    sql_example = r#"```sql
+SELECT map_merge(MAP {'a': 1, 'b': 2}, MAP {'c': 3});
+----
+{a: 1, b: 2, c: 3}
+
+SELECT map_merge(MAP {'a': 1, 'b': 2}, MAP {'b': 20, 'c': 3});
+----
+{a: 1, b: 20, c: 3}
+```"#,
+    argument(
+        name = "map_a",
+        description = "Map expression. Can be a constant, column, or function, and any combination of map operators."
+    ),
+    argument(
+        name = "map_b",
+        description = "Map expression whose entries take precedence. Can be a constant, column, or function, and any combination of map operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct MapMergeFunc {
+    signature: Signature,
+}
+
+impl Default for MapMergeFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapMergeFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MapMergeFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_merge"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [map_a, map_b] = take_function_args(self.name(), arg_types)?;
+
+        match (map_a, map_b) {
+            (DataType::Map(_, _), DataType::Map(_, _)) => {
+                let [key_a, value_a] = map_key_value_types(map_a)?;
+                let [key_b, value_b] = map_key_value_types(map_b)?;
+                if key_a != key_b || value_a != value_b {
+                    return plan_err!(
+                        "map_merge requires maps with the same key and value types, got {map_a} and {map_b}"
+                    );
+                }
+                Ok(map_a.clone())
+            }
+            (DataType::Map(_, _), DataType::Null) => Ok(map_a.clone()),
+            (DataType::Null, DataType::Map(_, _)) => Ok(map_b.clone()),
+            _ => plan_err!("map_merge requires two maps, got {map_a} and {map_b}"),
+        }
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        make_scalar_function(map_merge_inner)(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [map_a, map_b] = take_function_args(self.name(), arg_types)?;
+
+        let (DataType::Map(entries, sorted), DataType::Map(_, _)) = (map_a, map_b) else {
+            // a single map is returned as is, `return_type` rejects the rest
+            return Ok(vec![map_a.clone(), map_b.clone()]);
+        };
+
+        let [key_a, value_a] = map_key_value_types(map_a)?;
+        let [key_b, value_b] = map_key_value_types(map_b)?;
+        let (Some(key), Some(value)) = (
+            type_union_resolution(&[key_a.clone(), key_b.clone()]),
+            type_union_resolution(&[value_a.clone(), value_b.clone()]),
+        ) else {
+            return plan_err!(
+                "map_merge cannot coerce {map_a} and {map_b} to a common map type"
+            );
+        };
+
+        // both maps are cast to the first map with the common key and value types
+        let fields = get_map_entry_field(map_a)?;
+        let entries = Field::new(
+            entries.name(),
+            DataType::Struct(
+                vec![
+                    fields[0].as_ref().clone().with_data_type(key),
+                    fields[1].as_ref().clone().with_data_type(value),
+                ]
+                .into(),
+            ),
+            entries.is_nullable(),
+        );
+        let map_type = DataType::Map(Arc::new(entries), *sorted);
+
+        Ok(vec![map_type.clone(), map_type])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the key and value types of a map type
+fn map_key_value_types(map_type: &DataType) -> Result<[&DataType; 2]> {
+    let fields = get_map_entry_field(map_type)?;
+    Ok([fields[0].data_type(), fields[1].data_type()])
+}
+
+fn map_merge_inner(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [map_a, map_b] = take_function_args("map_merge", args)?;
+
+    let (map_a, map_b) = match (map_a.data_type(), map_b.data_type()) {
+        (DataType::Map(_, _), DataType::Map(_, _)) => {
+            (as_map_array(map_a)?, as_map_array(map_b)?)
+        }
+        (DataType::Map(_, _), DataType::Null) => return Ok(Arc::clone(map_a)),
+        (DataType::Null, DataType::Map(_, _)) => return Ok(Arc::clone(map_b)),
+        _ => return exec_err!("map_merge requires two maps"),
+    };
+
+    if map_a.data_type() != map_b.data_type() {
+        return exec_err!(
+            "map_merge requires maps of the same type, got {} and {}",
+            map_a.data_type(),
+            map_b.data_type()
+        );
+    }
+
+    general_map_merge(map_a, map_b)
+}
+
+fn general_map_merge(map_a: &MapArray, map_b: &MapArray) -> Result<ArrayRef> {
+    let keys_a = map_a.keys().to_data();
+    let keys_b = map_b.keys().to_data();
+    let values_a = map_a.values().to_data();
+    let values_b = map_b.values().to_data();
+
+    let mut keys = MutableArrayData::with_capacities(
+        vec![&keys_a, &keys_b],
+        false,
+        Capacities::Array(keys_a.len() + keys_b.len()),
+    );
+    let mut values = MutableArrayData::with_capacities(
+        vec![&values_a, &values_b],
+        true,
+        Capacities::Array(values_a.len() + values_b.len()),
+    );
+
+    let comparator = make_comparator(
+        map_a.keys().as_ref(),
+        map_b.keys().as_ref(),
+        SortOptions::default(),
+    )?;
+
+    let mut offsets = Vec::with_capacity(map_a.len() + 1);
+    offsets.push(0_i32);
+    let mut overridden = vec![];
+
+    for row in 0..map_a.len() {
+        // a null map contributes no entries
+        let range_a = if map_a.is_null(row) {
+            0..0
+        } else {
+            map_a.value_offsets()[row] as usize..map_a.value_offsets()[row + 1] as usize
+        };
+        let range_b = if map_b.is_null(row) {
+            0..0
+        } else {
+            map_b.value_offsets()[row] as usize..map_b.value_offsets()[row + 1] as usize
+        };
+
+        // entries of `map_a` keep their position, taking the value of `map_b` on conflict
+        overridden.clear();
+        overridden.resize(range_b.len(), false);
+        for a in range_a.clone() {
+            keys.extend(0, a, a + 1);
+            match range_b.clone().position(|b| comparator(a, b).is_eq()) {
+                Some(i) => {
+                    let b = range_b.start + i;
+                    values.extend(1, b, b + 1);
+                    overridden[i] = true;
+                }
+                None => values.extend(0, a, a + 1),
+            }
+        }
+
+        // the remaining entries of `map_b` follow
+        for (i, b) in range_b.clone().enumerate() {
+            if !overridden[i] {
+                keys.extend(1, b, b + 1);
+                values.extend(1, b, b + 1);
+            }
+        }
+
+        let len =
+            range_a.len() + range_b.len() - overridden.iter().filter(|&&o| o).count();
+        offsets.push(offsets[row] + len as i32);
+    }
+
+    let DataType::Map(entries_field, sorted) = map_a.data_type() else {
+        return exec_err!("map_merge requires two maps");
+    };
+    let entries = StructArray::try_new(
+        get_map_entry_field(map_a.data_type())?.clone(),
+        vec![make_array(keys.freeze()), make_array(values.freeze())],
+        None,
+    )?;
+    // the result is null only when both maps are null
+    let nulls = match (map_a.nulls(), map_b.nulls()) {
+        (Some(a), Some(b)) => {
+            Some(NullBuffer::new(a.inner() | b.inner())).filter(|n| n.null_count() > 0)
+        }
+        _ => None,
+    };
+
+    Ok(Arc::new(MapArray::try_new(
+        Arc::clone(entries_field),
+        OffsetBuffer::new(offsets.into()),
+        entries,
+        nulls,
+        *sorted,
+    )?))
+}
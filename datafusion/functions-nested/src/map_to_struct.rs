@@ -0,0 +1,252 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for map_to_struct function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{Array, ArrayRef, StructArray, UInt64Array};
+use arrow::compute::{cast, take};
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::cast::{as_map_array, as_string_array};
+use datafusion_common::{internal_err, plan_err, HashSet, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    MapToStructFunc,
+    map_to_struct,
+    map keys,
+    "Return a struct with a field for each key of the given constant list, holding the value of the key in the map.",
+    map_to_struct_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns a struct with a field for each of the given keys, holding the value of that key in the map. The keys must be a constant list of strings so the struct has a fixed set of fields. Keys missing from the map yield NULL fields, and a null map returns a null struct.",
+    syntax_example = "map_to_struct(map, keys)",
+    sql_example = r#"```sql
+SELECT map_to_struct(MAP {'a': 1, 'b': 2, 'c': 3}, 'a', 'c');
+----
+{a: 1, c: 3}
+
+SELECT map_to_struct(MAP {'a': 1}, 'a', 'z');
+----
+{a: 1, z: NULL}
+```"#,
+    argument(
+        name = "map",
+        description = "Map expression. Can be a constant, column, or function, and any combination of map operators."
+    ),
+    argument(
+        name = "keys",
+        description = "Constant list of strings naming the keys to extract, each of which becomes a field of the struct. In SQL, where array literals are not constant, the keys are given as separate constant strings instead."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct MapToStructFunc {
+    signature: Signature,
+}
+
+impl Default for MapToStructFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapToStructFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MapToStructFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "map_to_struct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!(
+            "map_to_struct: return_type called instead of return_field_from_args"
+        )
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let map_field = &args.arg_fields[0];
+        let DataType::Map(entries_field, _) = map_field.data_type() else {
+            return plan_err!(
+                "map_to_struct requires a map, got {}",
+                map_field.data_type()
+            );
+        };
+        let DataType::Struct(entries_fields) = entries_field.data_type() else {
+            return internal_err!("map_to_struct expects the map entries to be a struct");
+        };
+        let value_type = entries_fields[1].data_type();
+
+        // the keys form the schema of the struct, so they must be known at planning
+        let mut seen = HashSet::new();
+        let fields = constant_keys(&args.scalar_arguments[1..])?
+            .into_iter()
+            .map(|key| {
+                if !seen.insert(key.clone()) {
+                    return plan_err!(
+                        "map_to_struct requires distinct keys, got '{key}' more than once"
+                    );
+                }
+                Ok(Field::new(key, value_type.clone(), true))
+            })
+            .collect::<Result<Fields>>()?;
+        if fields.is_empty() {
+            return plan_err!("map_to_struct requires at least one key");
+        }
+
+        Ok(Arc::new(Field::new(
+            self.name(),
+            DataType::Struct(fields),
+            map_field.is_nullable(),
+        )))
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        let return_type = args.return_type().clone();
+        // only the map is evaluated, the keys are the fields of the return type
+        make_scalar_function(move |arrays| map_to_struct_inner(&arrays[0], &return_type))(
+            &args.args[..1],
+        )
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let Some((map_type, key_types)) = arg_types.split_first() else {
+            return plan_err!("map_to_struct requires a map and a list of keys");
+        };
+        if key_types.is_empty() {
+            return plan_err!("map_to_struct requires a map and a list of keys");
+        }
+        if !matches!(map_type, DataType::Map(_, _)) {
+            return plan_err!("map_to_struct requires a map, got {map_type}");
+        }
+        let keys_are_strings = match key_types {
+            [DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _)] => is_string(field.data_type()),
+            _ => key_types.iter().all(is_string),
+        };
+        if !keys_are_strings {
+            return plan_err!(
+                "map_to_struct requires the keys to be a list of strings, got {}",
+                key_types
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        Ok(arg_types.to_vec())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+fn is_string(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    )
+}
+
+/// Returns the keys given either as a single constant list literal, or as
+/// constant strings (SQL array literals are not constant at planning time)
+fn constant_keys(keys: &[Option<&ScalarValue>]) -> Result<Vec<String>> {
+    let list = match keys {
+        [Some(ScalarValue::List(list))] => Some(list.value(0)),
+        [Some(ScalarValue::LargeList(list))] => Some(list.value(0)),
+        [Some(ScalarValue::FixedSizeList(list))] => Some(list.value(0)),
+        _ => None,
+    };
+    if let Some(list) = list {
+        let list = cast(&list, &DataType::Utf8)?;
+        return as_string_array(&list)?
+            .iter()
+            .map(|key| match key {
+                Some(key) => Ok(key.to_string()),
+                None => plan_err!("map_to_struct requires the keys to be non-null"),
+            })
+            .collect();
+    }
+
+    keys.iter()
+        .map(|key| match key.and_then(|key| key.try_as_str()) {
+            Some(Some(key)) => Ok(key.to_string()),
+            _ => plan_err!(
+                "map_to_struct requires the keys to be a constant list of strings"
+            ),
+        })
+        .collect()
+}
+
+fn map_to_struct_inner(array: &ArrayRef, return_type: &DataType) -> Result<ArrayRef> {
+    let DataType::Struct(fields) = return_type else {
+        return internal_err!("map_to_struct returns a struct, got {return_type}");
+    };
+    let map_array = as_map_array(array)?;
+
+    // map keys are compared to the field names as strings
+    let keys = cast(map_array.keys(), &DataType::Utf8)?;
+    let keys = as_string_array(&keys)?;
+
+    // for each field, the index into the map values of its key in every row
+    let mut indices = vec![Vec::with_capacity(map_array.len()); fields.len()];
+    for (row, offsets) in map_array.value_offsets().windows(2).enumerate() {
+        let entries = offsets[0] as usize..offsets[1] as usize;
+        for (field, indices) in fields.iter().zip(indices.iter_mut()) {
+            let index = map_array
+                .is_valid(row)
+                .then(|| entries.clone().find(|&i| keys.value(i) == field.name()))
+                .flatten();
+            indices.push(index.map(|i| i as u64));
+        }
+    }
+
+    let columns = indices
+        .into_iter()
+        .map(|indices| take(map_array.values(), &UInt64Array::from(indices), None))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Arc::new(StructArray::try_new(
+        fields.clone(),
+        columns,
+        map_array.nulls().cloned(),
+    )?))
+}
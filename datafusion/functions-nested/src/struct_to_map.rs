@@ -0,0 +1,203 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for struct_to_map function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{Array, ArrayRef, MapArray, StringArray, StructArray};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::interleave;
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{
+    cast::as_struct_array, internal_err, plan_datafusion_err, plan_err, Result,
+};
+use datafusion_expr::binary::type_union_resolution;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    StructToMapFunc,
+    struct_to_map,
+    struct_expr,
+    "Return a map with the field names of the struct as keys and its field values as values.",
+    struct_to_map_udf
+);
+
+#[user_doc(
+    doc_section(label = "Map Functions"),
+    description = "Returns a map with the field names of the struct as keys and the field values as values. The fields of the struct must be coercible to a common value type. A null struct returns a null map.",
+    syntax_example = "struct_to_map(struct)",
+    sql_example = r#"```sql
+SELECT struct_to_map(named_struct('a', 1, 'b', 2));
+----
+{a: 1, b: 2}
+
+SELECT struct_to_map(named_struct('a', 1, 'b', 2.5));
+----
+{a: 1.0, b: 2.5}
+```"#,
+    argument(
+        name = "struct",
+        description = "Struct expression with at least one field. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct StructToMapFunc {
+    signature: Signature,
+}
+
+impl Default for StructToMapFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructToMapFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StructToMapFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_to_map"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [struct_type] = take_function_args(self.name(), arg_types)?;
+        let value_type = common_value_type(struct_type)?;
+
+        let fields = Fields::from(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", value_type, true),
+        ]);
+        Ok(DataType::Map(
+            Arc::new(Field::new("entries", DataType::Struct(fields), false)),
+            false,
+        ))
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        let return_type = args.return_type().clone();
+        make_scalar_function(move |arrays| struct_to_map_inner(arrays, &return_type))(
+            &args.args,
+        )
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [struct_type] = take_function_args(self.name(), arg_types)?;
+        let value_type = common_value_type(struct_type)?;
+
+        // every field takes the value type, keeping its name
+        let DataType::Struct(fields) = struct_type else {
+            return plan_err!("struct_to_map requires a struct, got {struct_type}");
+        };
+        let fields = fields
+            .iter()
+            .map(|field| field.as_ref().clone().with_data_type(value_type.clone()))
+            .collect::<Fields>();
+        Ok(vec![DataType::Struct(fields)])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the type all the fields of the struct are coerced to
+fn common_value_type(struct_type: &DataType) -> Result<DataType> {
+    let DataType::Struct(fields) = struct_type else {
+        return plan_err!("struct_to_map requires a struct, got {struct_type}");
+    };
+    if fields.is_empty() {
+        return plan_err!("struct_to_map requires a struct with at least one field");
+    }
+
+    let field_types = fields
+        .iter()
+        .map(|field| field.data_type().clone())
+        .collect::<Vec<_>>();
+    type_union_resolution(&field_types).ok_or_else(|| {
+        plan_datafusion_err!(
+            "struct_to_map requires the fields of the struct to have a common type, got {struct_type}"
+        )
+    })
+}
+
+fn struct_to_map_inner(args: &[ArrayRef], return_type: &DataType) -> Result<ArrayRef> {
+    let [array] = take_function_args("struct_to_map", args)?;
+    let struct_array = as_struct_array(array)?;
+
+    let DataType::Map(entries_field, sorted) = return_type else {
+        return internal_err!("struct_to_map returns a map, got {return_type}");
+    };
+    let DataType::Struct(entries_fields) = entries_field.data_type() else {
+        return internal_err!("struct_to_map returns a map of structs");
+    };
+
+    // one entry per field, for each struct that is not null
+    let num_fields = struct_array.num_columns();
+    let mut indices = Vec::with_capacity(struct_array.len() * num_fields);
+    let mut offsets = Vec::with_capacity(struct_array.len() + 1);
+    offsets.push(0_i32);
+    for row in 0..struct_array.len() {
+        if struct_array.is_valid(row) {
+            indices.extend((0..num_fields).map(|field| (field, row)));
+        }
+        offsets.push(indices.len() as i32);
+    }
+
+    let names = struct_array.column_names();
+    let keys = indices
+        .iter()
+        .map(|(field, _)| Some(names[*field]))
+        .collect::<StringArray>();
+    let columns = struct_array
+        .columns()
+        .iter()
+        .map(|column| column.as_ref())
+        .collect::<Vec<_>>();
+    let values = interleave(&columns, &indices)?;
+
+    let entries =
+        StructArray::try_new(entries_fields.clone(), vec![Arc::new(keys), values], None)?;
+    Ok(Arc::new(MapArray::try_new(
+        Arc::clone(entries_field),
+        OffsetBuffer::new(offsets.into()),
+        entries,
+        struct_array.nulls().cloned(),
+        *sorted,
+    )?))
+}
@@ -0,0 +1,244 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for array_zip function.
+
+use crate::utils::make_scalar_function;
+use arrow::array::{
+    make_array, Array, ArrayRef, GenericListArray, MutableArrayData, NullBufferBuilder,
+    OffsetSizeTrait, StructArray,
+};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::DataType::{FixedSizeList, LargeList, List, Null};
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::cast::as_generic_list_array;
+use datafusion_common::{
+    exec_err, internal_err, plan_err, utils::take_function_args, Result,
+};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    ArrayZip,
+    array_zip,
+    array1 array2,
+    "returns a list of structs pairing the elements of the two arrays.",
+    array_zip_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns an array of structs pairing the elements of the two arrays by position. The shorter array is padded with NULLs to the length of the longer one. Returns NULL if either array is NULL.",
+    syntax_example = "array_zip(array1, array2)",
+    sql_example = r#"```sql
+> select array_zip([1, 2, 3], ['a', 'b']);
++-----------------------------------------------------+
+| array_zip(List([1,2,3]),List([a,b]))                |
++-----------------------------------------------------+
+| [{c0: 1, c1: a}, {c0: 2, c1: b}, {c0: 3, c1: NULL}] |
++-----------------------------------------------------+
+```"#,
+    argument(
+        name = "array1",
+        description = "Array expression whose elements become the `c0` fields. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "array2",
+        description = "Array expression whose elements become the `c1` fields. Can be a constant, column, or function, and any combination of array operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ArrayZip {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ArrayZip {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayZip {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            aliases: vec!["list_zip".to_string(), "zip".to_string()],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayZip {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "array_zip"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [array1, array2] = take_function_args(self.name(), arg_types)?;
+
+        let fields = [array1, array2]
+            .into_iter()
+            .enumerate()
+            .map(|(i, arg_type)| match arg_type {
+                List(field) | LargeList(field) => {
+                    Ok(Field::new(format!("c{i}"), field.data_type().clone(), true))
+                }
+                _ => plan_err!("array_zip expects array arguments, got {arg_type}"),
+            })
+            .collect::<Result<Fields>>()?;
+
+        let field = Arc::new(Field::new_list_field(DataType::Struct(fields), true));
+        match array1 {
+            LargeList(_) => Ok(LargeList(field)),
+            _ => Ok(List(field)),
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [array1, array2] = take_function_args(self.name(), arg_types)?;
+
+        // both arrays get the same offset type, so that they are zipped into one
+        let large = matches!(array1, LargeList(_)) || matches!(array2, LargeList(_));
+        [array1, array2]
+            .into_iter()
+            .map(|arg_type| {
+                let field = match arg_type {
+                    List(field) | LargeList(field) | FixedSizeList(field, _) => {
+                        Arc::clone(field)
+                    }
+                    Null => Arc::new(Field::new_list_field(Null, true)),
+                    _ => {
+                        return plan_err!(
+                            "array_zip expects array arguments, got {arg_type}"
+                        )
+                    }
+                };
+                Ok(if large { LargeList(field) } else { List(field) })
+            })
+            .collect()
+    }
+
+    fn invoke_with_args(
+        &self,
+        args: datafusion_expr::ScalarFunctionArgs,
+    ) -> Result<ColumnarValue> {
+        let return_type = args.return_type().clone();
+        make_scalar_function(move |arrays| array_zip_inner(arrays, &return_type))(
+            &args.args,
+        )
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// array_zip SQL function
+fn array_zip_inner(args: &[ArrayRef], return_type: &DataType) -> Result<ArrayRef> {
+    let [array1, array2] = take_function_args("array_zip", args)?;
+
+    match return_type {
+        List(field) => general_array_zip::<i32>(array1, array2, field.data_type()),
+        LargeList(field) => general_array_zip::<i64>(array1, array2, field.data_type()),
+        _ => internal_err!("array_zip returns a list, got {return_type}"),
+    }
+}
+
+fn general_array_zip<O: OffsetSizeTrait>(
+    array1: &ArrayRef,
+    array2: &ArrayRef,
+    struct_type: &DataType,
+) -> Result<ArrayRef> {
+    let DataType::Struct(fields) = struct_type else {
+        return exec_err!("array_zip returns a list of structs, got {struct_type}");
+    };
+    let array1 = as_generic_list_array::<O>(array1)?;
+    let array2 = as_generic_list_array::<O>(array2)?;
+
+    let values1 = array1.values().to_data();
+    let values2 = array2.values().to_data();
+    let capacity = values1.len().max(values2.len());
+    let mut mutable1 = MutableArrayData::new(vec![&values1], true, capacity);
+    let mut mutable2 = MutableArrayData::new(vec![&values2], true, capacity);
+
+    let mut offsets = Vec::with_capacity(array1.len() + 1);
+    offsets.push(O::usize_as(0));
+    let mut nulls = NullBufferBuilder::new(array1.len());
+    let mut len = 0;
+
+    for row_index in 0..array1.len() {
+        if array1.is_null(row_index) || array2.is_null(row_index) {
+            offsets.push(O::usize_as(len));
+            nulls.append_null();
+            continue;
+        }
+
+        let (start1, end1) = row_bounds(array1, row_index);
+        let (start2, end2) = row_bounds(array2, row_index);
+        let row_len = (end1 - start1).max(end2 - start2);
+
+        // the shorter array is padded with NULLs
+        mutable1.extend(0, start1, end1);
+        mutable1.extend_nulls(row_len - (end1 - start1));
+        mutable2.extend(0, start2, end2);
+        mutable2.extend_nulls(row_len - (end2 - start2));
+
+        len += row_len;
+        offsets.push(O::usize_as(len));
+        nulls.append_non_null();
+    }
+
+    let values = StructArray::try_new(
+        fields.clone(),
+        vec![make_array(mutable1.freeze()), make_array(mutable2.freeze())],
+        None,
+    )?;
+
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        Arc::new(Field::new_list_field(struct_type.clone(), true)),
+        OffsetBuffer::new(offsets.into()),
+        Arc::new(values),
+        nulls.finish(),
+    )?))
+}
+
+fn row_bounds<O: OffsetSizeTrait>(
+    array: &GenericListArray<O>,
+    row_index: usize,
+) -> (usize, usize) {
+    let offsets = array.value_offsets();
+    (
+        offsets[row_index].as_usize(),
+        offsets[row_index + 1].as_usize(),
+    )
+}
@@ -0,0 +1,88 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use arrow::array::{ArrayRef, StructArray};
+use arrow::datatypes::{DataType, Field, FieldRef, Int32Type};
+use arrow::util::bench_util::{create_primitive_array, create_string_array};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::ScalarValue;
+use datafusion_expr::{ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs};
+use datafusion_functions::core::arrow_cast;
+use std::sync::Arc;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let arrow_cast = arrow_cast();
+    let num_batches = 10_000;
+    let batch_size = 8;
+
+    let struct_array: ArrayRef = Arc::new(StructArray::from(vec![
+        (
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(create_primitive_array::<Int32Type>(batch_size, 0.2)) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("b", DataType::Utf8, true)),
+            Arc::new(create_string_array::<i32>(batch_size, 0.2)) as ArrayRef,
+        ),
+    ]));
+    let type_arg = ScalarValue::from("Struct(b Utf8View, a Int64)");
+    let args = vec![
+        ColumnarValue::Array(struct_array),
+        ColumnarValue::Scalar(type_arg.clone()),
+    ];
+    let arg_fields: Vec<FieldRef> = args
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| Field::new(format!("arg_{idx}"), arg.data_type(), true).into())
+        .collect();
+
+    // The target type is resolved once, as it would be during planning
+    let return_field = arrow_cast
+        .return_field_from_args(ReturnFieldArgs {
+            arg_fields: &arg_fields,
+            scalar_arguments: &[None, Some(&type_arg)],
+        })
+        .unwrap();
+    let config_options = Arc::new(ConfigOptions::default());
+
+    c.bench_function(
+        &format!("arrow_cast struct {num_batches} batches of {batch_size}"),
+        |b| {
+            b.iter(|| {
+                for _ in 0..num_batches {
+                    black_box(
+                        arrow_cast
+                            .invoke_with_args(ScalarFunctionArgs {
+                                args: args.clone(),
+                                arg_fields: arg_fields.clone(),
+                                number_rows: batch_size,
+                                return_field: Arc::clone(&return_field),
+                                config_options: Arc::clone(&config_options),
+                            })
+                            .unwrap(),
+                    );
+                }
+            })
+        },
+    );
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use arrow::array::{ArrayRef, Int64Array};
+use arrow::datatypes::{DataType, Field};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datafusion_common::config::ConfigOptions;
+use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion_functions::core::coalesce;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::Arc;
+
+/// The keys of both sides of a full outer join, where each row only has the
+/// key of one side if `match_density` is 0 and the keys of both if it is 1
+fn full_outer_join_keys(size: usize, match_density: f64) -> (ArrayRef, ArrayRef) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let (left, right): (Vec<_>, Vec<_>) = (0..size as i64)
+        .map(|key| {
+            if rng.random_bool(match_density) {
+                (Some(key), Some(key))
+            } else if rng.random_bool(0.5) {
+                (Some(key), None)
+            } else {
+                (None, Some(key))
+            }
+        })
+        .unzip();
+    (
+        Arc::new(Int64Array::from(left)),
+        Arc::new(Int64Array::from(right)),
+    )
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let coalesce = coalesce();
+    let batch_size = 8192;
+    let num_batches = 128;
+    let config_options = Arc::new(ConfigOptions::default());
+    let arg_fields = vec![
+        Field::new("a", DataType::Int64, true).into(),
+        Field::new("b", DataType::Int64, true).into(),
+    ];
+
+    for match_density in [0.0, 0.5] {
+        let batches = (0..num_batches)
+            .map(|_| {
+                let (left, right) = full_outer_join_keys(batch_size, match_density);
+                vec![ColumnarValue::Array(left), ColumnarValue::Array(right)]
+            })
+            .collect::<Vec<_>>();
+
+        c.bench_function(
+            &format!(
+                "coalesce full outer join keys: {num_batches} batches, match density {match_density}"
+            ),
+            |b| {
+                b.iter(|| {
+                    for args in &batches {
+                        black_box(
+                            coalesce
+                                .invoke_with_args(ScalarFunctionArgs {
+                                    args: args.clone(),
+                                    arg_fields: arg_fields.clone(),
+                                    number_rows: batch_size,
+                                    return_field: Field::new("f", DataType::Int64, true)
+                                        .into(),
+                                    config_options: Arc::clone(&config_options),
+                                })
+                                .unwrap(),
+                        );
+                    }
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields, Int64Type};
+use arrow::util::bench_util::create_primitive_array;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::ScalarValue;
+use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion_functions::core::named_struct;
+use std::sync::Arc;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let named_struct = named_struct();
+    let num_fields = 50;
+    let size = 1_000_000;
+
+    let array: ArrayRef = Arc::new(create_primitive_array::<Int64Type>(size, 0.2));
+    let args = (0..num_fields)
+        .flat_map(|i| {
+            [
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some(format!("f{i}")))),
+                ColumnarValue::Array(Arc::clone(&array)),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let arg_fields = args
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| Field::new(format!("arg_{idx}"), arg.data_type(), true).into())
+        .collect::<Vec<_>>();
+    let fields = (0..num_fields)
+        .map(|i| Field::new(format!("f{i}"), DataType::Int64, true))
+        .collect::<Fields>();
+    let return_field: FieldRef = Field::new("f", DataType::Struct(fields), true).into();
+    let config_options = Arc::new(ConfigOptions::default());
+
+    c.bench_function(&format!("named_struct {num_fields} fields: {size}"), |b| {
+        b.iter(|| {
+            black_box(
+                named_struct
+                    .invoke_with_args(ScalarFunctionArgs {
+                        args: args.clone(),
+                        arg_fields: arg_fields.clone(),
+                        number_rows: size,
+                        return_field: Arc::clone(&return_field),
+                        config_options: Arc::clone(&config_options),
+                    })
+                    .unwrap(),
+            )
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -17,6 +17,8 @@
 
 extern crate criterion;
 
+use arrow::array::{ArrayRef, BooleanArray};
+use arrow::compute::kernels::zip::zip;
 use arrow::datatypes::{DataType, Field};
 use arrow::util::bench_util::create_string_array_with_len;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
@@ -28,6 +30,7 @@ use std::sync::Arc;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let nullif = nullif();
+    let config_options = Arc::new(ConfigOptions::default());
     for size in [1024, 4096, 8192] {
         let array = Arc::new(create_string_array_with_len::<i32>(size, 0.2, 32));
         let args = vec![
@@ -41,7 +44,6 @@ fn criterion_benchmark(c: &mut Criterion) {
                 Field::new(format!("arg_{idx}"), arg.data_type(), true).into()
             })
             .collect::<Vec<_>>();
-        let config_options = Arc::new(ConfigOptions::default());
 
         c.bench_function(&format!("nullif scalar array: {size}"), |b| {
             b.iter(|| {
@@ -59,6 +61,35 @@ fn criterion_benchmark(c: &mut Criterion) {
             })
         });
     }
+
+    // two string columns with every other row equal
+    let size = 1024 * 1024;
+    let lhs: ArrayRef = Arc::new(create_string_array_with_len::<i32>(size, 0.2, 32));
+    let other = create_string_array_with_len::<i32>(size, 0.2, 16);
+    let every_other = BooleanArray::from_iter((0..size).map(|i| Some(i % 2 == 0)));
+    let rhs = zip(&every_other, &lhs, &other).unwrap();
+    let args = vec![ColumnarValue::Array(lhs), ColumnarValue::Array(rhs)];
+    let arg_fields = args
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| Field::new(format!("arg_{idx}"), arg.data_type(), true).into())
+        .collect::<Vec<_>>();
+
+    c.bench_function(&format!("nullif array array: {size}"), |b| {
+        b.iter(|| {
+            black_box(
+                nullif
+                    .invoke_with_args(ScalarFunctionArgs {
+                        args: args.clone(),
+                        arg_fields: arg_fields.clone(),
+                        number_rows: size,
+                        return_field: Field::new("f", DataType::Utf8, true).into(),
+                        config_options: Arc::clone(&config_options),
+                    })
+                    .unwrap(),
+            )
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
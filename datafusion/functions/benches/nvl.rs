@@ -0,0 +1,79 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate criterion;
+
+use arrow::datatypes::{DataType, Field, Int64Type};
+use arrow::util::bench_util::create_primitive_array;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::ScalarValue;
+use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion_functions::core::nvl;
+use std::sync::Arc;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let nvl = nvl();
+    let batch_size = 8192;
+    let num_batches = 128;
+    let config_options = Arc::new(ConfigOptions::default());
+
+    for null_density in [0.0, 0.2] {
+        let batches = (0..num_batches)
+            .map(|_| {
+                let array = Arc::new(create_primitive_array::<Int64Type>(
+                    batch_size,
+                    null_density,
+                ));
+                vec![
+                    ColumnarValue::Array(array),
+                    ColumnarValue::Scalar(ScalarValue::Int64(Some(0))),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let arg_fields = vec![
+            Field::new("a", DataType::Int64, true).into(),
+            Field::new("b", DataType::Int64, false).into(),
+        ];
+
+        c.bench_function(
+            &format!(
+                "nvl array scalar: {num_batches} batches, null density {null_density}"
+            ),
+            |b| {
+                b.iter(|| {
+                    for args in &batches {
+                        black_box(
+                            nvl.invoke_with_args(ScalarFunctionArgs {
+                                args: args.clone(),
+                                arg_fields: arg_fields.clone(),
+                                number_rows: batch_size,
+                                return_field: Field::new("f", DataType::Int64, true)
+                                    .into(),
+                                config_options: Arc::clone(&config_options),
+                            })
+                            .unwrap(),
+                        );
+                    }
+                })
+            },
+        );
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,148 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`AllTrueFunc`]: Implementation of the `all_true` function
+
+use super::kleene_utils::fold_kleene;
+use arrow::compute::kernels::boolean::and_kleene;
+use arrow::datatypes::DataType;
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns true if all of its boolean arguments are true, following SQL three-valued logic: returns false if any is false, _null_ if none is false but some are _null_.",
+    syntax_example = "all_true(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select all_true(true, null, false);
++---------------------------------------------+
+| all_true(Boolean(true),NULL,Boolean(false)) |
++---------------------------------------------+
+| false                                       |
++---------------------------------------------+
+> select all_true(true, null);
++------------------------------+
+| all_true(Boolean(true),NULL) |
++------------------------------+
+| NULL                         |
++------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Boolean expression to check for true. Can be a constant, column, or function, and any combination of boolean operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct AllTrueFunc {
+    signature: Signature,
+}
+
+impl Default for AllTrueFunc {
+    fn default() -> Self {
+        AllTrueFunc::new()
+    }
+}
+
+impl AllTrueFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic(
+                vec![DataType::Boolean],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for AllTrueFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "all_true"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    /// all_true evaluates per row to false if any value is false, NULL if none is but some value is NULL
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        fold_kleene(self.name(), &args.args, and_kleene)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, BooleanArray};
+    use arrow::datatypes::Field;
+    use datafusion_common::config::ConfigOptions;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_all_true_three_valued() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(BooleanArray::from(vec![
+                Some(true),
+                None,
+                Some(false),
+                None,
+                Some(true),
+            ]))),
+            ColumnarValue::Array(Arc::new(BooleanArray::from(vec![
+                Some(true),
+                Some(true),
+                None,
+                None,
+                Some(false),
+            ]))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = AllTrueFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 5,
+                return_field: Field::new("f", DataType::Boolean, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(5)?;
+
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(false), None, Some(false)]);
+        assert_eq!(result.as_boolean(), &expected);
+
+        Ok(())
+    }
+}
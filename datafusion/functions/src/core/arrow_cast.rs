@@ -17,23 +17,43 @@
 
 //! [`ArrowCastFunc`]: Implementation of the `arrow_cast`
 
-use arrow::datatypes::{DataType, Field, FieldRef};
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::compute::kernels::boolean::{and, or_kleene};
+use arrow::compute::kernels::cmp::{gt, lt};
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{
+    can_cast_types, cast, cast_with_options, is_not_null, is_null, nullif, CastOptions,
+};
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
 use arrow::error::ArrowError;
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::format::DEFAULT_FORMAT_OPTIONS;
 use datafusion_common::{
-    arrow_datafusion_err, exec_err, internal_err, Result, ScalarValue,
+    arrow_datafusion_err, exec_err, internal_err, plan_err, Result, ScalarValue,
 };
 use datafusion_common::{
     exec_datafusion_err, utils::take_function_args, DataFusionError,
 };
+use itertools::Itertools;
 use std::any::Any;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::sync::Arc;
 
+use datafusion_expr::expr::ScalarFunction;
 use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::sort_properties::{
+    cast_preserves_ordering, ExprProperties, SortProperties,
+};
 use datafusion_expr::{
     ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs,
-    ScalarUDFImpl, Signature, Volatility,
+    ScalarUDFImpl, Signature, TypeSignature, Volatility,
 };
 use datafusion_macros::user_doc;
 
+use super::arrowtypeof::ArrowTypeOfFunc;
+use super::options_utils::{is_options_type, option_value, options_literal};
+
 /// Implements casting to arbitrary arrow types (rather than SQL types)
 ///
 /// Note that the `arrow_cast` function is somewhat special in that its
@@ -57,8 +77,12 @@ use datafusion_macros::user_doc;
 /// ```
 #[user_doc(
     doc_section(label = "Other Functions"),
-    description = "Casts a value to a specific Arrow data type.",
-    syntax_example = "arrow_cast(expression, datatype)",
+    description = "Casts a value to a specific Arrow data type.
+
+When casting a struct to another struct type, the fields of the target struct are matched to the fields of the source struct by name, so fields may be reordered, dropped or cast to a different type. Every field of the target struct must exist in the source struct.
+
+When casting a numeric value to a numeric type, the values which overflow the type are an error by default. With `overflow => 'saturate'` they are clamped to the smallest or greatest value of the type instead, and with `overflow => 'null'` they are _null_.",
+    syntax_example = "arrow_cast(expression, datatype[, format | overflow => 'error' | 'saturate' | 'null'])",
     sql_example = r#"```sql
 > select arrow_cast(-5, 'Int8') as a,
   arrow_cast('foo', 'Dictionary(Int32, Utf8)') as b,
@@ -78,6 +102,14 @@ use datafusion_macros::user_doc;
     argument(
         name = "datatype",
         description = "[Arrow data type](https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html) name to cast to, as a string. The format is the same as that returned by [`arrow_typeof`]"
+    ),
+    argument(
+        name = "format",
+        description = "Optional [Chrono format](https://docs.rs/chrono/latest/chrono/format/strftime/index.html) string used when casting a date, time or timestamp to a string."
+    ),
+    argument(
+        name = "overflow",
+        description = "Optional named argument setting how the values which overflow a numeric type are cast: `'error'` (the default), `'saturate'` or `'null'`."
     )
 )]
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -94,8 +126,90 @@ impl Default for ArrowCastFunc {
 impl ArrowCastFunc {
     pub fn new() -> Self {
         Self {
-            signature: Signature::any(2, Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![TypeSignature::Any(2), TypeSignature::Any(3)],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+/// How `arrow_cast` handles the numeric values which overflow the type they
+/// are cast to, set with the named argument `overflow`, such as
+/// `arrow_cast(a, 'Int8', overflow => 'saturate')`.
+///
+/// The mode is passed to `arrow_cast` as its third argument in an options
+/// literal, see [`OverflowMode::to_scalar`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OverflowMode {
+    /// The values which overflow are an error, like for a SQL cast
+    #[default]
+    Error,
+    /// The values which overflow are clamped to the smallest value of the
+    /// type if they are negative, and to the greatest value otherwise
+    Saturate,
+    /// The values which overflow are NULL
+    Null,
+}
+
+impl FromStr for OverflowMode {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "error" => Ok(Self::Error),
+            "saturate" => Ok(Self::Saturate),
+            "null" => Ok(Self::Null),
+            _ => plan_err!(
+                "Invalid overflow option '{s}', expected one of 'error', 'saturate' or 'null'"
+            ),
+        }
+    }
+}
+
+impl Display for OverflowMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Saturate => write!(f, "saturate"),
+            Self::Null => write!(f, "null"),
+        }
+    }
+}
+
+/// The named argument setting the [`OverflowMode`]
+const OVERFLOW_OPTION: &str = "overflow";
+
+impl OverflowMode {
+    /// Returns the options literal `{overflow: '<mode>'}` passing this mode
+    /// as the third argument of `arrow_cast`, which is how the named argument
+    /// `overflow => '<mode>'` is planned
+    pub fn to_scalar(self) -> ScalarValue {
+        options_literal(OVERFLOW_OPTION, &self.to_string())
+    }
+}
+
+impl TryFrom<&ScalarValue> for OverflowMode {
+    type Error = DataFusionError;
+
+    fn try_from(options: &ScalarValue) -> Result<Self> {
+        option_value(options, OVERFLOW_OPTION)?.parse()
+    }
+}
+
+/// Splits the options literal of [`OverflowMode::to_scalar`] off the argument
+/// expressions of `arrow_cast`. The mode is `None` without an options literal.
+fn split_overflow_mode_literal(args: &[Expr]) -> (&[Expr], Option<OverflowMode>) {
+    match args {
+        [_, _, Expr::Literal(options, _)]
+            if is_options_type(&options.data_type(), OVERFLOW_OPTION) =>
+        {
+            match OverflowMode::try_from(options) {
+                Ok(overflow_mode) => (&args[..2], Some(overflow_mode)),
+                Err(_) => (args, None),
+            }
         }
+        _ => (args, None),
     }
 }
 
@@ -116,30 +230,126 @@ impl ScalarUDFImpl for ArrowCastFunc {
         internal_err!("return_field_from_args should be called instead")
     }
 
-    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
-        let nullable = args.arg_fields.iter().any(|f| f.is_nullable());
+    fn schema_name(&self, args: &[Expr]) -> Result<String> {
+        let (args, overflow_mode) = split_overflow_mode_literal(args);
+        let args = args
+            .iter()
+            .map(|arg| arg.schema_name().to_string())
+            .join(",");
+        match overflow_mode {
+            None => Ok(format!("{}({args})", self.name())),
+            Some(mode) => Ok(format!("{}({args}, overflow => '{mode}')", self.name())),
+        }
+    }
 
-        let [_, type_arg] = take_function_args(self.name(), args.scalar_arguments)?;
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let (type_arg, third_arg) = match args.scalar_arguments {
+            [_, type_arg] => (type_arg, None),
+            [_, type_arg, third_arg] => (type_arg, Some(third_arg)),
+            _ => {
+                return plan_err!(
+                    "{} expects 2 or 3 arguments, got {}",
+                    self.name(),
+                    args.scalar_arguments.len()
+                )
+            }
+        };
 
-        type_arg
-            .and_then(|sv| sv.try_as_str().flatten().filter(|s| !s.is_empty()))
-            .map_or_else(
-                || {
-                    exec_err!(
-                        "{} requires its second argument to be a non-empty constant string",
+        // the third argument is either a format or the options literal of the
+        // named argument `overflow`
+        let is_overflow_arg = args
+            .arg_fields
+            .get(2)
+            .is_some_and(|f| is_options_type(f.data_type(), OVERFLOW_OPTION));
+        let (format_arg, overflow_mode) = match third_arg {
+            Some(options) if is_overflow_arg => {
+                let Some(options) = options else {
+                    return plan_err!(
+                        "{} requires the overflow argument to be a constant",
                         self.name()
-                    )
-                },
-                |casted_type| match casted_type.parse::<DataType>() {
-                    Ok(data_type) => Ok(Field::new(self.name(), data_type, nullable).into()),
-                    Err(ArrowError::ParseError(e)) => Err(exec_datafusion_err!("{e}")),
-                    Err(e) => Err(arrow_datafusion_err!(e)),
-                },
-            )
+                    );
+                };
+                (None, OverflowMode::try_from(*options)?)
+            }
+            format_arg => (format_arg, OverflowMode::default()),
+        };
+
+        // the values which overflow are NULL with `overflow => 'null'`
+        let nullable = args.arg_fields.iter().any(|f| f.is_nullable())
+            || overflow_mode == OverflowMode::Null;
+
+        // a type argument such as `arrow_typeof(b)` is passed as the type name,
+        // see `ScalarUDFImpl::value_from_args`
+        let Some(casted_type) = type_arg
+            .and_then(|sv| sv.try_as_str().flatten())
+            .filter(|s| !s.is_empty())
+        else {
+            return exec_err!(
+                "{} requires its second argument to be a non-empty constant string",
+                self.name()
+            );
+        };
+
+        let data_type = parse_data_type(casted_type)?;
+        let source_type = args.arg_fields[0].data_type();
+        validate_struct_cast(source_type, &data_type)?;
+        if let Some(format_arg) = format_arg {
+            validate_format_cast(*format_arg, source_type, &data_type)?;
+        }
+        if overflow_mode != OverflowMode::Error {
+            validate_overflow_cast(overflow_mode, source_type, &data_type)?;
+        }
+
+        Ok(Field::new(self.name(), data_type, nullable).into())
     }
 
-    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        internal_err!("arrow_cast should have been simplified to cast")
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let target_type = args.return_field.data_type();
+
+        let mut arguments = args.args;
+        let overflow_mode = match arguments.as_slice() {
+            [_, _, ColumnarValue::Scalar(options)]
+                if is_options_type(&options.data_type(), OVERFLOW_OPTION) =>
+            {
+                OverflowMode::try_from(options)?
+            }
+            [arg, _, ColumnarValue::Scalar(format)] => {
+                let Some(format) = format.try_as_str().flatten() else {
+                    return exec_err!(
+                        "{} requires its third argument to be a constant string",
+                        self.name()
+                    );
+                };
+                return cast_with_format(arg, target_type, format);
+            }
+            _ => OverflowMode::Error,
+        };
+        arguments.truncate(2);
+
+        let [arg, _] = take_function_args(self.name(), arguments)?;
+
+        if overflow_mode != OverflowMode::Error {
+            return cast_with_overflow_mode(arg, target_type, overflow_mode);
+        }
+
+        // Only struct to struct casts, casts with a format and casts with an
+        // overflow mode are left unsimplified, see `simplify`
+        let DataType::Struct(target_fields) = target_type else {
+            return internal_err!("arrow_cast should have been simplified to cast");
+        };
+
+        match arg {
+            ColumnarValue::Array(array) => Ok(ColumnarValue::Array(cast_struct_by_name(
+                &array,
+                target_fields,
+            )?)),
+            ColumnarValue::Scalar(scalar) => {
+                let array = cast_struct_by_name(&scalar.to_array()?, target_fields)?;
+                Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                    &array, 0,
+                )?))
+            }
+        }
     }
 
     fn simplify(
@@ -147,13 +357,34 @@ impl ScalarUDFImpl for ArrowCastFunc {
         mut args: Vec<Expr>,
         info: &dyn SimplifyInfo,
     ) -> Result<ExprSimplifyResult> {
+        // `Expr::Cast` errors on overflow like `overflow => 'error'`
+        if split_overflow_mode_literal(&args).1 == Some(OverflowMode::Error) {
+            args.truncate(2);
+        }
+        // `Expr::Cast` has no format and errors on overflow, so casts with a
+        // format or another overflow mode are evaluated by `invoke_with_args`
+        if args.len() == 3 {
+            return Ok(ExprSimplifyResult::Original(args));
+        }
+
+        let target_type = data_type_from_args(&args, info)?;
+        let source_type = info.get_data_type(&args[0])?;
+
+        // `Expr::Cast` matches struct fields by position, so struct to struct
+        // casts are kept and evaluated by `invoke_with_args` to match fields by name
+        if matches!(
+            (&source_type, &target_type),
+            (DataType::Struct(_), DataType::Struct(_))
+        ) && source_type != target_type
+        {
+            return Ok(ExprSimplifyResult::Original(args));
+        }
+
         // convert this into a real cast
-        let target_type = data_type_from_args(&args)?;
         // remove second (type) argument
         args.pop().unwrap();
         let arg = args.pop().unwrap();
 
-        let source_type = info.get_data_type(&arg)?;
         let new_expr = if source_type == target_type {
             // the argument's data type is already the correct type
             arg
@@ -168,22 +399,393 @@ impl ScalarUDFImpl for ArrowCastFunc {
         Ok(ExprSimplifyResult::Simplified(new_expr))
     }
 
+    /// The cast keeps the order of its input like a `CastExpr` does, see
+    /// [`cast_preserves_ordering`]. In particular dictionary decoding keeps
+    /// the order, while encoding only does if casting the values does.
+    fn output_ordering(&self, input: &[ExprProperties]) -> Result<SortProperties> {
+        // the target type is the constant second argument
+        let Some(target_type) = input
+            .get(1)
+            .and_then(|p| p.range.lower().try_as_str().flatten())
+        else {
+            return Ok(SortProperties::Unordered);
+        };
+        let target_type = parse_data_type(target_type)?;
+        if cast_preserves_ordering(&input[0].range.data_type(), &target_type) {
+            Ok(input[0].sort_properties)
+        } else {
+            Ok(SortProperties::Unordered)
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Implements `arrow_cast_strict`, the variant of [`ArrowCastFunc`] that
+/// rejects numeric casts which may lose values or precision while planning,
+/// such as `Int64` to `Int32` or reducing the scale of a decimal
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = "Casts a value to a specific Arrow data type like `arrow_cast`, but returns an error if the cast may lose values or precision, such as casting `Int64` to `Int32` or reducing the scale of a decimal. Use `arrow_cast` to allow such casts.",
+    syntax_example = "arrow_cast_strict(expression, datatype)",
+    sql_example = r#"```sql
+> select arrow_cast_strict(arrow_cast(5, 'Int32'), 'Int64') as a;
++---+
+| a |
++---+
+| 5 |
++---+
+```"#,
+    argument(
+        name = "expression",
+        description = "Expression to cast. The expression can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "datatype",
+        description = "[Arrow data type](https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html) name to cast to, as a string. The format is the same as that returned by [`arrow_typeof`]"
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ArrowCastStrictFunc {
+    cast: ArrowCastFunc,
+}
+
+impl Default for ArrowCastStrictFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrowCastStrictFunc {
+    pub fn new() -> Self {
+        Self {
+            cast: ArrowCastFunc::new(),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrowCastStrictFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "arrow_cast_strict"
+    }
+
+    fn signature(&self) -> &Signature {
+        self.cast.signature()
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let arg_fields = args.arg_fields;
+        let field = self.cast.return_field_from_args(args)?;
+
+        let (from, to) = (arg_fields[0].data_type(), field.data_type());
+        if is_lossy_cast(from, to) {
+            return plan_err!(
+                "{} cannot cast {from} to {to} without losing values or precision, \
+                use arrow_cast to allow it",
+                self.name()
+            );
+        }
+
+        Ok(field.as_ref().clone().with_name(self.name()).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        self.cast.invoke_with_args(args)
+    }
+
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        self.cast.simplify(args, info)
+    }
+
+    fn output_ordering(&self, input: &[ExprProperties]) -> Result<SortProperties> {
+        self.cast.output_ordering(input)
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
 }
 
+/// Implements `arrow_cast_range`, the variant of [`ArrowCastFunc`] that
+/// returns NULL for the values which cannot be cast or are out of a range
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = "Casts a value to a specific numeric Arrow data type like `arrow_cast`, but returns _null_ for the values that cannot be cast to the type or that are outside of the range from `min` to `max` once cast, instead of returning an error. A _null_ bound leaves the range unbounded on its side.",
+    syntax_example = "arrow_cast_range(expression, datatype, min, max)",
+    sql_example = r#"```sql
+> select arrow_cast_range(column1, 'Int8', 0, 10) from (values (5), (20), (300)) as t;
++-------------------------------------------------------------+
+| arrow_cast_range(t.column1,Utf8("Int8"),Int64(0),Int64(10)) |
++-------------------------------------------------------------+
+| 5                                                           |
+| NULL                                                        |
+| NULL                                                        |
++-------------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Expression to cast. The expression can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "datatype",
+        description = "[Arrow data type](https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html) name of the numeric type to cast to, as a string. The format is the same as that returned by [`arrow_typeof`]"
+    ),
+    argument(name = "min", description = "Smallest value kept, cast to `datatype`."),
+    argument(name = "max", description = "Greatest value kept, cast to `datatype`.")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ArrowCastRangeFunc {
+    signature: Signature,
+}
+
+impl Default for ArrowCastRangeFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrowCastRangeFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(4, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrowCastRangeFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "arrow_cast_range"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [_, &type_arg, _, _] =
+            take_function_args(self.name(), args.scalar_arguments)?;
+
+        let Some(casted_type) =
+            type_arg.and_then(|sv| sv.try_as_str().flatten().filter(|s| !s.is_empty()))
+        else {
+            return exec_err!(
+                "{} requires its second argument to be a non-empty constant string",
+                self.name()
+            );
+        };
+
+        let data_type = parse_data_type(casted_type)?;
+        if !data_type.is_numeric() {
+            return plan_err!(
+                "{} requires a numeric type to cast to, got {data_type}",
+                self.name()
+            );
+        }
+
+        // the values out of the range are NULL
+        Ok(Field::new(self.name(), data_type, true).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let target_type = args.return_field.data_type();
+        let [value, _, min, max] = take_function_args(self.name(), args.args)?;
+
+        // the values which cannot be cast are NULL rather than an error
+        let options = CastOptions {
+            safe: true,
+            ..Default::default()
+        };
+        let value = value.cast_to(target_type, Some(&options))?;
+        let min = min.cast_to(target_type, None)?;
+        let max = max.cast_to(target_type, None)?;
+        let is_scalar = [&value, &min, &max]
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        let arrays = ColumnarValue::values_to_arrays(&[value, min, max])?;
+        let (value, min, max) = (&arrays[0], &arrays[1], &arrays[2]);
+
+        // a NULL bound compares to NULL, which `nullif` does not apply
+        let out_of_range = or_kleene(&lt(value, min)?, &gt(value, max)?)?;
+        let result = nullif(value, &out_of_range)?;
+
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns true if casting between the numeric types `from` and `to` may lose
+/// values or precision. Casts involving other types are never considered lossy
+fn is_lossy_cast(from: &DataType, to: &DataType) -> bool {
+    if from == to {
+        return false;
+    }
+
+    match (NumericKind::of(from), NumericKind::of(to)) {
+        (
+            Some(NumericKind::Integer {
+                signed: from_signed,
+                bits: from_bits,
+            }),
+            Some(NumericKind::Integer {
+                signed: to_signed,
+                bits: to_bits,
+            }),
+        ) => {
+            (from_signed && !to_signed)
+                || to_bits < from_bits
+                || (!from_signed && to_signed && to_bits == from_bits)
+        }
+        (
+            Some(NumericKind::Integer { signed, bits }),
+            Some(NumericKind::Float { mantissa_bits }),
+        ) => bits - u32::from(signed) > mantissa_bits,
+        (
+            Some(from @ NumericKind::Integer { .. }),
+            Some(NumericKind::Decimal { precision, scale }),
+        ) => precision - scale < from.integer_digits(),
+        (Some(NumericKind::Float { .. }), Some(NumericKind::Integer { .. }))
+        | (Some(NumericKind::Float { .. }), Some(NumericKind::Decimal { .. })) => true,
+        (
+            Some(NumericKind::Float {
+                mantissa_bits: from_bits,
+            }),
+            Some(NumericKind::Float {
+                mantissa_bits: to_bits,
+            }),
+        ) => to_bits < from_bits,
+        (
+            Some(NumericKind::Decimal {
+                precision: from_precision,
+                scale: from_scale,
+            }),
+            Some(NumericKind::Decimal {
+                precision: to_precision,
+                scale: to_scale,
+            }),
+        ) => {
+            to_scale < from_scale || to_precision - to_scale < from_precision - from_scale
+        }
+        (
+            Some(NumericKind::Decimal { precision, scale }),
+            Some(to @ NumericKind::Integer { signed, .. }),
+        ) => scale > 0 || !signed || precision - scale >= to.integer_digits(),
+        (
+            Some(NumericKind::Decimal { precision, scale }),
+            Some(NumericKind::Float { mantissa_bits }),
+        ) => {
+            // the largest number of decimal digits the float holds exactly
+            let digits = i32::try_from(mantissa_bits).unwrap_or(i32::MAX) * 3 / 10;
+            scale > 0 || precision > digits
+        }
+        _ => false,
+    }
+}
+
+/// The numeric types [`is_lossy_cast`] compares
+#[derive(Debug, Clone, Copy)]
+enum NumericKind {
+    Integer { signed: bool, bits: u32 },
+    Float { mantissa_bits: u32 },
+    Decimal { precision: i32, scale: i32 },
+}
+
+impl NumericKind {
+    fn of(data_type: &DataType) -> Option<Self> {
+        let integer = |signed, bits| Some(Self::Integer { signed, bits });
+        let decimal = |precision: &u8, scale: &i8| {
+            Some(Self::Decimal {
+                precision: i32::from(*precision),
+                scale: i32::from(*scale),
+            })
+        };
+
+        match data_type {
+            DataType::Int8 => integer(true, 8),
+            DataType::Int16 => integer(true, 16),
+            DataType::Int32 => integer(true, 32),
+            DataType::Int64 => integer(true, 64),
+            DataType::UInt8 => integer(false, 8),
+            DataType::UInt16 => integer(false, 16),
+            DataType::UInt32 => integer(false, 32),
+            DataType::UInt64 => integer(false, 64),
+            DataType::Float16 => Some(Self::Float { mantissa_bits: 11 }),
+            DataType::Float32 => Some(Self::Float { mantissa_bits: 24 }),
+            DataType::Float64 => Some(Self::Float { mantissa_bits: 53 }),
+            DataType::Decimal32(precision, scale)
+            | DataType::Decimal64(precision, scale)
+            | DataType::Decimal128(precision, scale)
+            | DataType::Decimal256(precision, scale) => decimal(precision, scale),
+            _ => None,
+        }
+    }
+
+    /// The number of decimal digits of the largest value of an integer type,
+    /// for example 3 for `Int8` and `UInt8`
+    fn integer_digits(&self) -> i32 {
+        match self {
+            Self::Integer { signed, bits } => {
+                let max = u128::MAX >> (128 - bits + u32::from(*signed));
+                max.to_string().len() as i32
+            }
+            _ => 0,
+        }
+    }
+}
+
 /// Returns the requested type from the arguments
-fn data_type_from_args(args: &[Expr]) -> Result<DataType> {
+fn data_type_from_args(args: &[Expr], info: &dyn SimplifyInfo) -> Result<DataType> {
     let [_, type_arg] = take_function_args("arrow_cast", args)?;
 
-    let Expr::Literal(ScalarValue::Utf8(Some(val)), _) = type_arg else {
-        return exec_err!(
+    match type_arg {
+        Expr::Literal(ScalarValue::Utf8(Some(val)), _) => parse_data_type(val),
+        // the type of `b` in `arrow_cast(a, arrow_typeof(b))`
+        Expr::ScalarFunction(ScalarFunction { func, args })
+            if func.inner().as_any().is::<ArrowTypeOfFunc>() =>
+        {
+            let [arg] = take_function_args(func.name(), args)?;
+            info.get_data_type(arg)
+        }
+        _ => exec_err!(
             "arrow_cast requires its second argument to be a constant string, got {:?}",
             type_arg
-        );
-    };
+        ),
+    }
+}
 
+/// Parses a data type name such as `Int32` or `Dictionary(Int32, Utf8)`
+pub(super) fn parse_data_type(val: &str) -> Result<DataType> {
     val.parse().map_err(|e| match e {
         // If the data type cannot be parsed, return a Plan error to signal an
         // error in the input rather than a more general ArrowError
@@ -191,3 +793,203 @@ fn data_type_from_args(args: &[Expr]) -> Result<DataType> {
         e => arrow_datafusion_err!(e),
     })
 }
+
+/// Check that the optional format argument is a constant string and that the
+/// cast is from a date, time or timestamp to a string, which the format applies to
+fn validate_format_cast(
+    format_arg: Option<&ScalarValue>,
+    source_type: &DataType,
+    target_type: &DataType,
+) -> Result<()> {
+    if format_arg
+        .and_then(|sv| sv.try_as_str().flatten())
+        .is_none_or(str::is_empty)
+    {
+        return exec_err!(
+            "arrow_cast requires its third argument to be a non-empty constant string"
+        );
+    }
+
+    let is_temporal = matches!(
+        source_type,
+        DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _)
+    );
+    let is_string = matches!(
+        target_type,
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+    );
+    if !is_temporal || !is_string {
+        return plan_err!(
+            "arrow_cast only supports a format when casting a date, time or timestamp \
+            to a string, got {source_type} to {target_type}"
+        );
+    }
+
+    Ok(())
+}
+
+/// Cast `arg` to the string type `target_type`, formatting its dates, times
+/// and timestamps with the chrono `format`
+fn cast_with_format(
+    arg: &ColumnarValue,
+    target_type: &DataType,
+    format: &str,
+) -> Result<ColumnarValue> {
+    let format = Some(format);
+    let cast_options = CastOptions {
+        safe: false,
+        format_options: DEFAULT_FORMAT_OPTIONS
+            .with_date_format(format)
+            .with_datetime_format(format)
+            .with_timestamp_format(format)
+            .with_timestamp_tz_format(format)
+            .with_time_format(format),
+    };
+
+    match arg {
+        ColumnarValue::Array(array) => Ok(ColumnarValue::Array(cast_with_options(
+            array,
+            target_type,
+            &cast_options,
+        )?)),
+        ColumnarValue::Scalar(scalar) => {
+            let array =
+                cast_with_options(&scalar.to_array()?, target_type, &cast_options)?;
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &array, 0,
+            )?))
+        }
+    }
+}
+
+/// Check that a cast with an overflow mode other than [`OverflowMode::Error`]
+/// is from a numeric type to a numeric type, which values can overflow
+fn validate_overflow_cast(
+    overflow_mode: OverflowMode,
+    source_type: &DataType,
+    target_type: &DataType,
+) -> Result<()> {
+    if !source_type.is_numeric() || !target_type.is_numeric() {
+        return plan_err!(
+            "arrow_cast only supports the overflow argument when casting a numeric \
+            value to a numeric type, got {source_type} to {target_type}"
+        );
+    }
+    if overflow_mode == OverflowMode::Saturate
+        && (ScalarValue::min(target_type).is_none()
+            || ScalarValue::max(target_type).is_none())
+    {
+        return plan_err!("arrow_cast cannot saturate the values cast to {target_type}");
+    }
+
+    Ok(())
+}
+
+/// Cast the numeric `arg` to `target_type`, replacing the values which
+/// overflow it as `overflow_mode` says rather than returning an error
+fn cast_with_overflow_mode(
+    arg: ColumnarValue,
+    target_type: &DataType,
+    overflow_mode: OverflowMode,
+) -> Result<ColumnarValue> {
+    let is_scalar = matches!(arg, ColumnarValue::Scalar(_));
+    let array = arg.into_array(1)?;
+
+    // the values which overflow are NULL once cast with `safe`
+    let cast_options = CastOptions {
+        safe: true,
+        ..Default::default()
+    };
+    let mut result = cast_with_options(&array, target_type, &cast_options)?;
+
+    if overflow_mode == OverflowMode::Saturate {
+        let overflowed = and(&is_null(&result)?, &is_not_null(&array)?)?;
+        if overflowed.true_count() > 0 {
+            let (Some(min), Some(max)) =
+                (ScalarValue::min(target_type), ScalarValue::max(target_type))
+            else {
+                return internal_err!(
+                    "arrow_cast cannot saturate the values cast to {target_type}"
+                );
+            };
+            let zero = ScalarValue::new_zero(array.data_type())?.to_scalar()?;
+            let below = and(&overflowed, &lt(&array, &zero)?)?;
+            let above = and(&overflowed, &gt(&array, &zero)?)?;
+            result = zip(&below, &min.to_scalar()?, &result)?;
+            result = zip(&above, &max.to_scalar()?, &result)?;
+        }
+    }
+
+    if is_scalar {
+        Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &result, 0,
+        )?))
+    } else {
+        Ok(ColumnarValue::Array(result))
+    }
+}
+
+/// When casting a struct to a struct, check that every field of the target
+/// struct can be cast from the field with the same name in the source struct
+fn validate_struct_cast(source_type: &DataType, target_type: &DataType) -> Result<()> {
+    let (DataType::Struct(source_fields), DataType::Struct(target_fields)) =
+        (source_type, target_type)
+    else {
+        return Ok(());
+    };
+
+    for target_field in target_fields {
+        let Some((_, source_field)) = source_fields.find(target_field.name()) else {
+            return plan_err!(
+                "arrow_cast cannot find field '{}' of the target struct in the source struct",
+                target_field.name()
+            );
+        };
+
+        let (from, to) = (source_field.data_type(), target_field.data_type());
+        if matches!((from, to), (DataType::Struct(_), DataType::Struct(_))) {
+            validate_struct_cast(from, to)?;
+        } else if !can_cast_types(from, to) {
+            return plan_err!(
+                "arrow_cast cannot cast struct field '{}' from {from} to {to}",
+                target_field.name()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Cast a struct array to `target_fields`, matching the fields by name
+fn cast_struct_by_name(array: &ArrayRef, target_fields: &Fields) -> Result<ArrayRef> {
+    let struct_array = as_struct_array(array)?;
+
+    let columns = target_fields
+        .iter()
+        .map(|target_field| {
+            let Some(column) = struct_array.column_by_name(target_field.name()) else {
+                return exec_err!(
+                    "arrow_cast cannot find field '{}' of the target struct in the source struct",
+                    target_field.name()
+                );
+            };
+
+            match (column.data_type(), target_field.data_type()) {
+                (DataType::Struct(_), DataType::Struct(fields)) => {
+                    cast_struct_by_name(column, fields)
+                }
+                (_, data_type) => Ok(cast(column, data_type)?),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Arc::new(StructArray::try_new(
+        target_fields.clone(),
+        columns,
+        struct_array.nulls().cloned(),
+    )?))
+}
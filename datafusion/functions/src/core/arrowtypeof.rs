@@ -16,8 +16,11 @@
 // under the License.
 
 use arrow::datatypes::DataType;
-use datafusion_common::{utils::take_function_args, Result, ScalarValue};
-use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_common::{utils::take_function_args, ExprSchema, Result, ScalarValue};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{
+    lit, ColumnarValue, Documentation, Expr, ExprSchemable, ScalarFunctionArgs,
+};
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use datafusion_macros::user_doc;
 use std::any::Any;
@@ -83,6 +86,29 @@ impl ScalarUDFImpl for ArrowTypeOfFunc {
         ))))
     }
 
+    /// The type of the argument is known while planning, so functions taking
+    /// a type name such as `arrow_cast(a, arrow_typeof(b))` can use it before
+    /// the call is simplified
+    fn value_from_args(
+        &self,
+        args: &[Expr],
+        schema: &dyn ExprSchema,
+    ) -> Result<Option<ScalarValue>> {
+        let [arg] = take_function_args(self.name(), args)?;
+        Ok(Some(ScalarValue::from(arg.get_type(schema)?.to_string())))
+    }
+
+    /// The type of the argument is known, so the call is replaced by its name
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [arg] = take_function_args(self.name(), &args)?;
+        let data_type = info.get_data_type(arg)?;
+        Ok(ExprSimplifyResult::Simplified(lit(data_type.to_string())))
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
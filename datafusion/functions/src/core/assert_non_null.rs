@@ -0,0 +1,138 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`AssertNonNullFunc`]: Implementation of the `assert_non_null` function
+
+use arrow::array::Array;
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, internal_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the expression unchanged, but fails the query with the given message if any of its values is _null_. This can be used as a data quality check, for example after cleaning the values with `coalesce`.",
+    syntax_example = "assert_non_null(expression, message)",
+    sql_example = r#"```sql
+> select assert_non_null(column1, 'column1 must not be null') from (values (1), (2)) as t;
++-------------------------------------------------------------+
+| assert_non_null(t.column1,Utf8("column1 must not be null")) |
++-------------------------------------------------------------+
+| 1                                                           |
+| 2                                                           |
++-------------------------------------------------------------+
+> select assert_non_null(column1, 'column1 must not be null') from (values (1), (null)) as t;
+Execution error: column1 must not be null
+```"#,
+    argument(
+        name = "expression",
+        description = "Expression to check. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "message",
+        description = "Constant string with the message of the error returned if a value is _null_."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct AssertNonNullFunc {
+    signature: Signature,
+}
+
+impl Default for AssertNonNullFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AssertNonNullFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for AssertNonNullFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "assert_non_null"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [value, _] = take_function_args(self.name(), args.arg_fields)?;
+
+        if args.scalar_arguments[1]
+            .and_then(|sv| sv.try_as_str().flatten())
+            .is_none()
+        {
+            return plan_err!(
+                "assert_non_null requires the message as a constant string"
+            );
+        }
+
+        // the query fails rather than returning a NULL value
+        Ok(Arc::new(Field::new(
+            self.name(),
+            value.data_type().clone(),
+            false,
+        )))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [value, message] = take_function_args(self.name(), args.args)?;
+        let message = match &message {
+            ColumnarValue::Scalar(message) => message.try_as_str().flatten(),
+            ColumnarValue::Array(_) => None,
+        };
+        let Some(message) = message else {
+            return exec_err!(
+                "assert_non_null requires the message as a constant string"
+            );
+        };
+
+        let has_null = match &value {
+            ColumnarValue::Array(array) => array.logical_null_count() > 0,
+            ColumnarValue::Scalar(scalar) => scalar.is_null(),
+        };
+        if has_null {
+            return exec_err!("{message}");
+        }
+
+        Ok(value)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
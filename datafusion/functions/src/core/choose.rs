@@ -0,0 +1,221 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ChooseFunc`]: Implementation of the `choose` function
+
+use super::coalesce::CoalesceFunc;
+use arrow::array::{new_null_array, Array, ArrayRef};
+use arrow::compute::interleave;
+use arrow::datatypes::DataType;
+use datafusion_common::cast::as_int64_array;
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::iter;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the argument at the given position among the values, counting from 1. Returns _null_ if the index is _null_ or out of range. The values are coerced like the arguments of `coalesce`.",
+    syntax_example = "choose(index, expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select choose(2, 'a', 'b', 'c');
++------------------------------------------------+
+| choose(Int64(2),Utf8("a"),Utf8("b"),Utf8("c")) |
++------------------------------------------------+
+| b                                              |
++------------------------------------------------+
+```"#,
+    argument(
+        name = "index",
+        description = "The 1-based position of the value to return. Can be a constant, column, or function, and any combination of arithmetic operators."
+    ),
+    argument(
+        name = "expression1, expression_n",
+        description = "Expression to return if its position is the index. Can be a constant, column, or function, and any combination of arithmetic operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ChooseFunc {
+    signature: Signature,
+}
+
+impl Default for ChooseFunc {
+    fn default() -> Self {
+        ChooseFunc::new()
+    }
+}
+
+impl ChooseFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ChooseFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "choose"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match arg_types.get(1) {
+            Some(value_type) => Ok(value_type.clone()),
+            None => plan_err!("choose requires an index and at least one argument"),
+        }
+    }
+
+    /// choose evaluates per row to the value at the index
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        if args.args.len() < 2 {
+            return exec_err!(
+                "choose was called with {} arguments. It requires at least 2.",
+                args.args.len()
+            );
+        }
+        let is_scalar = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let index = as_int64_array(&arrays[0])?;
+        let values = &arrays[1..];
+
+        // index 0 of `sources` is a null value, for rows with a null or out of range index
+        let nulls = new_null_array(args.return_field.data_type(), 1);
+        let sources = iter::once(&nulls)
+            .chain(values)
+            .map(|array| array.as_ref())
+            .collect::<Vec<&dyn Array>>();
+
+        let indices = index
+            .iter()
+            .enumerate()
+            .map(|(row, index)| match index {
+                Some(index) if index >= 1 && index as u64 <= values.len() as u64 => {
+                    (index as usize, row)
+                }
+                _ => (0, 0),
+            })
+            .collect::<Vec<_>>();
+
+        let result: ArrayRef = interleave(&sources, &indices)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    /// Coerce the index to `Int64` and the other arguments to a single common
+    /// type, like `coalesce`
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let Some((index_type, value_types)) = arg_types.split_first() else {
+            return plan_err!("choose requires an index and at least one argument");
+        };
+        if value_types.is_empty() {
+            return plan_err!("choose requires an index and at least one argument");
+        }
+        if !index_type.is_integer() && !index_type.is_null() {
+            return plan_err!("choose requires an integer index, got {index_type}");
+        }
+
+        let value_types = CoalesceFunc::new().coerce_arguments(value_types)?;
+        Ok(iter::once(DataType::Int64).chain(value_types).collect())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, Int32Array, Int64Array};
+    use arrow::datatypes::{Field, Int32Type};
+    use datafusion_common::config::ConfigOptions;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_choose_per_row() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Int64Array::from(vec![
+                Some(1),
+                Some(2),
+                Some(3),
+                Some(0),
+                Some(4),
+                None,
+            ]))),
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+                Some(10),
+                Some(11),
+                Some(12),
+                Some(13),
+                Some(14),
+                Some(15),
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Int32(Some(2))),
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+                Some(30),
+                Some(31),
+                None,
+                Some(33),
+                Some(34),
+                Some(35),
+            ]))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = ChooseFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 6,
+                return_field: Field::new("f", DataType::Int32, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(6)?;
+
+        assert_eq!(
+            result
+                .as_primitive::<Int32Type>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(10), Some(2), None, None, None, None]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ClampFunc`]: Implementation of the `clamp` function
+
+use crate::core::greatest::GreatestFunc;
+use crate::core::greatest_least_utils::{
+    coerce_types, execute_conditional, find_coerced_type, normalize_timestamps,
+    propagate_nulls,
+};
+use crate::core::least::LeastFunc;
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_doc::Documentation;
+use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the value bounded to the range from `low` to `high`, the same as `least(greatest(value, low), high)`. Returns _null_ if the value is _null_, a _null_ bound is ignored. Returns `high` if `low` is greater than `high`.",
+    syntax_example = "clamp(value, low, high)",
+    sql_example = r#"```sql
+> select clamp(12, 0, 10);
++-------------------------------------+
+| clamp(Int64(12),Int64(0),Int64(10)) |
++-------------------------------------+
+| 10                                  |
++-------------------------------------+
+```"#,
+    argument(
+        name = "value",
+        description = "Expression to bound. Can be a constant, column, or function, and any combination of arithmetic operators."
+    ),
+    argument(
+        name = "low",
+        description = "Lower bound of the range, returned if the value is smaller."
+    ),
+    argument(
+        name = "high",
+        description = "Upper bound of the range, returned if the value is greater."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ClampFunc {
+    signature: Signature,
+}
+
+impl Default for ClampFunc {
+    fn default() -> Self {
+        ClampFunc::new()
+    }
+}
+
+impl ClampFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ClampFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "clamp"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        find_coerced_type::<GreatestFunc>(arg_types)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let arguments = normalize_timestamps::<GreatestFunc>(
+            args.args,
+            args.return_field.data_type(),
+            &args.config_options,
+        )?;
+        let [value, low, high] = take_function_args(self.name(), arguments)?;
+
+        let bounded_below = execute_conditional::<GreatestFunc>(&[value.clone(), low])?;
+        let result = execute_conditional::<LeastFunc>(&[bounded_below, high])?;
+
+        // the bounds ignore a NULL value, which must give NULL instead
+        propagate_nulls(&[value], result)
+    }
+
+    /// Coerce the value and the bounds to a common type, like `greatest`
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _, _] = take_function_args(self.name(), arg_types)?;
+        coerce_types::<GreatestFunc>(arg_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, Int64Array};
+    use arrow::datatypes::{Field, Int64Type};
+    use datafusion_common::config::ConfigOptions;
+    use datafusion_common::ScalarValue;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_clamp_null_value_and_bounds() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Int64Array::from(vec![
+                Some(-5),
+                Some(5),
+                Some(15),
+                None,
+                Some(-5),
+            ]))),
+            ColumnarValue::Array(Arc::new(Int64Array::from(vec![
+                Some(0),
+                Some(0),
+                Some(0),
+                Some(0),
+                None,
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(10))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = ClampFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 5,
+                return_field: Field::new("f", DataType::Int64, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(5)?;
+
+        assert_eq!(
+            result
+                .as_primitive::<Int64Type>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(0), Some(5), Some(10), None, Some(-5)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clamp_coerce_types() -> Result<()> {
+        let clamp = ClampFunc::new();
+        let coerced_types =
+            clamp.coerce_types(&[DataType::Int32, DataType::Float64, DataType::Int64])?;
+        assert_eq!(coerced_types, vec![DataType::Float64; 3]);
+        assert_eq!(clamp.return_type(&coerced_types)?, DataType::Float64);
+
+        assert!(clamp
+            .coerce_types(&[DataType::Int32, DataType::Int32])
+            .is_err());
+
+        Ok(())
+    }
+}
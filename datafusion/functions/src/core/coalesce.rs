@@ -15,19 +15,27 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::array::{new_null_array, BooleanArray};
+use arrow::array::{new_null_array, Array, ArrayRef, BooleanArray};
 use arrow::compute::kernels::zip::zip;
 use arrow::compute::{and, is_not_null, is_null};
-use arrow::datatypes::{DataType, Field, FieldRef};
+use arrow::datatypes::{
+    DataType, Field, FieldRef, TimeUnit, DECIMAL128_MAX_PRECISION,
+    DECIMAL256_MAX_PRECISION,
+};
 use datafusion_common::{exec_err, internal_err, Result};
-use datafusion_expr::binary::try_type_union_resolution;
+use datafusion_expr::binary::{comparison_coercion, try_type_union_resolution};
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion_expr::{
-    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs,
+    ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDF,
 };
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use datafusion_macros::user_doc;
 use itertools::Itertools;
 use std::any::Any;
+use std::sync::Arc;
+
+use super::coercion_utils::coerce_with_signature;
 
 #[user_doc(
     doc_section(label = "Conditional Functions"),
@@ -49,6 +57,19 @@ use std::any::Any;
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct CoalesceFunc {
     signature: Signature,
+    coercion: CoalesceCoercion,
+}
+
+/// How `coalesce` and `nvl` coerce their arguments to a common type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CoalesceCoercion {
+    /// DataFusion's type union resolution, see [`CoalesceFunc::coerce_types`]
+    #[default]
+    Default,
+    /// Spark's type precedence, where numeric and temporal arguments mixed
+    /// with string arguments are coerced to a string, like `nvl(1, 'a')` in
+    /// Spark returns `'1'`
+    Spark,
 }
 
 impl Default for CoalesceFunc {
@@ -59,10 +80,45 @@ impl Default for CoalesceFunc {
 
 impl CoalesceFunc {
     pub fn new() -> Self {
+        Self::new_with_coercion(CoalesceCoercion::default())
+    }
+
+    /// Create a new `coalesce` function coercing its arguments as `coercion`
+    pub fn new_with_coercion(coercion: CoalesceCoercion) -> Self {
         Self {
             signature: Signature::user_defined(Volatility::Immutable),
+            coercion,
         }
     }
+
+    /// Returns the types the arguments are coerced to, see
+    /// [`ScalarUDFImpl::coerce_types`]
+    pub(super) fn coerce_arguments(
+        &self,
+        arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        if arg_types.is_empty() {
+            return exec_err!("coalesce must have at least one argument");
+        }
+
+        if self.coercion == CoalesceCoercion::Spark {
+            if let Some(string_type) = spark_string_union_type(arg_types) {
+                return Ok(vec![string_type; arg_types.len()]);
+            }
+        }
+
+        if let Some(binary_type) = binary_union_type(arg_types) {
+            return Ok(vec![binary_type; arg_types.len()]);
+        }
+        if let Some(decimal_type) = decimal_union_type(arg_types) {
+            return Ok(vec![decimal_type; arg_types.len()]);
+        }
+        if let Some(timestamp_type) = timestamp_union_type(arg_types) {
+            return Ok(vec![timestamp_type; arg_types.len()]);
+        }
+
+        try_type_union_resolution(arg_types)
+    }
 }
 
 impl ScalarUDFImpl for CoalesceFunc {
@@ -106,6 +162,14 @@ impl ScalarUDFImpl for CoalesceFunc {
             );
         }
 
+        // two columns which are NULL where the other is not, like the join keys
+        // of both sides of a full outer join, are merged in a single pass
+        if let [ColumnarValue::Array(first), ColumnarValue::Array(second)] =
+            args.as_slice()
+        {
+            return Ok(ColumnarValue::Array(coalesce_arrays(first, second)?));
+        }
+
         let return_type = args[0].data_type();
         let mut return_array = args.iter().filter_map(|x| match x {
             ColumnarValue::Array(array) => Some(array.len()),
@@ -156,15 +220,385 @@ impl ScalarUDFImpl for CoalesceFunc {
         true
     }
 
-    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
-        if arg_types.is_empty() {
-            return exec_err!("coalesce must have at least one argument");
+    /// The arguments after the first one that can't be NULL, such as the
+    /// literal `0` in `coalesce(a, 0, b)`, are never returned, so they are
+    /// dropped. The result is then not nullable.
+    fn simplify(
+        &self,
+        mut args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let mut non_null = None;
+        for (i, arg) in args.iter().enumerate() {
+            if !info.nullable(arg)? {
+                non_null = Some(i);
+                break;
+            }
         }
+        let Some(i) = non_null.filter(|i| i + 1 < args.len()) else {
+            return Ok(ExprSimplifyResult::Original(args));
+        };
 
-        try_type_union_resolution(arg_types)
+        args.truncate(i + 1);
+        if args.len() == 1 {
+            return Ok(ExprSimplifyResult::Simplified(args.remove(0)));
+        }
+        let func = ScalarUDF::from(Self::new_with_coercion(self.coercion));
+        Ok(ExprSimplifyResult::Simplified(Expr::ScalarFunction(
+            ScalarFunction::new_udf(Arc::new(func), args),
+        )))
+    }
+
+    /// Coerce all arguments to a single common type.
+    ///
+    /// The common type follows DataFusion's type union resolution rules:
+    /// numeric arguments are widened to the narrowest numeric type that can
+    /// hold all of them, and string arguments are treated as "unknown" and
+    /// cast to the type of the other arguments. For example
+    /// `coalesce(int_col, '1')` coerces `'1'` to the integer type of `int_col`
+    /// rather than silently widening `int_col` to `Utf8`, and
+    /// `coalesce(int_col, 'abc')` fails when `'abc'` is cast to that type.
+    ///
+    /// Binary arguments are coerced to a common binary type, keeping
+    /// `BinaryView` if any argument is one.
+    ///
+    /// Decimal arguments are coerced to a decimal type with the largest scale
+    /// and enough precision for all of them, see [`decimal_union_type`].
+    ///
+    /// With [`CoalesceCoercion::Spark`], numeric and temporal arguments mixed
+    /// with string arguments are coerced to a string instead, see
+    /// [`spark_string_union_type`].
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_with_signature(self.name(), "T, ...", arg_types, || {
+            self.coerce_arguments(arg_types)
+        })
     }
 
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
 }
+
+/// Returns whether `data_type` is one of the binary types
+pub(super) fn is_binary_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Binary
+            | DataType::LargeBinary
+            | DataType::BinaryView
+            | DataType::FixedSizeBinary(_)
+    )
+}
+
+/// Returns the string type the arguments are coerced to following Spark's
+/// type precedence, if any argument is a string and all the others are
+/// strings, numbers, dates, times, timestamps or null.
+///
+/// Booleans, binaries and nested types mixed with strings are left to the
+/// default coercion, as in Spark.
+pub(super) fn spark_string_union_type(arg_types: &[DataType]) -> Option<DataType> {
+    let is_string = |t: &DataType| {
+        matches!(t, DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View)
+    };
+    let string_types = arg_types
+        .iter()
+        .filter(|t| is_string(t))
+        .cloned()
+        .collect::<Vec<_>>();
+    if string_types.is_empty()
+        || !arg_types.iter().all(|t| {
+            is_string(t)
+                || t.is_null()
+                || t.is_numeric()
+                || matches!(
+                    t,
+                    DataType::Date32
+                        | DataType::Date64
+                        | DataType::Time32(_)
+                        | DataType::Time64(_)
+                        | DataType::Timestamp(_, _)
+                )
+        })
+    {
+        return None;
+    }
+
+    try_type_union_resolution(&string_types)
+        .ok()
+        .and_then(|types| types.into_iter().next())
+}
+
+/// Returns the common binary type of `arg_types` if they are all binary or
+/// null, as type union resolution does not handle binary types
+fn binary_union_type(arg_types: &[DataType]) -> Option<DataType> {
+    let mut binary_types = arg_types.iter().filter(|t| !t.is_null());
+    let first = binary_types.next().filter(|t| is_binary_type(t))?;
+    binary_types.try_fold(first.clone(), |acc, t| {
+        is_binary_type(t)
+            .then(|| comparison_coercion(&acc, t))
+            .flatten()
+    })
+}
+
+/// Returns the decimal type holding every value of `arg_types` if they are
+/// all decimals or null.
+///
+/// Type union resolution caps the precision at the one of `Decimal128`, so
+/// `Decimal128(38, 0)` and `Decimal128(10, 6)` would be coerced to
+/// `Decimal128(38, 6)` which cannot hold the largest values of the first one.
+/// `Decimal256` is used instead when the precision does not fit `Decimal128`.
+fn decimal_union_type(arg_types: &[DataType]) -> Option<DataType> {
+    let decimals = arg_types
+        .iter()
+        .filter(|t| !t.is_null())
+        .map(|t| match t {
+            DataType::Decimal128(precision, scale)
+            | DataType::Decimal256(precision, scale) => Some((*precision, *scale)),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let scale = decimals.iter().map(|(_, scale)| *scale).max()?;
+    let integer_digits = decimals
+        .iter()
+        .map(|(precision, scale)| *precision as i16 - *scale as i16)
+        .max()?;
+    let precision = integer_digits + scale as i16;
+
+    let is_decimal256 = arg_types
+        .iter()
+        .any(|t| matches!(t, DataType::Decimal256(_, _)));
+    if !is_decimal256 && precision <= DECIMAL128_MAX_PRECISION as i16 {
+        Some(DataType::Decimal128(precision as u8, scale))
+    } else if precision <= DECIMAL256_MAX_PRECISION as i16 {
+        Some(DataType::Decimal256(precision as u8, scale))
+    } else {
+        None
+    }
+}
+
+/// Returns the timestamp type holding every value of `arg_types` if they are
+/// all timestamps or null.
+///
+/// Type union resolution picks the coarsest unit, so `Timestamp(Millisecond)`
+/// and `Timestamp(Nanosecond)` would be coerced to `Timestamp(Millisecond)`,
+/// truncating the sub-millisecond part of the second one. The finest unit is
+/// used instead, with the first timezone among the arguments.
+fn timestamp_union_type(arg_types: &[DataType]) -> Option<DataType> {
+    let timestamps = arg_types
+        .iter()
+        .filter(|t| !t.is_null())
+        .map(|t| match t {
+            DataType::Timestamp(unit, tz) => Some((unit, tz)),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let unit =
+        timestamps
+            .iter()
+            .map(|(unit, _)| **unit)
+            .max_by_key(|unit| match unit {
+                TimeUnit::Second => 0,
+                TimeUnit::Millisecond => 1,
+                TimeUnit::Microsecond => 2,
+                TimeUnit::Nanosecond => 3,
+            })?;
+    let tz = timestamps.iter().find_map(|&(_, tz)| tz.clone());
+
+    Some(DataType::Timestamp(unit, tz))
+}
+
+/// Returns the values of `first` where it is not NULL and the values of
+/// `second` elsewhere, using the validity of `first` as the mask of a single
+/// `zip`. The result has no NULL value when the NULL values of `first` and
+/// `second` are complementary.
+fn coalesce_arrays(first: &ArrayRef, second: &ArrayRef) -> Result<ArrayRef> {
+    let Some(nulls) = first.logical_nulls() else {
+        return Ok(Arc::clone(first));
+    };
+    let mask = BooleanArray::new(nulls.into_inner(), None);
+    Ok(zip(&mask, first, second)?)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::coalesce::{CoalesceCoercion, CoalesceFunc};
+    use arrow::array::{Array, ArrayRef, Int64Array};
+    use arrow::datatypes::{DataType, Field, TimeUnit};
+    use datafusion_common::config::ConfigOptions;
+    use datafusion_common::Result;
+    use datafusion_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_coalesce_complementary_nulls() -> Result<()> {
+        // the keys of both sides of a full outer join
+        let left: ArrayRef =
+            Arc::new(Int64Array::from(vec![Some(1), None, Some(3), None]));
+        let right: ArrayRef =
+            Arc::new(Int64Array::from(vec![None, Some(2), None, Some(4)]));
+        let invoke = |args: Vec<ArrayRef>| {
+            CoalesceFunc::new()
+                .invoke_with_args(ScalarFunctionArgs {
+                    arg_fields: args
+                        .iter()
+                        .map(|arg| Field::new("a", arg.data_type().clone(), true).into())
+                        .collect(),
+                    args: args.into_iter().map(ColumnarValue::Array).collect(),
+                    number_rows: 4,
+                    return_field: Field::new("f", DataType::Int64, true).into(),
+                    config_options: Arc::new(ConfigOptions::default()),
+                })?
+                .into_array(4)
+        };
+
+        let result = invoke(vec![Arc::clone(&left), Arc::clone(&right)])?;
+        assert_eq!(result.null_count(), 0);
+        assert_eq!(
+            result.as_ref(),
+            &Int64Array::from(vec![1, 2, 3, 4]) as &dyn Array
+        );
+
+        // the NULL values of both arrays may overlap, or not be NULL in either
+        let other: ArrayRef =
+            Arc::new(Int64Array::from(vec![Some(10), None, None, Some(40)]));
+        let result = invoke(vec![Arc::clone(&left), other])?;
+        assert_eq!(
+            result.as_ref(),
+            &Int64Array::from(vec![Some(1), None, Some(3), Some(40)]) as &dyn Array
+        );
+
+        // the result matches the one of the general case with a third argument
+        let with_null: ArrayRef = Arc::new(Int64Array::from(vec![None::<i64>; 4]));
+        let result = invoke(vec![left, right, with_null])?;
+        assert_eq!(
+            result.as_ref(),
+            &Int64Array::from(vec![1, 2, 3, 4]) as &dyn Array
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_coerce_numeric_types() {
+        let coalesce = CoalesceFunc::new();
+        let coerced = coalesce
+            .coerce_types(&[DataType::Int8, DataType::Int32, DataType::Float32])
+            .unwrap();
+        assert_eq!(coerced, vec![DataType::Float32; 3]);
+
+        let coerced = coalesce
+            .coerce_types(&[DataType::Int32, DataType::Null, DataType::Int64])
+            .unwrap();
+        assert_eq!(coerced, vec![DataType::Int64; 3]);
+    }
+
+    #[test]
+    fn test_coalesce_coerce_numeric_and_string_to_numeric() {
+        let coalesce = CoalesceFunc::new();
+        let coerced = coalesce
+            .coerce_types(&[DataType::Int32, DataType::Utf8])
+            .unwrap();
+        assert_eq!(coerced, vec![DataType::Int32, DataType::Int32]);
+    }
+
+    #[test]
+    fn test_coalesce_coerce_binary_view() {
+        let coalesce = CoalesceFunc::new();
+        let coerced = coalesce
+            .coerce_types(&[DataType::BinaryView, DataType::Null, DataType::Binary])
+            .unwrap();
+        assert_eq!(coerced, vec![DataType::BinaryView; 3]);
+
+        let coerced = coalesce
+            .coerce_types(&[DataType::Binary, DataType::LargeBinary])
+            .unwrap();
+        assert_eq!(coerced, vec![DataType::LargeBinary; 2]);
+    }
+
+    #[test]
+    fn test_coalesce_coerce_decimals() {
+        let coalesce = CoalesceFunc::new();
+        let coerced = coalesce
+            .coerce_types(&[
+                DataType::Decimal128(10, 2),
+                DataType::Null,
+                DataType::Decimal128(14, 6),
+            ])
+            .unwrap();
+        assert_eq!(coerced, vec![DataType::Decimal128(14, 6); 3]);
+
+        // the precision needed for both exceeds the one of Decimal128
+        let coerced = coalesce
+            .coerce_types(&[DataType::Decimal128(38, 0), DataType::Decimal128(10, 6)])
+            .unwrap();
+        assert_eq!(coerced, vec![DataType::Decimal256(44, 6); 2]);
+
+        let coerced = coalesce
+            .coerce_types(&[DataType::Decimal128(10, 8), DataType::Decimal256(20, 0)])
+            .unwrap();
+        assert_eq!(coerced, vec![DataType::Decimal256(28, 8); 2]);
+    }
+
+    #[test]
+    fn test_coalesce_coerce_timestamps() {
+        let coalesce = CoalesceFunc::new();
+        let coerced = coalesce
+            .coerce_types(&[
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                DataType::Null,
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+            ])
+            .unwrap();
+        assert_eq!(
+            coerced,
+            vec![DataType::Timestamp(TimeUnit::Nanosecond, None); 3]
+        );
+
+        let coerced = coalesce
+            .coerce_types(&[
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                DataType::Timestamp(TimeUnit::Second, Some("+01:00".into())),
+            ])
+            .unwrap();
+        assert_eq!(
+            coerced,
+            vec![DataType::Timestamp(TimeUnit::Microsecond, Some("+01:00".into())); 2]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_spark_coercion() {
+        let default = CoalesceFunc::new();
+        let spark = CoalesceFunc::new_with_coercion(CoalesceCoercion::Spark);
+
+        let arg_types = [DataType::Int32, DataType::Null, DataType::Utf8];
+        assert_eq!(
+            default.coerce_types(&arg_types).unwrap(),
+            vec![DataType::Int32; 3]
+        );
+        assert_eq!(
+            spark.coerce_types(&arg_types).unwrap(),
+            vec![DataType::Utf8; 3]
+        );
+
+        let arg_types = [DataType::Date32, DataType::LargeUtf8, DataType::Utf8];
+        assert_eq!(
+            spark.coerce_types(&arg_types).unwrap(),
+            vec![DataType::LargeUtf8; 3]
+        );
+
+        // arguments without strings or with booleans are coerced as by default
+        let arg_types = [DataType::Int32, DataType::Float64];
+        assert_eq!(
+            spark.coerce_types(&arg_types).unwrap(),
+            default.coerce_types(&arg_types).unwrap()
+        );
+        let arg_types = [DataType::Boolean, DataType::Utf8];
+        assert_eq!(
+            spark.coerce_types(&arg_types).ok(),
+            default.coerce_types(&arg_types).ok()
+        );
+    }
+}
@@ -0,0 +1,288 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CoalesceFiniteFunc`]: Implementation of the `coalesce_finite` function
+
+use arrow::array::{new_null_array, Array, ArrayRef, AsArray, BooleanArray};
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{and, and_not};
+use arrow::datatypes::{DataType, Float32Type, Float64Type};
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::binary::try_type_union_resolution;
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the first of its arguments that is finite, skipping _null_, _NaN_ and infinite values. Returns _null_ if no argument is finite. The arguments are coerced to `Float32` if their common type is `Float16` or `Float32`, and to `Float64` otherwise.",
+    syntax_example = "coalesce_finite(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select coalesce_finite(null, 'Infinity'::double, 'NaN'::double, 2.5);
++---------------------------------------------------------------------------------------------------+
+| coalesce_finite(NULL,CAST(Utf8("Infinity") AS Float64),CAST(Utf8("NaN") AS Float64),Float64(2.5)) |
++---------------------------------------------------------------------------------------------------+
+| 2.5                                                                                               |
++---------------------------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Numeric expression to use if previous expressions are not finite. Can be a constant, column, or function, and any combination of arithmetic operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CoalesceFiniteFunc {
+    signature: Signature,
+}
+
+impl Default for CoalesceFiniteFunc {
+    fn default() -> Self {
+        CoalesceFiniteFunc::new()
+    }
+}
+
+impl CoalesceFiniteFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns whether the scalar holds a finite float, that is not null, NaN or infinite
+fn is_finite_scalar(value: &ScalarValue) -> bool {
+    match value {
+        ScalarValue::Float32(value) => value.is_some_and(f32::is_finite),
+        ScalarValue::Float64(value) => value.is_some_and(f64::is_finite),
+        _ => false,
+    }
+}
+
+/// Returns a mask of the rows of `array` holding a finite float
+fn finite(array: &ArrayRef) -> Result<BooleanArray> {
+    let mask = match array.data_type() {
+        DataType::Float32 => array
+            .as_primitive::<Float32Type>()
+            .iter()
+            .map(|value| Some(value.is_some_and(f32::is_finite)))
+            .collect(),
+        DataType::Float64 => array
+            .as_primitive::<Float64Type>()
+            .iter()
+            .map(|value| Some(value.is_some_and(f64::is_finite)))
+            .collect(),
+        other => return internal_err!("coalesce_finite does not support type {other}"),
+    };
+    Ok(mask)
+}
+
+impl ScalarUDFImpl for CoalesceFiniteFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "coalesce_finite"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    /// coalesce_finite evaluates to the first value which is finite
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let args = args.args;
+        if args.is_empty() {
+            return exec_err!(
+                "coalesce_finite was called with {} arguments. It requires at least 1.",
+                args.len()
+            );
+        }
+
+        let return_type = args[0].data_type();
+        let size = args.iter().find_map(|x| match x {
+            ColumnarValue::Array(array) => Some(array.len()),
+            _ => None,
+        });
+
+        let Some(size) = size else {
+            let result = args
+                .iter()
+                .find(|x| match x {
+                    ColumnarValue::Scalar(value) => is_finite_scalar(value),
+                    _ => false,
+                })
+                .cloned()
+                .unwrap_or(ColumnarValue::Scalar(ScalarValue::try_from(&return_type)?));
+            return Ok(result);
+        };
+
+        // start with nulls as default output
+        let mut current_value = new_null_array(&return_type, size);
+        let mut remainder = BooleanArray::from(vec![true; size]);
+
+        for arg in args {
+            match arg {
+                ColumnarValue::Array(ref array) => {
+                    let finite = finite(array)?;
+                    let to_apply = and(&remainder, &finite)?;
+                    current_value = zip(&to_apply, array, &current_value)?;
+                    remainder = and_not(&remainder, &finite)?;
+                }
+                ColumnarValue::Scalar(value) => {
+                    if !is_finite_scalar(&value) {
+                        continue;
+                    }
+                    current_value = zip(&remainder, &value.to_scalar()?, &current_value)?;
+                    break;
+                }
+            }
+            if remainder.true_count() == 0 {
+                break;
+            }
+        }
+        Ok(ColumnarValue::Array(current_value))
+    }
+
+    fn short_circuits(&self) -> bool {
+        true
+    }
+
+    /// Coerce all arguments to a single float type
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.is_empty() {
+            return exec_err!("coalesce_finite must have at least one argument");
+        }
+
+        if let Some(data_type) = arg_types
+            .iter()
+            .find(|data_type| !data_type.is_numeric() && !data_type.is_null())
+        {
+            return plan_err!(
+                "coalesce_finite requires numeric arguments, got {data_type}"
+            );
+        }
+
+        let coerced_type = match &try_type_union_resolution(arg_types)?[0] {
+            DataType::Float16 | DataType::Float32 => DataType::Float32,
+            DataType::Null => DataType::Float64,
+            _ => DataType::Float64,
+        };
+        Ok(vec![coerced_type; arg_types.len()])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::Float64Array;
+    use arrow::datatypes::Field;
+    use datafusion_common::config::ConfigOptions;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_coalesce_finite_skips_non_finite() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Float64Array::from(vec![
+                Some(f64::INFINITY),
+                Some(f64::NEG_INFINITY),
+                Some(f64::NAN),
+                None,
+                Some(1.5),
+            ]))),
+            ColumnarValue::Array(Arc::new(Float64Array::from(vec![
+                Some(2.0),
+                Some(f64::NAN),
+                None,
+                Some(f64::INFINITY),
+                Some(2.5),
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Float64(Some(3.0))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = CoalesceFiniteFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 5,
+                return_field: Field::new("f", DataType::Float64, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(5)?;
+
+        let expected = Float64Array::from(vec![2.0, 3.0, 3.0, 3.0, 1.5]);
+        assert_eq!(result.as_primitive::<Float64Type>(), &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coalesce_finite_coerce_types() -> Result<()> {
+        let func = CoalesceFiniteFunc::new();
+        assert_eq!(
+            func.coerce_types(&[DataType::Float32, DataType::Null])?,
+            vec![DataType::Float32; 2]
+        );
+        assert_eq!(
+            func.coerce_types(&[DataType::Float32, DataType::Int64])?,
+            vec![DataType::Float32; 2]
+        );
+        assert_eq!(
+            func.coerce_types(&[DataType::Float32, DataType::Float64])?,
+            vec![DataType::Float64; 2]
+        );
+        assert_eq!(
+            func.coerce_types(&[DataType::Int32, DataType::Null])?,
+            vec![DataType::Float64; 2]
+        );
+        assert!(func
+            .coerce_types(&[DataType::Float64, DataType::Utf8])
+            .is_err());
+
+        // all scalars
+        let result = func.invoke_with_args(ScalarFunctionArgs {
+            args: vec![
+                ColumnarValue::Scalar(ScalarValue::Float32(Some(f32::NEG_INFINITY))),
+                ColumnarValue::Scalar(ScalarValue::Float32(None)),
+                ColumnarValue::Scalar(ScalarValue::Float32(Some(0.5))),
+            ],
+            arg_fields: vec![Field::new("a", DataType::Float32, true).into(); 3],
+            number_rows: 1,
+            return_field: Field::new("f", DataType::Float32, true).into(),
+            config_options: Arc::new(ConfigOptions::default()),
+        })?;
+        let ColumnarValue::Scalar(value) = result else {
+            return internal_err!("expected a scalar result");
+        };
+        assert_eq!(value, ScalarValue::Float32(Some(0.5)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CoalesceFlaggedFunc`]: Implementation of the `coalesce_flagged` function
+
+use super::coercion_utils::coerce_with_signature;
+use super::nvl::{nvl_func, SUPPORTED_NVL_TYPES};
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::compute::is_null;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::{internal_err, utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::{
+    type_coercion::functions::data_types, ColumnarValue, Documentation, ReturnFieldArgs,
+    ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns a struct with _expression2_ if _expression1_ is _null_ and _expression1_ otherwise as `value`, like [nvl](#nvl), and whether _expression1_ is _null_ as `was_null`, like [was_null](#was_null). This both fills the _null_ values and tracks which values were filled.",
+    syntax_example = "coalesce_flagged(expression1, expression2)",
+    sql_example = r#"```sql
+> select coalesce_flagged(null, 'a');
++----------------------------------+
+| coalesce_flagged(NULL,Utf8("a")) |
++----------------------------------+
+| {value: a, was_null: true}       |
++----------------------------------+
+> select coalesce_flagged('b', 'a');
++---------------------------------------+
+| coalesce_flagged(Utf8("b"),Utf8("a")) |
++---------------------------------------+
+| {value: b, was_null: false}           |
++---------------------------------------+
+```"#,
+    argument(
+        name = "expression1",
+        description = "Expression to test for _null_ and return if it is not _null_. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "expression2",
+        description = "Expression to return if expression1 is _null_. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CoalesceFlaggedFunc {
+    signature: Signature,
+}
+
+impl Default for CoalesceFlaggedFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CoalesceFlaggedFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the fields of the struct returned for arguments of type `value_type`
+fn result_fields(value_type: DataType, nullable: bool) -> Fields {
+    Fields::from(vec![
+        Field::new("value", value_type, nullable),
+        Field::new("was_null", DataType::Boolean, false),
+    ])
+}
+
+impl ScalarUDFImpl for CoalesceFlaggedFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "coalesce_flagged"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [value, default] = take_function_args(self.name(), args.arg_fields)?;
+        // the value is only NULL when both arguments are NULL
+        let nullable = value.is_nullable() && default.is_nullable();
+        let fields = result_fields(value.data_type().clone(), nullable);
+        Ok(Field::new(self.name(), DataType::Struct(fields), false).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
+            return internal_err!("incorrect coalesce_flagged return type");
+        };
+        let size = args.args.iter().find_map(|arg| match arg {
+            ColumnarValue::Array(array) => Some(array.len()),
+            _ => None,
+        });
+        let is_scalar = size.is_none();
+        let size = size.unwrap_or(1);
+
+        let value = nvl_func(&args.args)?.into_array(size)?;
+        let [arg, _] = take_function_args(self.name(), args.args)?;
+        let was_null = is_null(&arg.into_array(size)?)?;
+
+        let columns: Vec<ArrayRef> = vec![value, Arc::new(was_null)];
+        let result = StructArray::try_new(fields.clone(), columns, None)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+
+    /// Coerce both arguments to a common type, like the two arguments of `nvl`
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_with_signature(self.name(), "T, T", arg_types, || {
+            let [_, _] = take_function_args(self.name(), arg_types)?;
+            data_types(
+                self.name(),
+                arg_types,
+                &Signature::uniform(
+                    2,
+                    SUPPORTED_NVL_TYPES.to_vec(),
+                    Volatility::Immutable,
+                ),
+            )
+        })
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, Int32Array};
+    use arrow::datatypes::Int32Type;
+    use datafusion_common::config::ConfigOptions;
+
+    #[test]
+    fn test_coalesce_flagged() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+                Some(1),
+                None,
+                Some(3),
+                None,
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Int32(Some(0))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+        let fields = result_fields(DataType::Int32, true);
+
+        let result = CoalesceFlaggedFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args: args.clone(),
+                arg_fields,
+                number_rows: 4,
+                return_field: Field::new("f", DataType::Struct(fields), false).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(4)?;
+
+        let result = result.as_struct();
+        let values = result.column(0).as_primitive::<Int32Type>();
+        let was_null = result.column(1).as_boolean();
+        // the value is the one returned by nvl
+        let expected = nvl_func(&args)?.into_array(4)?;
+        assert_eq!(values, expected.as_primitive::<Int32Type>());
+        // the flags are the null mask of the first argument
+        assert_eq!(
+            was_null.iter().collect::<Vec<_>>(),
+            vec![Some(false), Some(true), Some(false), Some(true)]
+        );
+        assert_eq!(result.null_count(), 0);
+
+        Ok(())
+    }
+}
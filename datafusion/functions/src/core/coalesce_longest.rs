@@ -0,0 +1,235 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CoalesceLongestFunc`]: Implementation of the `coalesce_longest` function
+
+use arrow::array::{new_null_array, Array, ArrayRef, AsArray, BooleanArray, Int64Array};
+use arrow::compute::kernels::zip::zip;
+use arrow::datatypes::{DataType, Int64Type};
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::binary::try_type_union_resolution;
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use itertools::Itertools;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the longest of its arguments that are not _null_, measured in characters. When several arguments have the greatest length, the first of them is returned. Returns _null_ if all arguments are _null_.",
+    syntax_example = "coalesce_longest(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select coalesce_longest(null, 'Main St', 'Main Street');
++------------------------------------------------------------+
+| coalesce_longest(NULL,Utf8("Main St"),Utf8("Main Street")) |
++------------------------------------------------------------+
+| Main Street                                                |
++------------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "String expression to compare with the other expressions. Can be a constant, column, or function, and any combination of string operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CoalesceLongestFunc {
+    signature: Signature,
+}
+
+impl Default for CoalesceLongestFunc {
+    fn default() -> Self {
+        CoalesceLongestFunc::new()
+    }
+}
+
+impl CoalesceLongestFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the length in characters of the strings of `array`, or NULL for
+/// NULL strings
+fn char_lengths(array: &ArrayRef) -> Result<Int64Array> {
+    let char_length = |value: &str| value.chars().count() as i64;
+    let lengths = match array.data_type() {
+        DataType::Utf8 => array
+            .as_string::<i32>()
+            .iter()
+            .map(|value| value.map(char_length))
+            .collect(),
+        DataType::LargeUtf8 => array
+            .as_string::<i64>()
+            .iter()
+            .map(|value| value.map(char_length))
+            .collect(),
+        DataType::Utf8View => array
+            .as_string_view()
+            .iter()
+            .map(|value| value.map(char_length))
+            .collect(),
+        other => return internal_err!("coalesce_longest does not support type {other}"),
+    };
+    Ok(lengths)
+}
+
+impl ScalarUDFImpl for CoalesceLongestFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "coalesce_longest"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    /// coalesce_longest evaluates to the first of the longest non-null values
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let ScalarFunctionArgs {
+            args, number_rows, ..
+        } = args;
+        if args.is_empty() {
+            return exec_err!(
+                "coalesce_longest was called with {} arguments. It requires at least 1.",
+                args.len()
+            );
+        }
+
+        let return_type = args[0].data_type();
+        let all_scalars = args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+        let size = if all_scalars { 1 } else { number_rows };
+
+        // start with nulls as default output
+        let mut current_value = new_null_array(&return_type, size);
+        let mut current_length = Int64Array::new_null(size);
+
+        for arg in args {
+            let array = arg.into_array(size)?;
+            let length = char_lengths(&array)?;
+
+            // only a strictly longer value replaces the current one, so the
+            // first of the longest values is kept
+            let longer = length
+                .iter()
+                .zip(current_length.iter())
+                .map(|(length, current)| match (length, current) {
+                    (Some(length), Some(current)) => Some(length > current),
+                    (Some(_), None) => Some(true),
+                    (None, _) => Some(false),
+                })
+                .collect::<BooleanArray>();
+
+            current_value = zip(&longer, &array, &current_value)?;
+            let length: ArrayRef = Arc::new(length);
+            current_length = zip(&longer, &length, &current_length)?
+                .as_primitive::<Int64Type>()
+                .clone();
+        }
+
+        if all_scalars {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &current_value,
+                0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(current_value))
+        }
+    }
+
+    /// Coerce all arguments to a single string type
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.is_empty() {
+            return exec_err!("coalesce_longest must have at least one argument");
+        }
+
+        let coerced_types = try_type_union_resolution(arg_types)?;
+        match &coerced_types[0] {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+                Ok(coerced_types)
+            }
+            DataType::Null => Ok(vec![DataType::Utf8; arg_types.len()]),
+            _ => plan_err!(
+                "coalesce_longest requires string arguments, got {}",
+                arg_types.iter().join(", ")
+            ),
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::StringArray;
+    use arrow::datatypes::Field;
+    use datafusion_common::config::ConfigOptions;
+
+    #[test]
+    fn test_coalesce_longest_first_of_longest() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(StringArray::from(vec![
+                Some("ab"),
+                None,
+                Some("xy"),
+                None,
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("éé".to_string()))),
+            ColumnarValue::Array(Arc::new(StringArray::from(vec![
+                Some("abc"),
+                Some("c"),
+                Some("z"),
+                None,
+            ]))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = CoalesceLongestFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 4,
+                return_field: Field::new("f", DataType::Utf8, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(4)?;
+
+        // "éé" is two characters long, even though it takes four bytes
+        let expected =
+            StringArray::from(vec![Some("abc"), Some("éé"), Some("xy"), Some("éé")]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        Ok(())
+    }
+}
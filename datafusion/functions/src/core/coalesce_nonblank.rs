@@ -0,0 +1,243 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CoalesceNonBlankFunc`]: Implementation of the `coalesce_nonblank` function
+
+use arrow::array::{new_null_array, Array, ArrayRef, AsArray, BooleanArray};
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{and, and_not};
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::binary::try_type_union_resolution;
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use itertools::Itertools;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the first of its arguments that is not blank, where blank means _null_, empty or only whitespace. The argument is returned as is, without trimming its whitespace. Returns _null_ if all arguments are blank.",
+    syntax_example = "coalesce_nonblank(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select coalesce_nonblank(null, '  ', ' datafusion ');
++---------------------------------------------------------+
+| coalesce_nonblank(NULL,Utf8("  "),Utf8(" datafusion ")) |
++---------------------------------------------------------+
+|  datafusion                                             |
++---------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "String expression to use if previous expressions are blank. Can be a constant, column, or function, and any combination of string operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CoalesceNonBlankFunc {
+    signature: Signature,
+}
+
+impl Default for CoalesceNonBlankFunc {
+    fn default() -> Self {
+        CoalesceNonBlankFunc::new()
+    }
+}
+
+impl CoalesceNonBlankFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns whether the string is not blank, that is not empty or only whitespace
+fn is_non_blank(value: &str) -> bool {
+    !value.trim().is_empty()
+}
+
+/// Returns a mask of the rows of `array` holding a non blank string
+fn non_blank(array: &ArrayRef) -> Result<BooleanArray> {
+    let mask = match array.data_type() {
+        DataType::Utf8 => array
+            .as_string::<i32>()
+            .iter()
+            .map(|value| Some(value.is_some_and(is_non_blank)))
+            .collect(),
+        DataType::LargeUtf8 => array
+            .as_string::<i64>()
+            .iter()
+            .map(|value| Some(value.is_some_and(is_non_blank)))
+            .collect(),
+        DataType::Utf8View => array
+            .as_string_view()
+            .iter()
+            .map(|value| Some(value.is_some_and(is_non_blank)))
+            .collect(),
+        other => return internal_err!("coalesce_nonblank does not support type {other}"),
+    };
+    Ok(mask)
+}
+
+impl ScalarUDFImpl for CoalesceNonBlankFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "coalesce_nonblank"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    /// coalesce_nonblank evaluates to the first value which is not blank
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let args = args.args;
+        if args.is_empty() {
+            return exec_err!(
+                "coalesce_nonblank was called with {} arguments. It requires at least 1.",
+                args.len()
+            );
+        }
+
+        let return_type = args[0].data_type();
+        let size = args.iter().find_map(|x| match x {
+            ColumnarValue::Array(array) => Some(array.len()),
+            _ => None,
+        });
+
+        let Some(size) = size else {
+            let result = args
+                .iter()
+                .find(|x| match x {
+                    ColumnarValue::Scalar(value) => {
+                        value.try_as_str().flatten().is_some_and(is_non_blank)
+                    }
+                    _ => false,
+                })
+                .cloned()
+                .unwrap_or(ColumnarValue::Scalar(ScalarValue::try_from(&return_type)?));
+            return Ok(result);
+        };
+
+        // start with nulls as default output
+        let mut current_value = new_null_array(&return_type, size);
+        let mut remainder = BooleanArray::from(vec![true; size]);
+
+        for arg in args {
+            match arg {
+                ColumnarValue::Array(ref array) => {
+                    let non_blank = non_blank(array)?;
+                    let to_apply = and(&remainder, &non_blank)?;
+                    current_value = zip(&to_apply, array, &current_value)?;
+                    remainder = and_not(&remainder, &non_blank)?;
+                }
+                ColumnarValue::Scalar(value) => {
+                    if !value.try_as_str().flatten().is_some_and(is_non_blank) {
+                        continue;
+                    }
+                    current_value = zip(&remainder, &value.to_scalar()?, &current_value)?;
+                    break;
+                }
+            }
+            if remainder.true_count() == 0 {
+                break;
+            }
+        }
+        Ok(ColumnarValue::Array(current_value))
+    }
+
+    fn short_circuits(&self) -> bool {
+        true
+    }
+
+    /// Coerce all arguments to a single string type
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.is_empty() {
+            return exec_err!("coalesce_nonblank must have at least one argument");
+        }
+
+        let coerced_types = try_type_union_resolution(arg_types)?;
+        match &coerced_types[0] {
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => {
+                Ok(coerced_types)
+            }
+            DataType::Null => Ok(vec![DataType::Utf8; arg_types.len()]),
+            _ => plan_err!(
+                "coalesce_nonblank requires string arguments, got {}",
+                arg_types.iter().join(", ")
+            ),
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::StringArray;
+    use arrow::datatypes::Field;
+    use datafusion_common::config::ConfigOptions;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_coalesce_nonblank_untrimmed() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(StringArray::from(vec![
+                Some(" a "),
+                None,
+                Some(""),
+                Some(" \t"),
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("   ".to_string()))),
+            ColumnarValue::Array(Arc::new(StringArray::from(vec![
+                Some("b"),
+                Some("c "),
+                None,
+                Some(" "),
+            ]))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = CoalesceNonBlankFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 4,
+                return_field: Field::new("f", DataType::Utf8, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(4)?;
+
+        let expected = StringArray::from(vec![Some(" a "), Some("c "), None, None]);
+        assert_eq!(result.as_string::<i32>(), &expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CoalesceScanFunc`]: Implementation of the `coalesce_scan` function
+
+use arrow::array::Array;
+use arrow::compute::interleave;
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+use super::coalesce::CoalesceFunc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Replaces the _null_ values of an expression with the last value before them that is not _null_, and the leading _null_ values with the default. The values are only filled within a batch of rows, from the previous rows of the same batch, so the result depends on how the rows are batched and ordered.",
+    syntax_example = "coalesce_scan(expression, default)",
+    sql_example = r#"```sql
+> select v, coalesce_scan(v, 0) from (values (null), (1), (null), (null), (2)) t(v);
++---+-----------------------------+
+| v | coalesce_scan(t.v,Int64(0)) |
++---+-----------------------------+
+|   | 0                           |
+| 1 | 1                           |
+|   | 1                           |
+|   | 1                           |
+| 2 | 2                           |
++---+-----------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Expression to fill the _null_ values of."
+    ),
+    argument(
+        name = "default",
+        description = "Expression to use for the _null_ values before the first value that is not _null_."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CoalesceScanFunc {
+    signature: Signature,
+}
+
+impl Default for CoalesceScanFunc {
+    fn default() -> Self {
+        CoalesceScanFunc::new()
+    }
+}
+
+impl CoalesceScanFunc {
+    pub fn new() -> Self {
+        Self {
+            // the result of a row depends on the rows before it in the batch
+            signature: Signature::user_defined(Volatility::Volatile),
+        }
+    }
+}
+
+impl ScalarUDFImpl for CoalesceScanFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "coalesce_scan"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    /// coalesce_scan evaluates to the last non-null value up to each row, or
+    /// the default when there is none yet
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [value, default] = take_function_args(self.name(), args.args)?;
+
+        if let ColumnarValue::Scalar(scalar) = &value {
+            return Ok(if scalar.is_null() { default } else { value });
+        }
+
+        let value = value.into_array(args.number_rows)?;
+        let default = default.into_array(args.number_rows)?;
+
+        // a single pass over the rows, taking each of them from the last
+        // non-null value so far, or from the default before the first one
+        let mut last_valid = None;
+        let indices = (0..value.len())
+            .map(|row| {
+                if value.is_valid(row) {
+                    last_valid = Some(row);
+                }
+                match last_valid {
+                    Some(valid_row) => (0, valid_row),
+                    None => (1, row),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Ok(ColumnarValue::Array(interleave(
+            &[value.as_ref(), default.as_ref()],
+            &indices,
+        )?))
+    }
+
+    /// Coerce both arguments to a common type like `coalesce`
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _] = take_function_args(self.name(), arg_types)?;
+        CoalesceFunc::new().coerce_arguments(arg_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, Int32Array};
+    use arrow::datatypes::{Field, Int32Type};
+    use datafusion_common::config::ConfigOptions;
+    use datafusion_common::ScalarValue;
+    use std::sync::Arc;
+
+    fn coalesce_scan(value: Vec<Option<i32>>, default: i32) -> Vec<Option<i32>> {
+        let number_rows = value.len();
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Int32Array::from(value))),
+            ColumnarValue::Scalar(ScalarValue::Int32(Some(default))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = CoalesceScanFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows,
+                return_field: Field::new("f", DataType::Int32, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })
+            .unwrap()
+            .into_array(number_rows)
+            .unwrap();
+        result.as_primitive::<Int32Type>().iter().collect()
+    }
+
+    #[test]
+    fn test_coalesce_scan() {
+        // leading nulls take the default, the others the previous value
+        assert_eq!(
+            coalesce_scan(vec![None, None, Some(1), None, Some(2), None], 9),
+            vec![Some(9), Some(9), Some(1), Some(1), Some(2), Some(2)]
+        );
+        assert_eq!(
+            coalesce_scan(vec![None, None, None], 9),
+            vec![Some(9), Some(9), Some(9)]
+        );
+        assert_eq!(coalesce_scan(vec![], 9), vec![]);
+    }
+}
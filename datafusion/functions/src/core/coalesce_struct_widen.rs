@@ -0,0 +1,200 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CoalesceStructWidenFunc`]: Implementation of the `coalesce_struct_widen` function
+
+use arrow::array::{Array, StructArray};
+use arrow::buffer::NullBuffer;
+use arrow::compute::is_not_null;
+use arrow::compute::kernels::zip::zip;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::binary::try_type_union_resolution_with_struct;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+use super::concat_struct::with_parent_nulls;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Merges two structs with the same field names field by field, taking each field from the first struct when it is not _null_, and from the second struct otherwise. A _null_ struct is handled as if all its fields were _null_. Fields of different types are widened to their common type, such as `Int64` for an `Int32` and an `Int64` field. Nested structs are taken as a whole rather than merged.",
+    syntax_example = "coalesce_struct_widen(struct1, struct2)",
+    sql_example = r#"```sql
+> select * from t;
++---------------+-----------------+
+| a             | b               |
++---------------+-----------------+
+| {x: 1, y: }   | {x: 10, y: foo} |
+| {x: , y: bar} | {x: 20, y: baz} |
++---------------+-----------------+
+> select coalesce_struct_widen(a, b) from t;
++--------------------------------+
+| coalesce_struct_widen(t.a,t.b) |
++--------------------------------+
+| {x: 1, y: foo}                 |
+| {x: 20, y: bar}                |
++--------------------------------+
+```"#,
+    argument(
+        name = "struct1",
+        description = "Struct expression to take the fields from when they are not _null_."
+    ),
+    argument(
+        name = "struct2",
+        description = "Struct expression to take the fields from when they are _null_ in `struct1`. Must have the same field names, in the same order, as `struct1`."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CoalesceStructWidenFunc {
+    signature: Signature,
+}
+
+impl Default for CoalesceStructWidenFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CoalesceStructWidenFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the struct types with every field widened to the common type of
+/// that field in both structs, keeping the names and nullability
+fn widen_struct_types(name: &str, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+    let [lhs, rhs] = take_function_args(name, arg_types)?;
+    for arg_type in [lhs, rhs] {
+        if !matches!(arg_type, DataType::Struct(_)) {
+            return plan_err!("{name} requires struct arguments, got {arg_type}");
+        }
+    }
+    try_type_union_resolution_with_struct(arg_types)
+}
+
+impl ScalarUDFImpl for CoalesceStructWidenFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "coalesce_struct_widen"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let mut widened = widen_struct_types(self.name(), arg_types)?;
+        Ok(widened.swap_remove(0))
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [lhs, rhs] = take_function_args(self.name(), args.arg_fields)?;
+        let arg_types = [lhs.data_type().clone(), rhs.data_type().clone()];
+        let DataType::Struct(fields) = self.return_type(&arg_types)? else {
+            return internal_err!("incorrect coalesce_struct_widen return type");
+        };
+        let (DataType::Struct(lhs_fields), DataType::Struct(rhs_fields)) =
+            (lhs.data_type(), rhs.data_type())
+        else {
+            return internal_err!("coalesce_struct_widen requires struct arguments");
+        };
+
+        // a field is only NULL when it is NULL, or comes from a NULL struct,
+        // on both sides
+        let fields = fields
+            .iter()
+            .zip(lhs_fields.iter().zip(rhs_fields.iter()))
+            .map(|(field, (lhs_field, rhs_field))| {
+                let nullable = (lhs_field.is_nullable() || lhs.is_nullable())
+                    && (rhs_field.is_nullable() || rhs.is_nullable());
+                field.as_ref().clone().with_nullable(nullable)
+            })
+            .collect::<Fields>();
+
+        Ok(Field::new(
+            self.name(),
+            DataType::Struct(fields),
+            lhs.is_nullable() && rhs.is_nullable(),
+        )
+        .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
+            return internal_err!("incorrect coalesce_struct_widen return type");
+        };
+        let is_scalar = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let [lhs, rhs] = take_function_args(self.name(), &arrays)?;
+        let lhs = as_struct_array(lhs)?;
+        let rhs = as_struct_array(rhs)?;
+
+        let columns = lhs
+            .columns()
+            .iter()
+            .zip(rhs.columns())
+            .map(|(lhs_column, rhs_column)| {
+                let lhs_column = with_parent_nulls(lhs_column, lhs.nulls())?;
+                let rhs_column = with_parent_nulls(rhs_column, rhs.nulls())?;
+                Ok(zip(&is_not_null(&lhs_column)?, &lhs_column, &rhs_column)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // the struct is only NULL when both structs are NULL
+        let nulls = match (lhs.nulls(), rhs.nulls()) {
+            (Some(lhs_nulls), Some(rhs_nulls)) => {
+                Some(NullBuffer::new(lhs_nulls.inner() | rhs_nulls.inner()))
+            }
+            _ => None,
+        };
+
+        let result = StructArray::try_new(fields.clone(), columns, nulls)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+
+    /// Widen the fields of both structs to their common types
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        widen_struct_types(self.name(), arg_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
@@ -0,0 +1,240 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CoalesceWithSourceFunc`]: Implementation of the `coalesce_with_source` function
+
+use super::coalesce::CoalesceFunc;
+use arrow::array::{new_null_array, Array, ArrayRef, BooleanArray, StructArray};
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{and, is_not_null, is_null};
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::{exec_err, internal_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use itertools::Itertools;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns a struct with the first of its arguments that is not _null_ as `value`, and the 1-based position of that argument as `source_index`. Both fields are _null_ if all arguments are _null_. The arguments are coerced like the ones of `coalesce`.",
+    syntax_example = "coalesce_with_source(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select coalesce_with_source(null, null, 'datafusion');
++----------------------------------------------------+
+| coalesce_with_source(NULL,NULL,Utf8("datafusion")) |
++----------------------------------------------------+
+| {value: datafusion, source_index: 3}               |
++----------------------------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Expression to use if previous expressions are _null_. Can be a constant, column, or function, and any combination of arithmetic operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CoalesceWithSourceFunc {
+    signature: Signature,
+}
+
+impl Default for CoalesceWithSourceFunc {
+    fn default() -> Self {
+        CoalesceWithSourceFunc::new()
+    }
+}
+
+impl CoalesceWithSourceFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the fields of the struct returned for arguments of type `value_type`
+fn result_fields(value_type: DataType, nullable: bool) -> Fields {
+    Fields::from(vec![
+        Field::new("value", value_type, nullable),
+        Field::new("source_index", DataType::UInt64, nullable),
+    ])
+}
+
+impl ScalarUDFImpl for CoalesceWithSourceFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "coalesce_with_source"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        // If any the arguments is non-null, both fields are non-null
+        let nullable = args.arg_fields.iter().all(|f| f.is_nullable());
+        let Some(value_type) = args
+            .arg_fields
+            .iter()
+            .map(|f| f.data_type())
+            .find_or_first(|d| !d.is_null())
+        else {
+            return exec_err!("coalesce_with_source must have at least one argument");
+        };
+        let fields = result_fields(value_type.clone(), nullable);
+        Ok(Field::new(self.name(), DataType::Struct(fields), false).into())
+    }
+
+    /// coalesce_with_source evaluates to the first value which is not NULL and its position
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
+            return internal_err!("incorrect coalesce_with_source return type");
+        };
+        let args = args.args;
+        if args.is_empty() {
+            return exec_err!(
+                "coalesce_with_source was called with {} arguments. It requires at least 1.",
+                args.len()
+            );
+        }
+
+        let size = args.iter().find_map(|x| match x {
+            ColumnarValue::Array(array) => Some(array.len()),
+            _ => None,
+        });
+        let is_scalar = size.is_none();
+        let size = size.unwrap_or(1);
+
+        // start with nulls as default output
+        let mut current_value = new_null_array(fields[0].data_type(), size);
+        let mut current_source = new_null_array(&DataType::UInt64, size);
+        let mut remainder = BooleanArray::from(vec![true; size]);
+
+        for (i, arg) in args.iter().enumerate() {
+            let source = ScalarValue::UInt64(Some(i as u64 + 1)).to_scalar()?;
+            match arg {
+                ColumnarValue::Array(array) => {
+                    let to_apply = and(&remainder, &is_not_null(array.as_ref())?)?;
+                    current_value = zip(&to_apply, array, &current_value)?;
+                    current_source = zip(&to_apply, &source, &current_source)?;
+                    remainder = and(&remainder, &is_null(array)?)?;
+                }
+                ColumnarValue::Scalar(value) => {
+                    if value.is_null() {
+                        continue;
+                    }
+                    current_value = zip(&remainder, &value.to_scalar()?, &current_value)?;
+                    current_source = zip(&remainder, &source, &current_source)?;
+                    break;
+                }
+            }
+            if remainder.iter().all(|x| x == Some(false)) {
+                break;
+            }
+        }
+
+        let columns: Vec<ArrayRef> = vec![current_value, current_source];
+        let result = StructArray::try_new(fields.clone(), columns, None)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+
+    /// Coerce all arguments to a single common type, like `coalesce`
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        CoalesceFunc::new().coerce_arguments(arg_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, Int32Array};
+    use arrow::datatypes::{Int32Type, UInt64Type};
+    use datafusion_common::config::ConfigOptions;
+
+    #[test]
+    fn test_coalesce_with_source_index() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+                Some(1),
+                None,
+                None,
+                None,
+            ]))),
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+                Some(2),
+                Some(3),
+                None,
+                None,
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Int32(None)),
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+                None,
+                Some(4),
+                Some(5),
+                None,
+            ]))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+        let fields = result_fields(DataType::Int32, true);
+
+        let result = CoalesceWithSourceFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 4,
+                return_field: Field::new("f", DataType::Struct(fields), false).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(4)?;
+
+        let result = result.as_struct();
+        let values = result.column(0).as_primitive::<Int32Type>();
+        let sources = result.column(1).as_primitive::<UInt64Type>();
+        assert_eq!(
+            values.iter().collect::<Vec<_>>(),
+            vec![Some(1), Some(3), Some(5), None]
+        );
+        assert_eq!(
+            sources.iter().collect::<Vec<_>>(),
+            vec![Some(1), Some(2), Some(4), None]
+        );
+
+        Ok(())
+    }
+}
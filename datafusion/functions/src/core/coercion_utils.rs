@@ -0,0 +1,87 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::datatypes::DataType;
+use datafusion_common::{DataFusionError, Result};
+use itertools::Itertools;
+
+/// Runs the `coerce_types` logic `coerce` of the function `name`, adding the
+/// signature it expects to its errors, such as
+/// `Expected nullif(T, T), got nullif(Int64, List(Int64))`.
+///
+/// The user defined signatures of these functions can't be shown by the
+/// planner, `signature` describes them with `T` for the arguments that are
+/// coerced to a common type and `...` for any number of them.
+pub(super) fn coerce_with_signature(
+    name: &str,
+    signature: &str,
+    arg_types: &[DataType],
+    coerce: impl FnOnce() -> Result<Vec<DataType>>,
+) -> Result<Vec<DataType>> {
+    coerce().map_err(|e| {
+        let expected = format!(
+            "Expected {name}({signature}), got {name}({})",
+            arg_types.iter().join(", ")
+        );
+        match e {
+            DataFusionError::Plan(message) => {
+                DataFusionError::Plan(with_expected(&message, &expected))
+            }
+            DataFusionError::Execution(message) => {
+                DataFusionError::Execution(with_expected(&message, &expected))
+            }
+            e => e,
+        }
+    })
+}
+
+/// Appends `expected` to the error `message`, before its backtrace if any
+fn with_expected(message: &str, expected: &str) -> String {
+    const BACK_TRACE_SEP: &str = DataFusionError::BACK_TRACE_SEP;
+    match message.split_once(BACK_TRACE_SEP) {
+        Some((message, backtrace)) => format!(
+            "{}. {expected}{BACK_TRACE_SEP}{backtrace}",
+            message.trim_end_matches('.')
+        ),
+        None => format!("{}. {expected}", message.trim_end_matches('.')),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datafusion_common::{internal_err, plan_err};
+
+    #[test]
+    fn errors_include_signature() {
+        let arg_types = [DataType::Int32, DataType::Utf8];
+        let err = coerce_with_signature("f", "T, T", &arg_types, || {
+            plan_err!("Cannot coerce the arguments.")
+        })
+        .unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Cannot coerce the arguments. \
+            Expected f(T, T), got f(Int32, Utf8)"
+        );
+
+        // internal errors are not caused by the arguments
+        let err = coerce_with_signature("f", "T, T", &arg_types, || internal_err!("bug"))
+            .unwrap_err();
+        assert!(!err.to_string().contains("Expected f(T, T)"));
+    }
+}
@@ -0,0 +1,119 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CommonTypeFunc`]: Implementation of the `common_type` function
+
+use arrow::datatypes::DataType;
+use datafusion_common::{plan_err, utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::binary::type_union_resolution;
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{ColumnarValue, Documentation, Expr, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = "Returns the name of the [Arrow data type](https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html) both expressions are coerced to by functions such as `coalesce` and `greatest`.",
+    syntax_example = "common_type(expression1, expression2)",
+    sql_example = r#"```sql
+> select common_type(1, 2.5), common_type(1, 'a');
++------------------------------------+---------------------------------+
+| common_type(Int64(1),Float64(2.5)) | common_type(Int64(1),Utf8("a")) |
++------------------------------------+---------------------------------+
+| Float64                            | Int64                           |
++------------------------------------+---------------------------------+
+```
+"#,
+    argument(
+        name = "expression1",
+        description = "First expression. The expression can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "expression2",
+        description = "Second expression. The expression can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CommonTypeFunc {
+    signature: Signature,
+}
+
+impl Default for CommonTypeFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommonTypeFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the name of the type `lhs` and `rhs` are coerced to
+fn common_type_name(lhs: &DataType, rhs: &DataType) -> Result<String> {
+    match type_union_resolution(&[lhs.clone(), rhs.clone()]) {
+        Some(data_type) => Ok(format!("{data_type}")),
+        None => plan_err!("There is no common type for {lhs} and {rhs}"),
+    }
+}
+
+impl ScalarUDFImpl for CommonTypeFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "common_type"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [lhs, rhs] = take_function_args(self.name(), args.args)?;
+        let name = common_type_name(&lhs.data_type(), &rhs.data_type())?;
+        Ok(ColumnarValue::Scalar(ScalarValue::from(name)))
+    }
+
+    /// The result only depends on the argument types, so it is folded into a literal
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [lhs, rhs] = take_function_args(self.name(), &args)?;
+        let name =
+            common_type_name(&info.get_data_type(lhs)?, &info.get_data_type(rhs)?)?;
+        Ok(ExprSimplifyResult::Simplified(Expr::Literal(
+            ScalarValue::from(name),
+            None,
+        )))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
@@ -0,0 +1,161 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ConcatStructFunc`]: Implementation of the `concat_struct` function
+
+use arrow::array::{make_array, Array, ArrayRef, StructArray};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::{internal_err, plan_err, HashSet, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns a struct with the fields of all the given structs, in order. The field names must be distinct across the structs. The fields coming from a _null_ struct are _null_.",
+    syntax_example = "concat_struct(struct1[, ..., struct_n])",
+    sql_example = r#"```sql
+> select concat_struct(named_struct('a', 1), named_struct('b', 'x', 'c', true));
++-----------------------------------------------------------------------------------------------------------+
+| concat_struct(named_struct(Utf8("a"),Int64(1)),named_struct(Utf8("b"),Utf8("x"),Utf8("c"),Boolean(true))) |
++-----------------------------------------------------------------------------------------------------------+
+| {a: 1, b: x, c: true}                                                                                     |
++-----------------------------------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "struct1, struct_n",
+        description = "Struct expressions to merge. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ConcatStructFunc {
+    signature: Signature,
+}
+
+impl Default for ConcatStructFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConcatStructFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ConcatStructFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "concat_struct"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let mut names = HashSet::new();
+        let mut fields = Vec::new();
+        for base in args.arg_fields {
+            let DataType::Struct(base_fields) = base.data_type() else {
+                return plan_err!(
+                    "concat_struct requires struct arguments, got {}",
+                    base.data_type()
+                );
+            };
+
+            for field in base_fields {
+                if !names.insert(field.name()) {
+                    return plan_err!(
+                        "concat_struct requires distinct field names, got '{}' in more than one struct",
+                        field.name()
+                    );
+                }
+                // the fields of a NULL struct are NULL
+                let nullable = field.is_nullable() || base.is_nullable();
+                fields.push(field.as_ref().clone().with_nullable(nullable));
+            }
+        }
+
+        Ok(Field::new(self.name(), DataType::Struct(Fields::from(fields)), false).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
+            return internal_err!("incorrect concat_struct return type");
+        };
+        let is_scalar = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let mut columns = Vec::with_capacity(fields.len());
+        for array in &arrays {
+            let struct_array = as_struct_array(array)?;
+            for column in struct_array.columns() {
+                columns.push(with_parent_nulls(column, struct_array.nulls())?);
+            }
+        }
+
+        let result = StructArray::try_new(fields.clone(), columns, None)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns `column` with the rows of its NULL parent struct also NULL.
+///
+/// The values of `column` are shared, only the validity is rebuilt when the
+/// parent has nulls.
+pub(super) fn with_parent_nulls(
+    column: &ArrayRef,
+    parent_nulls: Option<&NullBuffer>,
+) -> Result<ArrayRef> {
+    if parent_nulls.is_none() {
+        return Ok(Arc::clone(column));
+    }
+
+    let nulls = NullBuffer::union(parent_nulls, column.nulls());
+    let data = column.to_data().into_builder().nulls(nulls).build()?;
+    Ok(make_array(data))
+}
@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`CountNonNullFunc`]: Implementation of the `count_non_null` function
+
+use arrow::array::{Array, Int32Array};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the number of its arguments that are not _null_. The arguments can be of different types.",
+    syntax_example = "count_non_null(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select count_non_null(null, 1, 'datafusion', null);
++-------------------------------------------------------+
+| count_non_null(NULL,Int64(1),Utf8("datafusion"),NULL) |
++-------------------------------------------------------+
+| 2                                                     |
++-------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Expression to count if it is not _null_. Can be a constant, column, or function, and any combination of operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CountNonNullFunc {
+    signature: Signature,
+}
+
+impl Default for CountNonNullFunc {
+    fn default() -> Self {
+        CountNonNullFunc::new()
+    }
+}
+
+impl CountNonNullFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for CountNonNullFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "count_non_null"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int32)
+    }
+
+    fn return_field_from_args(&self, _args: ReturnFieldArgs) -> Result<FieldRef> {
+        // the count is 0 rather than NULL when all the arguments are NULL
+        Ok(Field::new(self.name(), DataType::Int32, false).into())
+    }
+
+    /// count_non_null evaluates per row to the number of values which are not NULL
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let non_null_scalars = args
+            .args
+            .iter()
+            .filter(|arg| matches!(arg, ColumnarValue::Scalar(value) if !value.is_null()))
+            .count() as i32;
+
+        let size = args.args.iter().find_map(|arg| match arg {
+            ColumnarValue::Array(array) => Some(array.len()),
+            _ => None,
+        });
+        let Some(size) = size else {
+            return Ok(ColumnarValue::Scalar(ScalarValue::Int32(Some(
+                non_null_scalars,
+            ))));
+        };
+
+        let mut counts = vec![non_null_scalars; size];
+        for arg in &args.args {
+            let ColumnarValue::Array(array) = arg else {
+                continue;
+            };
+            // logical nulls also cover the arrays of type Null and the
+            // dictionaries whose values are NULL
+            match array.logical_nulls() {
+                Some(nulls) => {
+                    for row in nulls.valid_indices() {
+                        counts[row] += 1;
+                    }
+                }
+                None => counts.iter_mut().for_each(|count| *count += 1),
+            }
+        }
+
+        Ok(ColumnarValue::Array(Arc::new(Int32Array::from(counts))))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, NullArray, StringArray};
+    use arrow::datatypes::Int32Type;
+    use datafusion_common::config::ConfigOptions;
+
+    #[test]
+    fn test_count_non_null_per_row() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![Some(1), None, None]))),
+            ColumnarValue::Scalar(ScalarValue::Utf8(Some("a".to_string()))),
+            ColumnarValue::Array(Arc::new(StringArray::from(vec![
+                Some("b"),
+                Some("c"),
+                None,
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Int32(None)),
+            ColumnarValue::Array(Arc::new(NullArray::new(3))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = CountNonNullFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 3,
+                return_field: Field::new("f", DataType::Int32, false).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(3)?;
+
+        assert_eq!(result.null_count(), 0);
+        assert_eq!(
+            result.as_primitive::<Int32Type>().values().to_vec(),
+            vec![3, 2, 1]
+        );
+
+        Ok(())
+    }
+}
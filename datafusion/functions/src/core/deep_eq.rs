@@ -0,0 +1,363 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`DeepEqFunc`]: Implementation of the `deep_eq` function
+
+use arrow::array::{
+    make_comparator, Array, ArrayRef, AsArray, BooleanArray, DynComparator,
+};
+use arrow::compute::SortOptions;
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::ops::Range;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns true if two values are equal, comparing the fields of structs and the elements of lists and maps recursively. Returns false if any nested value differs, otherwise NULL if either value or any nested value compared is NULL.",
+    syntax_example = "deep_eq(expression1, expression2)",
+    sql_example = r#"```sql
+> select deep_eq(named_struct('a', 1), named_struct('a', 1));
++----------------------------------------------------------------------------+
+| deep_eq(named_struct(Utf8("a"),Int64(1)),named_struct(Utf8("a"),Int64(1))) |
++----------------------------------------------------------------------------+
+| true                                                                       |
++----------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "expression1",
+        description = "Expression to compare. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "expression2",
+        description = "Expression to compare to expression1. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct DeepEqFunc {
+    signature: Signature,
+}
+
+impl Default for DeepEqFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DeepEqFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::comparable(2, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for DeepEqFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "deep_eq"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [lhs, rhs] = take_function_args(self.name(), &args.args)?;
+        let is_scalar = matches!(
+            (lhs, rhs),
+            (ColumnarValue::Scalar(_), ColumnarValue::Scalar(_))
+        );
+
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let result = deep_eq(&arrays[0], &arrays[1])?;
+
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Compares two arrays of the same type row by row, recursing into the fields
+/// of structs and the elements of lists and maps.
+///
+/// A row is false if any value compared differs, otherwise NULL if any value
+/// compared is NULL, and true if all of them are equal. Lists of different
+/// lengths are never equal.
+pub fn deep_eq(left: &ArrayRef, right: &ArrayRef) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return exec_err!(
+            "deep_eq expects arrays of the same length, got {} and {}",
+            left.len(),
+            right.len()
+        );
+    }
+
+    let comparator = DeepComparator::try_new(Arc::clone(left), Arc::clone(right), false)?;
+    Ok((0..left.len()).map(|i| comparator.eq(i, i)).collect())
+}
+
+/// Like [`deep_eq`], but compares NULL values like `IS NOT DISTINCT FROM`, at
+/// every level of nesting: two NULL values are equal, and a NULL value differs
+/// from any other value. The result has no NULL rows.
+pub fn deep_not_distinct(left: &ArrayRef, right: &ArrayRef) -> Result<BooleanArray> {
+    if left.len() != right.len() {
+        return exec_err!(
+            "deep_not_distinct expects arrays of the same length, got {} and {}",
+            left.len(),
+            right.len()
+        );
+    }
+
+    let comparator = DeepComparator::try_new(Arc::clone(left), Arc::clone(right), true)?;
+    Ok((0..left.len()).map(|i| comparator.eq(i, i)).collect())
+}
+
+/// Compares the values of two arrays, built once for the nested types
+struct DeepComparator {
+    left: ArrayRef,
+    right: ArrayRef,
+    /// Whether NULL values are equal to each other rather than unknown
+    nulls_equal: bool,
+    kind: ComparatorKind,
+}
+
+enum ComparatorKind {
+    /// The values of the `Null` type are all NULL
+    Null,
+    Leaf(DynComparator),
+    Struct(Vec<DeepComparator>),
+    /// Compares the elements of lists, or the entries of maps
+    List(Box<DeepComparator>),
+}
+
+impl DeepComparator {
+    fn try_new(left: ArrayRef, right: ArrayRef, nulls_equal: bool) -> Result<Self> {
+        let kind = match (left.data_type(), right.data_type()) {
+            (DataType::Null, _) | (_, DataType::Null) => ComparatorKind::Null,
+            (DataType::Struct(_), DataType::Struct(_)) => {
+                let (l, r) = (left.as_struct(), right.as_struct());
+                if l.num_columns() != r.num_columns() {
+                    return exec_err!(
+                        "deep_eq cannot compare structs with {} and {} fields",
+                        l.num_columns(),
+                        r.num_columns()
+                    );
+                }
+
+                let children = l
+                    .columns()
+                    .iter()
+                    .zip(r.columns())
+                    .map(|(l, r)| {
+                        Self::try_new(Arc::clone(l), Arc::clone(r), nulls_equal)
+                    })
+                    .collect::<Result<_>>()?;
+                ComparatorKind::Struct(children)
+            }
+            (DataType::List(_), DataType::List(_)) => {
+                ComparatorKind::List(Box::new(Self::try_new(
+                    Arc::clone(left.as_list::<i32>().values()),
+                    Arc::clone(right.as_list::<i32>().values()),
+                    nulls_equal,
+                )?))
+            }
+            (DataType::LargeList(_), DataType::LargeList(_)) => {
+                ComparatorKind::List(Box::new(Self::try_new(
+                    Arc::clone(left.as_list::<i64>().values()),
+                    Arc::clone(right.as_list::<i64>().values()),
+                    nulls_equal,
+                )?))
+            }
+            (DataType::FixedSizeList(_, _), DataType::FixedSizeList(_, _)) => {
+                ComparatorKind::List(Box::new(Self::try_new(
+                    Arc::clone(left.as_fixed_size_list().values()),
+                    Arc::clone(right.as_fixed_size_list().values()),
+                    nulls_equal,
+                )?))
+            }
+            (DataType::Map(_, _), DataType::Map(_, _)) => {
+                ComparatorKind::List(Box::new(Self::try_new(
+                    Arc::new(left.as_map().entries().clone()),
+                    Arc::new(right.as_map().entries().clone()),
+                    nulls_equal,
+                )?))
+            }
+            _ => ComparatorKind::Leaf(make_comparator(
+                left.as_ref(),
+                right.as_ref(),
+                SortOptions::default(),
+            )?),
+        };
+
+        Ok(Self {
+            left,
+            right,
+            nulls_equal,
+            kind,
+        })
+    }
+
+    /// Compares row `i` of the left array to row `j` of the right array
+    fn eq(&self, i: usize, j: usize) -> Option<bool> {
+        // the arrays of type `Null` have no validity, but all their values are NULL
+        let left_null = self.left.is_null(i) || self.left.data_type().is_null();
+        let right_null = self.right.is_null(j) || self.right.data_type().is_null();
+        if left_null || right_null {
+            return self.nulls_equal.then_some(left_null && right_null);
+        }
+
+        match &self.kind {
+            ComparatorKind::Null => None,
+            ComparatorKind::Leaf(cmp) => Some(cmp(i, j).is_eq()),
+            ComparatorKind::Struct(children) => {
+                all_eq(children.iter().map(|child| child.eq(i, j)))
+            }
+            ComparatorKind::List(child) => {
+                let left = list_range(self.left.as_ref(), i);
+                let right = list_range(self.right.as_ref(), j);
+                if left.len() != right.len() {
+                    return Some(false);
+                }
+                all_eq(left.zip(right).map(|(i, j)| child.eq(i, j)))
+            }
+        }
+    }
+}
+
+/// False if any of `results` is false, otherwise NULL if any is NULL
+fn all_eq(results: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+    let mut all = Some(true);
+    for result in results {
+        match result {
+            Some(false) => return Some(false),
+            None => all = None,
+            Some(true) => {}
+        }
+    }
+    all
+}
+
+/// The range of the values of the list or map at `index` in its child array
+fn list_range(array: &dyn Array, index: usize) -> Range<usize> {
+    match array.data_type() {
+        DataType::List(_) => {
+            let offsets = array.as_list::<i32>().value_offsets();
+            offsets[index] as usize..offsets[index + 1] as usize
+        }
+        DataType::LargeList(_) => {
+            let offsets = array.as_list::<i64>().value_offsets();
+            offsets[index] as usize..offsets[index + 1] as usize
+        }
+        DataType::FixedSizeList(_, _) => {
+            let array = array.as_fixed_size_list();
+            let start = array.value_offset(index) as usize;
+            start..start + array.value_length() as usize
+        }
+        DataType::Map(_, _) => {
+            let offsets = array.as_map().value_offsets();
+            offsets[index] as usize..offsets[index + 1] as usize
+        }
+        _ => 0..0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, ListArray, StructArray};
+    use arrow::datatypes::{Field, Int32Type};
+
+    fn struct_of_list(lists: Vec<Option<Vec<Option<i32>>>>, ids: Vec<i32>) -> ArrayRef {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(lists);
+        Arc::new(StructArray::from(vec![
+            (
+                Arc::new(Field::new("id", DataType::Int32, true)),
+                Arc::new(Int32Array::from(ids)) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new_list(
+                    "values",
+                    Field::new_list_field(DataType::Int32, true),
+                    true,
+                )),
+                Arc::new(list) as ArrayRef,
+            ),
+        ]))
+    }
+
+    #[test]
+    fn deep_eq_struct_of_list() -> Result<()> {
+        let left = struct_of_list(
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(1), None]),
+                Some(vec![Some(1), None]),
+                None,
+                Some(vec![Some(1)]),
+            ],
+            vec![1, 1, 1, 1, 1, 1],
+        );
+        let right = struct_of_list(
+            vec![
+                Some(vec![Some(1), Some(2)]),
+                Some(vec![Some(1), Some(3)]),
+                Some(vec![Some(1), None]),
+                Some(vec![Some(2), None]),
+                Some(vec![]),
+                Some(vec![Some(1), Some(2)]),
+            ],
+            vec![1, 1, 1, 1, 1, 1],
+        );
+
+        let result = deep_eq(&left, &right)?;
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                None,
+                Some(false),
+                None,
+                Some(false)
+            ])
+        );
+        Ok(())
+    }
+}
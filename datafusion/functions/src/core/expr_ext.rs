@@ -17,7 +17,9 @@
 
 //! Extension methods for Expr.
 
-use datafusion_expr::{Expr, Literal};
+use arrow::datatypes::DataType;
+use datafusion_common::{ExprSchema, Result};
+use datafusion_expr::{Expr, ExprSchemable, Literal};
 
 use super::expr_fn::get_field;
 
@@ -53,11 +55,35 @@ impl FieldAccessor for Expr {
     }
 }
 
+/// Returns the [`DataType`] `expr` evaluates to against `schema`.
+///
+/// This is the counterpart of the `arrow_typeof` function for plan builders,
+/// which can inspect the resolved type without parsing its string output.
+///
+/// # Example
+/// ```
+/// # use arrow::datatypes::{DataType, Field, Schema};
+/// # use datafusion_common::DFSchema;
+/// # use datafusion_expr::{col, lit};
+/// # use datafusion_functions::core::expr_ext::resolved_type_of;
+/// let schema = DFSchema::try_from(Schema::new(vec![
+///     Field::new("a", DataType::Int32, false),
+/// ]))
+/// .unwrap();
+/// let data_type = resolved_type_of(&(col("a") + lit(1i64)), &schema).unwrap();
+/// assert_eq!(data_type, DataType::Int64);
+/// ```
+pub fn resolved_type_of(expr: &Expr, schema: &dyn ExprSchema) -> Result<DataType> {
+    expr.get_type(schema)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    use datafusion_expr::col;
+    use arrow::datatypes::{Field, Fields, Schema};
+    use datafusion_common::DFSchema;
+    use datafusion_expr::{cast, col, lit};
 
     #[test]
     fn test_field() {
@@ -65,4 +91,35 @@ mod tests {
         let expr2 = get_field(col("a"), "b");
         assert_eq!(expr1, expr2);
     }
+
+    #[test]
+    fn test_resolved_type_of() -> Result<()> {
+        let schema = DFSchema::try_from(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new(
+                "s",
+                DataType::Struct(Fields::from(vec![Field::new(
+                    "f",
+                    DataType::Float64,
+                    true,
+                )])),
+                true,
+            ),
+        ]))?;
+
+        let cases = vec![
+            (col("a"), DataType::Int32),
+            (lit("x"), DataType::Utf8),
+            (col("a") + lit(1i64), DataType::Int64),
+            (col("a").eq(lit(1)), DataType::Boolean),
+            (cast(col("b"), DataType::Date32), DataType::Date32),
+            (col("s").field("f"), DataType::Float64),
+        ];
+        for (expr, expected) in cases {
+            assert_eq!(resolved_type_of(&expr, &schema)?, expected, "{expr}");
+        }
+
+        Ok(())
+    }
 }
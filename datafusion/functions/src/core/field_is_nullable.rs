@@ -0,0 +1,211 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`FieldIsNullableFunc`]: Implementation of the `field_is_nullable` function
+
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{
+    exec_err, internal_err, plan_datafusion_err, plan_err, Result, ScalarValue,
+};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{
+    lit, ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs,
+    ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns whether the field with the given name is declared nullable in the type of the struct. The result is a constant that only depends on the type of the struct, also for _null_ structs.",
+    syntax_example = "field_is_nullable(struct, field_name)",
+    sql_example = r#"```sql
+> select field_is_nullable(coalesce_with_source(null, 1), 'value'), field_is_nullable(named_struct('a', 1), 'a');
++----------------------------------------------------------------------+---------------------------------------------------------------+
+| field_is_nullable(coalesce_with_source(NULL,Int64(1)),Utf8("value")) | field_is_nullable(named_struct(Utf8("a"),Int64(1)),Utf8("a")) |
++----------------------------------------------------------------------+---------------------------------------------------------------+
+| false                                                                | true                                                          |
++----------------------------------------------------------------------+---------------------------------------------------------------+
+```"#,
+    argument(
+        name = "struct",
+        description = "Struct expression. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "field_name",
+        description = "Constant string with the name of the field."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct FieldIsNullableFunc {
+    signature: Signature,
+}
+
+impl Default for FieldIsNullableFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FieldIsNullableFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns whether the field `field_name` of the struct type `base` is nullable,
+/// or `None` if `base` is not a struct or has no such field
+fn field_is_nullable(base: &DataType, field_name: &str) -> Option<bool> {
+    match base {
+        DataType::Struct(fields) => fields
+            .find(field_name)
+            .map(|(_, field)| field.is_nullable()),
+        _ => None,
+    }
+}
+
+impl ScalarUDFImpl for FieldIsNullableFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "field_is_nullable"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [base, _] = take_function_args(self.name(), args.arg_fields)?;
+
+        if !matches!(base.data_type(), DataType::Struct(_)) {
+            return plan_err!(
+                "field_is_nullable requires a struct as first argument, got {}",
+                base.data_type()
+            );
+        }
+
+        let Some(field_name) =
+            args.scalar_arguments[1].and_then(|sv| sv.try_as_str().flatten())
+        else {
+            return plan_err!(
+                "field_is_nullable requires the field name as a constant string"
+            );
+        };
+
+        if field_is_nullable(base.data_type(), field_name).is_none() {
+            return Err(plan_datafusion_err!(
+                "Field {field_name} not found in struct"
+            ));
+        }
+
+        Ok(Arc::new(Field::new(self.name(), DataType::Boolean, false)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [base, field_name] = take_function_args(self.name(), &args.args)?;
+        let field_name = match field_name {
+            ColumnarValue::Scalar(name) => name.try_as_str().flatten(),
+            _ => None,
+        };
+        let Some(field_name) = field_name else {
+            return exec_err!(
+                "field_is_nullable requires the field name as a constant string"
+            );
+        };
+
+        match field_is_nullable(&base.data_type(), field_name) {
+            Some(nullable) => Ok(ColumnarValue::Scalar(ScalarValue::from(nullable))),
+            None => exec_err!("Field {field_name} not found in struct"),
+        }
+    }
+
+    /// The nullability only depends on the struct type, so the call is replaced
+    /// by a boolean literal at planning time.
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [base, field_name] = take_function_args(self.name(), &args)?;
+
+        let Expr::Literal(field_name, _) = field_name else {
+            return Ok(ExprSimplifyResult::Original(args));
+        };
+        let Some(field_name) = field_name.try_as_str().flatten() else {
+            return Ok(ExprSimplifyResult::Original(args));
+        };
+
+        match field_is_nullable(&info.get_data_type(base)?, field_name) {
+            Some(nullable) => Ok(ExprSimplifyResult::Simplified(lit(nullable))),
+            None => Ok(ExprSimplifyResult::Original(args)),
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FieldIsNullableFunc;
+    use arrow::datatypes::{DataType, Field, Fields, Schema};
+    use datafusion_common::DFSchema;
+    use datafusion_expr::execution_props::ExecutionProps;
+    use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyContext};
+    use datafusion_expr::{col, lit, ScalarUDFImpl};
+    use std::sync::Arc;
+
+    #[test]
+    fn field_is_nullable_simplifies_to_literal() {
+        let fields = Fields::from(vec![
+            Field::new("required", DataType::Int32, false),
+            Field::new("optional", DataType::Utf8, true),
+        ]);
+        let schema = Schema::new(vec![Field::new("s", DataType::Struct(fields), true)]);
+        let schema = Arc::new(DFSchema::try_from(schema).unwrap());
+        let props = ExecutionProps::new();
+        let context = SimplifyContext::new(&props).with_schema(schema);
+
+        for (field_name, expected) in [("required", false), ("optional", true)] {
+            let result = FieldIsNullableFunc::new()
+                .simplify(vec![col("s"), lit(field_name)], &context)
+                .unwrap();
+            let ExprSimplifyResult::Simplified(expr) = result else {
+                panic!("Expected ExprSimplifyResult::Simplified")
+            };
+            assert_eq!(expr, lit(expected), "for field {field_name}");
+        }
+
+        // an unknown field is left to fail at planning
+        let result = FieldIsNullableFunc::new()
+            .simplify(vec![col("s"), lit("missing")], &context)
+            .unwrap();
+        assert!(matches!(result, ExprSimplifyResult::Original(_)));
+    }
+}
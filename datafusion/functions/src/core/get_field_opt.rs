@@ -0,0 +1,195 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`GetFieldOptFunc`]: Implementation of the `get_field_opt` function
+
+use arrow::array::{make_array, Array};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns the field of a struct at a path of field names separated by dots. Returns _null_ if a field of the path does not exist, so that the same query works with structs that gained or lost fields.",
+    syntax_example = "get_field_opt(struct, path)",
+    sql_example = r#"```sql
+> select get_field_opt(named_struct('a', named_struct('b', 1)), 'a.b');
++-------------------------------------------------------------------------------------+
+| get_field_opt(named_struct(Utf8("a"),named_struct(Utf8("b"),Int64(1))),Utf8("a.b")) |
++-------------------------------------------------------------------------------------+
+| 1                                                                                   |
++-------------------------------------------------------------------------------------+
+> select get_field_opt(named_struct('a', named_struct('b', 1)), 'a.c');
++-------------------------------------------------------------------------------------+
+| get_field_opt(named_struct(Utf8("a"),named_struct(Utf8("b"),Int64(1))),Utf8("a.c")) |
++-------------------------------------------------------------------------------------+
+| NULL                                                                                |
++-------------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "struct",
+        description = "Struct expression to retrieve the field from. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "path",
+        description = "Constant string with the names of the nested fields separated by dots, such as `'a.b.c'`."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct GetFieldOptFunc {
+    signature: Signature,
+}
+
+impl Default for GetFieldOptFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GetFieldOptFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the field at `path` in `base`, or `None` if a field of the path
+/// does not exist. The field is nullable if any of the structs is nullable.
+fn resolve_path(base: &Field, path: &str) -> Option<Field> {
+    let mut field = base.clone();
+    for name in path.split('.') {
+        let DataType::Struct(fields) = field.data_type() else {
+            return None;
+        };
+        let (_, child) = fields.find(name)?;
+        let nullable = field.is_nullable() || child.is_nullable();
+        field = child.as_ref().clone().with_nullable(nullable);
+    }
+    Some(field)
+}
+
+impl ScalarUDFImpl for GetFieldOptFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "get_field_opt"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [base, _] = take_function_args(self.name(), args.arg_fields)?;
+        let Some(path) = args.scalar_arguments[1]
+            .and_then(|path| path.try_as_str().flatten())
+            .filter(|path| !path.is_empty())
+        else {
+            return plan_err!(
+                "get_field_opt requires the path to be a non-empty constant string"
+            );
+        };
+
+        match resolve_path(base, path) {
+            Some(field) => Ok(field.into()),
+            None => Ok(Field::new(self.name(), DataType::Null, true).into()),
+        }
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [base, path] = take_function_args(self.name(), args.args)?;
+
+        if args.return_field.data_type().is_null() {
+            return Ok(ColumnarValue::Scalar(ScalarValue::Null));
+        }
+        let ColumnarValue::Scalar(path) = path else {
+            return exec_err!("get_field_opt requires the path to be a constant string");
+        };
+        let Some(path) = path.try_as_str().flatten() else {
+            return exec_err!("get_field_opt requires the path to be a constant string");
+        };
+
+        let is_scalar = matches!(base, ColumnarValue::Scalar(_));
+        let mut array = base.into_array(1)?;
+        for name in path.split('.') {
+            let struct_array = as_struct_array(&array)?;
+            let Some(column) = struct_array.column_by_name(name) else {
+                return exec_err!(
+                    "get_field_opt did not find the field {name} of {path}"
+                );
+            };
+
+            // the field of a NULL struct is NULL
+            let nulls = NullBuffer::union(struct_array.nulls(), column.nulls());
+            let data = column.to_data().into_builder().nulls(nulls).build()?;
+            array = make_array(data);
+        }
+
+        if is_scalar {
+            ScalarValue::try_from_array(&array, 0).map(ColumnarValue::Scalar)
+        } else {
+            Ok(ColumnarValue::Array(array))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::datatypes::Fields;
+
+    #[test]
+    fn resolve_nested_path() {
+        let inner = Fields::from(vec![Field::new("c", DataType::Int32, false)]);
+        let outer = Fields::from(vec![
+            Field::new("b", DataType::Struct(inner), true),
+            Field::new("d", DataType::Utf8, false),
+        ]);
+        let base = Field::new("a", DataType::Struct(outer), false);
+
+        // the field is nullable as its parent struct is
+        assert_eq!(
+            resolve_path(&base, "b.c"),
+            Some(Field::new("c", DataType::Int32, true))
+        );
+        assert_eq!(
+            resolve_path(&base, "d"),
+            Some(Field::new("d", DataType::Utf8, false))
+        );
+        assert_eq!(resolve_path(&base, "b.x"), None);
+        assert_eq!(resolve_path(&base, "d.c"), None);
+    }
+}
@@ -16,19 +16,21 @@
 // under the License.
 
 use arrow::array::{
-    make_array, make_comparator, Array, BooleanArray, Capacities, MutableArrayData,
-    Scalar,
+    make_array, make_comparator, new_null_array, Array, AsArray, BooleanArray,
+    Capacities, MutableArrayData, Scalar,
 };
 use arrow::compute::SortOptions;
 use arrow::datatypes::{DataType, Field, FieldRef};
 use arrow_buffer::NullBuffer;
-use datafusion_common::cast::{as_map_array, as_struct_array};
+use datafusion_common::cast::{as_map_array, as_struct_array, as_union_array};
 use datafusion_common::{
     exec_err, internal_err, plan_datafusion_err, utils::take_function_args, Result,
     ScalarValue,
 };
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion_expr::{
-    ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs,
+    lit, ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs,
 };
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use datafusion_macros::user_doc;
@@ -38,6 +40,8 @@ use std::sync::Arc;
 #[user_doc(
     doc_section(label = "Other Functions"),
     description = r#"Returns a field within a map or a struct with the given key.
+    For a union, returns the value of the member with the given name when it is selected,
+    or _null_ otherwise, like `union_extract`.
     Note: most users invoke `get_field` indirectly via field access
     syntax such as `my_struct_col['field_name']` which results in a call to
     `get_field(my_struct_col, 'field_name')`."#,
@@ -68,7 +72,7 @@ use std::sync::Arc;
 ```"#,
     argument(
         name = "expression1",
-        description = "The map or struct to retrieve a field for."
+        description = "The map, struct or union to retrieve a field for."
     ),
     argument(
         name = "expression2",
@@ -147,6 +151,8 @@ impl ScalarUDFImpl for GetFieldFunc {
                         // execution.
                         let value_field = fields.get(1).expect("fields should have exactly two members");
 
+                        // the values are extracted as they are stored, so the value type,
+                        // including view types such as `Utf8View`, is kept as is
                         Ok(value_field.as_ref().clone().with_nullable(true).into())
                     },
                     _ => exec_err!("Map fields must contain a Struct with exactly 2 fields"),
@@ -172,8 +178,19 @@ impl ScalarUDFImpl for GetFieldFunc {
                     })
                 })
             },
+            (DataType::Union(fields, _), sv) => {
+                sv.and_then(|sv| sv.try_as_str().flatten().filter(|s| !s.is_empty()))
+                .map_or_else(
+                    || exec_err!("Field name must be a non-empty string"),
+                    |field_name| {
+                    fields.iter().find(|(_, f)| f.name() == field_name)
+                    .ok_or(plan_datafusion_err!("Field {field_name} not found in union"))
+                    // the value is null when another member is selected
+                    .map(|(_, f)| Arc::new(f.as_ref().clone().with_nullable(true)))
+                })
+            },
             (DataType::Null, _) => Ok(Field::new(self.name(), DataType::Null, true).into()),
-            (other, _) => exec_err!("The expression to get an indexed field is only valid for `Struct`, `Map` or `Null` types, got {other}"),
+            (other, _) => exec_err!("The expression to get an indexed field is only valid for `Struct`, `Map`, `Union` or `Null` types, got {other}"),
         }
     }
 
@@ -188,7 +205,16 @@ impl ScalarUDFImpl for GetFieldFunc {
             ColumnarValue::values_to_arrays(&[base.clone(), field_name.clone()])?;
         let array = Arc::clone(&arrays[0]);
         let name = match field_name {
+            // look up the dictionary value itself rather than materializing
+            // the dictionary, a `Dictionary(_, Utf8)` key behaves like `Utf8`
+            ColumnarValue::Scalar(ScalarValue::Dictionary(_, name)) => *name,
             ColumnarValue::Scalar(name) => name,
+            // a key column is looked up row by row in the map
+            ColumnarValue::Array(key_array)
+                if matches!(array.data_type(), DataType::Map(_, _)) =>
+            {
+                return process_map_array_with_key_column(array, key_array);
+            }
             _ => {
                 return exec_err!(
                     "get_field function requires the argument field_name to be a string"
@@ -196,6 +222,59 @@ impl ScalarUDFImpl for GetFieldFunc {
             }
         };
 
+        fn process_map_array_with_key_column(
+            array: Arc<dyn Array>,
+            key_array: Arc<dyn Array>,
+        ) -> Result<ColumnarValue> {
+            let map_array = as_map_array(array.as_ref())?;
+
+            // a `Dictionary(_, Utf8)` key is compared through its dictionary
+            // values rather than materializing the dictionary
+            let (key_values, key_indices) = match key_array.as_any_dictionary_opt() {
+                // without values every key is null, such as in an all-null
+                // dictionary, so no entry matches
+                Some(dictionary) if dictionary.values().is_empty() => {
+                    return Ok(ColumnarValue::Array(new_null_array(
+                        map_array.entries().column(1).data_type(),
+                        map_array.len(),
+                    )));
+                }
+                Some(dictionary) => (
+                    Arc::clone(dictionary.values()),
+                    dictionary.normalized_keys(),
+                ),
+                None => (Arc::clone(&key_array), (0..key_array.len()).collect()),
+            };
+            let comparator = make_comparator(
+                map_array.keys().as_ref(),
+                key_values.as_ref(),
+                SortOptions::default(),
+            )?;
+
+            let original_data = map_array.entries().column(1).to_data();
+            let capacity = Capacities::Array(original_data.len());
+            let mut mutable =
+                MutableArrayData::with_capacities(vec![&original_data], true, capacity);
+
+            for (row, key_index) in key_indices.into_iter().enumerate() {
+                let start = map_array.value_offsets()[row] as usize;
+                let end = map_array.value_offsets()[row + 1] as usize;
+
+                let maybe_matched = (start..end).find(|&entry| {
+                    map_array.is_valid(row)
+                        && key_array.is_valid(row)
+                        && key_values.is_valid(key_index)
+                        && comparator(entry, key_index).is_eq()
+                });
+                match maybe_matched {
+                    Some(entry) => mutable.extend(0, entry, entry + 1),
+                    None => mutable.extend_nulls(1),
+                }
+            }
+
+            Ok(ColumnarValue::Array(make_array(mutable.freeze())))
+        }
+
         fn process_map_array(
             array: Arc<dyn Array>,
             key_array: Arc<dyn Array>,
@@ -213,10 +292,14 @@ impl ScalarUDFImpl for GetFieldFunc {
                     NullBuffer::union(map_array.keys().nulls(), key_array.nulls());
                 BooleanArray::new(values, nulls)
             } else {
+                // dictionary-encoded map keys are compared through their
+                // dictionary values by the comparison kernel
                 let be_compared = Scalar::new(key_array);
                 arrow::compute::kernels::cmp::eq(&be_compared, map_array.keys())?
             };
 
+            // the values of a view type share the data buffers of the map
+            // rather than being copied into a new `Utf8` array
             let original_data = map_array.entries().column(1).to_data();
             let capacity = Capacities::Array(original_data.len());
             let mut mutable =
@@ -230,7 +313,7 @@ impl ScalarUDFImpl for GetFieldFunc {
                     .slice(start, end - start)
                     .iter()
                     .enumerate()
-                    .find(|(_, t)| t.unwrap());
+                    .find(|(_, t)| *t == Some(true));
 
                 if maybe_matched.is_none() {
                     mutable.extend_nulls(1);
@@ -272,6 +355,15 @@ impl ScalarUDFImpl for GetFieldFunc {
                 "get_field is only possible on struct with utf8 indexes. \
                              Received with {name:?} index"
             ),
+            (DataType::Union(_, _), ScalarValue::Utf8(Some(k))) => {
+                let union_array = as_union_array(&array)?;
+                Ok(ColumnarValue::Array(
+                    arrow::compute::kernels::union_extract::union_extract(
+                        union_array,
+                        &k,
+                    )?,
+                ))
+            }
             (DataType::Null, _) => Ok(ColumnarValue::Scalar(ScalarValue::Null)),
             (dt, name) => exec_err!(
                 "get_field is only possible on maps with utf8 indexes or struct \
@@ -280,7 +372,245 @@ impl ScalarUDFImpl for GetFieldFunc {
         }
     }
 
+    /// A field of a struct literal or of a `named_struct` call is replaced by
+    /// the field value, e.g. `get_field(named_struct('a', x), 'a')` by `x`,
+    /// and a member of a union is taken with `union_extract`
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [base, field_name] = take_function_args(self.name(), &args)?;
+        let Expr::Literal(field_name, _) = field_name else {
+            return Ok(ExprSimplifyResult::Original(args));
+        };
+        if matches!(field_name, ScalarValue::Utf8(Some(_)))
+            && matches!(info.get_data_type(base)?, DataType::Union(_, _))
+        {
+            let [base, field_name] = take_function_args(self.name(), args)?;
+            return Ok(ExprSimplifyResult::Simplified(Expr::ScalarFunction(
+                ScalarFunction::new_udf(super::union_extract(), vec![base, field_name]),
+            )));
+        }
+        let Some(field_name) = field_name.try_as_str().flatten() else {
+            return Ok(ExprSimplifyResult::Original(args));
+        };
+
+        match base {
+            Expr::Literal(ScalarValue::Struct(array), _) => {
+                match array.column_by_name(field_name) {
+                    // a null struct has null fields
+                    Some(column) => {
+                        let value = if array.is_null(0) {
+                            ScalarValue::try_from(column.data_type())?
+                        } else {
+                            ScalarValue::try_from_array(column, 0)?
+                        };
+                        Ok(ExprSimplifyResult::Simplified(lit(value)))
+                    }
+                    None => Ok(ExprSimplifyResult::Original(args)),
+                }
+            }
+            Expr::ScalarFunction(ScalarFunction { func, args: fields })
+                if func.name() == "named_struct" =>
+            {
+                // named_struct is never null, so the field is the value as is
+                let value = fields.chunks_exact(2).find_map(|pair| match &pair[0] {
+                    Expr::Literal(name, _)
+                        if name.try_as_str().flatten() == Some(field_name) =>
+                    {
+                        Some(pair[1].clone())
+                    }
+                    _ => None,
+                });
+                match value {
+                    Some(value) => Ok(ExprSimplifyResult::Simplified(value)),
+                    None => Ok(ExprSimplifyResult::Original(args)),
+                }
+            }
+            _ => Ok(ExprSimplifyResult::Original(args)),
+        }
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{
+        new_empty_array, ArrayRef, DictionaryArray, Int32Array, Int32Builder, MapBuilder,
+        StringBuilder, StringViewArray, StringViewBuilder,
+    };
+    use arrow::datatypes::Int32Type;
+    use datafusion_common::config::ConfigOptions;
+
+    #[test]
+    fn get_field_map_with_dictionary_key() -> Result<()> {
+        // {a: 1, b: 2}, {b: 3}, {c: 4}
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for entries in [vec![("a", 1), ("b", 2)], vec![("b", 3)], vec![("c", 4)]] {
+            for (key, value) in entries {
+                builder.keys().append_value(key);
+                builder.values().append_value(value);
+            }
+            builder.append(true)?;
+        }
+        let map: ArrayRef = Arc::new(builder.finish());
+
+        let key = ScalarValue::Dictionary(
+            Box::new(DataType::Int32),
+            Box::new(ScalarValue::new_utf8("b")),
+        );
+        let arg_fields = vec![
+            Field::new("map", map.data_type().clone(), true).into(),
+            Field::new("key", key.data_type(), false).into(),
+        ];
+
+        let result = GetFieldFunc::new().invoke_with_args(ScalarFunctionArgs {
+            args: vec![ColumnarValue::Array(map), ColumnarValue::Scalar(key)],
+            arg_fields,
+            number_rows: 3,
+            return_field: Field::new("f", DataType::Int32, true).into(),
+            config_options: Arc::new(ConfigOptions::default()),
+        })?;
+
+        let ColumnarValue::Array(result) = result else {
+            panic!("expected an array");
+        };
+        let expected: ArrayRef = Arc::new(Int32Array::from(vec![Some(2), Some(3), None]));
+        assert_eq!(&result, &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_field_map_with_dictionary_key_column() -> Result<()> {
+        // {a: 1, b: 2}, {b: 3}, {c: 4}, {a: 5}
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), Int32Builder::new());
+        for entries in [
+            vec![("a", 1), ("b", 2)],
+            vec![("b", 3)],
+            vec![("c", 4)],
+            vec![("a", 5)],
+        ] {
+            for (key, value) in entries {
+                builder.keys().append_value(key);
+                builder.values().append_value(value);
+            }
+            builder.append(true)?;
+        }
+        let map: ArrayRef = Arc::new(builder.finish());
+
+        let keys: ArrayRef = Arc::new(
+            vec![Some("b"), Some("b"), Some("a"), None]
+                .into_iter()
+                .collect::<DictionaryArray<Int32Type>>(),
+        );
+        let arg_fields = vec![
+            Field::new("map", map.data_type().clone(), true).into(),
+            Field::new("key", keys.data_type().clone(), true).into(),
+        ];
+
+        let result = GetFieldFunc::new().invoke_with_args(ScalarFunctionArgs {
+            args: vec![
+                ColumnarValue::Array(Arc::clone(&map)),
+                ColumnarValue::Array(keys),
+            ],
+            arg_fields,
+            number_rows: 4,
+            return_field: Field::new("f", DataType::Int32, true).into(),
+            config_options: Arc::new(ConfigOptions::default()),
+        })?;
+
+        let ColumnarValue::Array(result) = result else {
+            panic!("expected an array");
+        };
+        let expected: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(2), Some(3), None, None]));
+        assert_eq!(&result, &expected);
+
+        // an all-null dictionary has no values for its keys to point to
+        let keys: ArrayRef = Arc::new(DictionaryArray::<Int32Type>::try_new(
+            Int32Array::new_null(4),
+            new_empty_array(&DataType::Utf8),
+        )?);
+        let arg_fields = vec![
+            Field::new("map", map.data_type().clone(), true).into(),
+            Field::new("key", keys.data_type().clone(), true).into(),
+        ];
+        let result = GetFieldFunc::new().invoke_with_args(ScalarFunctionArgs {
+            args: vec![ColumnarValue::Array(map), ColumnarValue::Array(keys)],
+            arg_fields,
+            number_rows: 4,
+            return_field: Field::new("f", DataType::Int32, true).into(),
+            config_options: Arc::new(ConfigOptions::default()),
+        })?;
+
+        let ColumnarValue::Array(result) = result else {
+            panic!("expected an array");
+        };
+        let expected: ArrayRef = Arc::new(Int32Array::new_null(4));
+        assert_eq!(&result, &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_field_map_with_utf8_view_values() -> Result<()> {
+        // {a: fusion, b: NULL}, {a: <long string>}, {b: arrow}
+        let long = "a value longer than the inline prefix";
+        let mut builder =
+            MapBuilder::new(None, StringBuilder::new(), StringViewBuilder::new());
+        for entries in [
+            vec![("a", Some("fusion")), ("b", None)],
+            vec![("a", Some(long))],
+            vec![("b", Some("arrow"))],
+        ] {
+            for (key, value) in entries {
+                builder.keys().append_value(key);
+                builder.values().append_option(value);
+            }
+            builder.append(true)?;
+        }
+        let map: ArrayRef = Arc::new(builder.finish());
+
+        let key = ScalarValue::new_utf8("a");
+        let arg_fields: Vec<FieldRef> = vec![
+            Field::new("map", map.data_type().clone(), true).into(),
+            Field::new("key", key.data_type(), false).into(),
+        ];
+
+        let func = GetFieldFunc::new();
+        let return_field = func.return_field_from_args(ReturnFieldArgs {
+            arg_fields: &arg_fields,
+            scalar_arguments: &[None, Some(&key)],
+        })?;
+        assert_eq!(return_field.data_type(), &DataType::Utf8View);
+
+        let result = func.invoke_with_args(ScalarFunctionArgs {
+            args: vec![ColumnarValue::Array(map), ColumnarValue::Scalar(key)],
+            arg_fields,
+            number_rows: 3,
+            return_field,
+            config_options: Arc::new(ConfigOptions::default()),
+        })?;
+
+        let ColumnarValue::Array(result) = result else {
+            panic!("expected an array");
+        };
+        assert_eq!(result.data_type(), &DataType::Utf8View);
+        let expected: ArrayRef = Arc::new(StringViewArray::from(vec![
+            Some("fusion"),
+            Some(long),
+            None,
+        ]));
+        assert_eq!(&result, &expected);
+
+        Ok(())
+    }
+}
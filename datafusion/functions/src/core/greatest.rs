@@ -15,7 +15,11 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::core::greatest_least_utils::GreatestLeastOperator;
+use crate::core::coercion_utils::coerce_with_signature;
+use crate::core::greatest_least_utils::{
+    split_null_policy, split_null_policy_literal, value_types, GreatestLeastOperator,
+    NullPolicy,
+};
 use arrow::array::{make_comparator, Array, BooleanArray};
 use arrow::buffer::BooleanBuffer;
 use arrow::compute::kernels::cmp;
@@ -23,10 +27,14 @@ use arrow::compute::SortOptions;
 use arrow::datatypes::DataType;
 use datafusion_common::{internal_err, Result, ScalarValue};
 use datafusion_doc::Documentation;
-use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{lit, ColumnarValue, Expr, ScalarFunctionArgs, ScalarUDF};
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use datafusion_macros::user_doc;
+use itertools::Itertools;
 use std::any::Any;
+use std::sync::Arc;
 
 const SORT_OPTIONS: SortOptions = SortOptions {
     // We want greatest first
@@ -38,8 +46,9 @@ const SORT_OPTIONS: SortOptions = SortOptions {
 
 #[user_doc(
     doc_section(label = "Conditional Functions"),
-    description = "Returns the greatest value in a list of expressions. Returns _null_ if all expressions are _null_.",
-    syntax_example = "greatest(expression1[, ..., expression_n])",
+    description = "Returns the greatest value in a list of expressions. Returns _null_ if all expressions are _null_.
+NULL arguments are ignored by default. With `nulls => 'last'` any _null_ argument makes the result _null_, `nulls => 'ignore'` and `nulls => 'first'` ignore them.",
+    syntax_example = "greatest(expression1[, ..., expression_n][, nulls => 'ignore' | 'first' | 'last'])",
     sql_example = r#"```sql
 > select greatest(4, 7, 5);
 +---------------------------+
@@ -140,18 +149,84 @@ impl ScalarUDFImpl for GreatestFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        Ok(arg_types[0].clone())
+        super::greatest_least_utils::find_coerced_type::<Self>(value_types(arg_types))
+    }
+
+    fn schema_name(&self, args: &[Expr]) -> Result<String> {
+        let (args, null_policy) = split_null_policy_literal(args);
+        let args = args
+            .iter()
+            .map(|arg| arg.schema_name().to_string())
+            .join(",");
+        match null_policy {
+            None => Ok(format!("{}({args})", self.name())),
+            Some(policy) => Ok(format!("{}({args}, nulls => '{policy}')", self.name())),
+        }
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        super::greatest_least_utils::execute_conditional::<Self>(&args.args)
+        let (arguments, null_policy) = split_null_policy::<Self>(args.args)?;
+        let arguments = super::greatest_least_utils::normalize_timestamps::<Self>(
+            arguments,
+            args.return_field.data_type(),
+            &args.config_options,
+        )?;
+        let result =
+            super::greatest_least_utils::execute_conditional::<Self>(&arguments)?;
+        if null_policy == NullPolicy::Last {
+            super::greatest_least_utils::propagate_nulls(&arguments, result)
+        } else {
+            Ok(result)
+        }
     }
 
-    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
-        let coerced_type =
-            super::greatest_least_utils::find_coerced_type::<Self>(arg_types)?;
+    /// The arguments that can't beat a literal argument are dropped, so that
+    /// they are not evaluated
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let (values, null_policy) = split_null_policy_literal(&args);
+        // a dropped argument could be NULL
+        if null_policy == Some(NullPolicy::Last) {
+            return Ok(ExprSimplifyResult::Original(args));
+        }
+        match super::greatest_least_utils::drop_dominated_args::<Self>(values, info)? {
+            None => Ok(ExprSimplifyResult::Original(args)),
+            Some(mut kept) if kept.len() == 1 => {
+                Ok(ExprSimplifyResult::Simplified(kept.remove(0)))
+            }
+            Some(mut kept) => {
+                kept.extend(null_policy.map(|policy| lit(policy.to_scalar())));
+                let func = ScalarUDF::from(Self::new());
+                Ok(ExprSimplifyResult::Simplified(Expr::ScalarFunction(
+                    ScalarFunction::new_udf(Arc::new(func), kept),
+                )))
+            }
+        }
+    }
 
-        Ok(vec![coerced_type; arg_types.len()])
+    /// A cast of the result is the type the arguments are compared in, when
+    /// they all cast to it without losing values
+    fn coerce_types_for_cast(
+        &self,
+        arg_types: &[DataType],
+        cast_type: &DataType,
+    ) -> Result<Option<Vec<DataType>>> {
+        Ok(super::greatest_least_utils::coerce_types_for_cast(
+            arg_types, cast_type,
+        ))
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_with_signature(self.name(), "T, ...", arg_types, || {
+            // the options literal is passed as is
+            let values = value_types(arg_types);
+            let mut coerced = super::greatest_least_utils::coerce_types::<Self>(values)?;
+            coerced.extend_from_slice(&arg_types[values.len()..]);
+            Ok(coerced)
+        })
     }
 
     fn documentation(&self) -> Option<&Documentation> {
@@ -162,7 +237,7 @@ impl ScalarUDFImpl for GreatestFunc {
 #[cfg(test)]
 mod test {
     use crate::core;
-    use arrow::datatypes::DataType;
+    use arrow::datatypes::{DataType, TimeUnit};
     use datafusion_expr::ScalarUDFImpl;
 
     #[test]
@@ -176,4 +251,68 @@ mod test {
             vec![DataType::Decimal128(11, 4), DataType::Decimal128(11, 4)]
         );
     }
+
+    #[test]
+    fn test_greatest_return_types_mixed_signedness() {
+        let greatest = core::greatest::GreatestFunc::new();
+        let return_type = greatest
+            .coerce_types(&[DataType::UInt64, DataType::Int64])
+            .unwrap();
+        assert_eq!(
+            return_type,
+            vec![DataType::Decimal128(20, 0), DataType::Decimal128(20, 0)]
+        );
+
+        let return_type = greatest
+            .coerce_types(&[DataType::Int8, DataType::UInt32, DataType::Int16])
+            .unwrap();
+        assert_eq!(return_type, vec![DataType::Int64; 3]);
+    }
+
+    #[test]
+    fn test_greatest_return_types_timestamps_with_and_without_time_zone() {
+        let greatest = core::greatest::GreatestFunc::new();
+        let utc = DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()));
+        let arg_types = [
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())),
+        ];
+
+        // the timestamp without a time zone only gets the common time unit
+        let coerced_types = greatest.coerce_types(&arg_types).unwrap();
+        assert_eq!(
+            coerced_types,
+            vec![
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                utc.clone()
+            ]
+        );
+        assert_eq!(greatest.return_type(&coerced_types).unwrap(), utc);
+    }
+
+    #[test]
+    fn test_greatest_return_types_timestamps_with_same_time_zone() {
+        let greatest = core::greatest::GreatestFunc::new();
+        let new_york =
+            DataType::Timestamp(TimeUnit::Nanosecond, Some("America/New_York".into()));
+
+        let coerced_types = greatest
+            .coerce_types(&[new_york.clone(), new_york.clone()])
+            .unwrap();
+        assert_eq!(coerced_types, vec![new_york.clone(); 2]);
+        assert_eq!(greatest.return_type(&coerced_types).unwrap(), new_york);
+
+        // dates are compared as timestamps without a time zone
+        let coerced_types = greatest
+            .coerce_types(&[new_york.clone(), DataType::Date32])
+            .unwrap();
+        assert_eq!(
+            coerced_types,
+            vec![
+                new_york.clone(),
+                DataType::Timestamp(TimeUnit::Nanosecond, None)
+            ]
+        );
+        assert_eq!(greatest.return_type(&coerced_types).unwrap(), new_york);
+    }
 }
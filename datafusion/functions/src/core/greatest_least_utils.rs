@@ -15,26 +15,157 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::array::{Array, ArrayRef, BooleanArray};
+use super::options_utils::{is_options_type, option_value, options_literal};
+use arrow::array::timezone::Tz;
+use arrow::array::{
+    downcast_dictionary_array, new_null_array, Array, ArrayRef, BooleanArray,
+    DictionaryArray, PrimitiveArray,
+};
+use arrow::buffer::ScalarBuffer;
 use arrow::compute::kernels::zip::zip;
-use arrow::datatypes::DataType;
-use datafusion_common::{internal_err, plan_err, Result, ScalarValue};
+use arrow::compute::{is_null, nullif, or};
+use arrow::datatypes::{ArrowDictionaryKeyType, DataType};
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::{
+    exec_err, internal_err, plan_err, DataFusionError, Result, ScalarValue,
+};
+use datafusion_expr::expr::{Cast, TryCast};
+use datafusion_expr::simplify::SimplifyInfo;
+use datafusion_expr::Expr;
 use datafusion_expr_common::columnar_value::ColumnarValue;
 use datafusion_expr_common::type_coercion::binary::type_union_resolution;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
 use std::sync::Arc;
 
+/// How `greatest` and `least` treat NULL arguments, set with the named
+/// argument `nulls`, such as `least(a, b, nulls => 'first')`.
+///
+/// The policy is passed to the functions as a trailing options literal, see
+/// [`NullPolicy::to_scalar`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NullPolicy {
+    /// NULLs are ignored, the result is NULL only if all the arguments are NULL
+    #[default]
+    Ignore,
+    /// NULL is smaller than any other value, so `least` is NULL if any
+    /// argument is NULL
+    First,
+    /// NULL is greater than any other value, so `greatest` is NULL if any
+    /// argument is NULL
+    Last,
+}
+
+impl FromStr for NullPolicy {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "ignore" => Ok(Self::Ignore),
+            "first" => Ok(Self::First),
+            "last" => Ok(Self::Last),
+            _ => plan_err!(
+                "Invalid nulls option '{s}', expected one of 'ignore', 'first' or 'last'"
+            ),
+        }
+    }
+}
+
+impl Display for NullPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Ignore => write!(f, "ignore"),
+            Self::First => write!(f, "first"),
+            Self::Last => write!(f, "last"),
+        }
+    }
+}
+
+/// The named argument setting the [`NullPolicy`]
+const NULLS_OPTION: &str = "nulls";
+
+impl NullPolicy {
+    /// Returns the options literal `{nulls: '<policy>'}` passing this policy
+    /// as the last argument of `greatest` or `least`, which is how the named
+    /// argument `nulls => '<policy>'` is planned
+    pub fn to_scalar(self) -> ScalarValue {
+        options_literal(NULLS_OPTION, &self.to_string())
+    }
+}
+
+impl TryFrom<&ScalarValue> for NullPolicy {
+    type Error = DataFusionError;
+
+    fn try_from(options: &ScalarValue) -> Result<Self> {
+        option_value(options, NULLS_OPTION)?.parse()
+    }
+}
+
+/// Returns true for the type of the options literal of [`NullPolicy::to_scalar`]
+fn is_null_policy_type(data_type: &DataType) -> bool {
+    is_options_type(data_type, NULLS_OPTION)
+}
+
+/// Returns the types of the arguments that are compared, which are all of
+/// them but the trailing options literal if any
+pub(super) fn value_types(arg_types: &[DataType]) -> &[DataType] {
+    match arg_types.split_last() {
+        Some((options, values)) if is_null_policy_type(options) => values,
+        _ => arg_types,
+    }
+}
+
+/// Splits the trailing options literal off the arguments, returning the
+/// arguments that are compared and the [`NullPolicy`] to compare them with
+pub(super) fn split_null_policy<Op: GreatestLeastOperator>(
+    mut args: Vec<ColumnarValue>,
+) -> Result<(Vec<ColumnarValue>, NullPolicy)> {
+    if !args
+        .last()
+        .is_some_and(|arg| is_null_policy_type(&arg.data_type()))
+    {
+        return Ok((args, NullPolicy::default()));
+    }
+    match args.pop() {
+        Some(ColumnarValue::Scalar(options)) => {
+            Ok((args, NullPolicy::try_from(&options)?))
+        }
+        _ => exec_err!("{} expects the nulls option to be a literal", Op::NAME),
+    }
+}
+
+/// Splits the trailing options literal off the argument expressions, see
+/// [`split_null_policy`]. The policy is `None` without an options literal.
+pub(super) fn split_null_policy_literal(args: &[Expr]) -> (&[Expr], Option<NullPolicy>) {
+    match args.split_last() {
+        Some((Expr::Literal(options, _), values))
+            if is_null_policy_type(&options.data_type()) =>
+        {
+            match NullPolicy::try_from(options) {
+                Ok(null_policy) => (values, Some(null_policy)),
+                Err(_) => (args, None),
+            }
+        }
+        _ => (args, None),
+    }
+}
+
 pub(super) trait GreatestLeastOperator {
     const NAME: &'static str;
 
+    /// Return the scalar that we should keep, `lhs` if both are equal
     fn keep_scalar<'a>(
         lhs: &'a ScalarValue,
         rhs: &'a ScalarValue,
     ) -> Result<&'a ScalarValue>;
 
-    /// Return array with true for values that we should keep from the lhs array
+    /// Return array with true for values that we should keep from the lhs array,
+    /// including the values of `lhs` that are equal to the ones of `rhs`
     fn get_indexes_to_keep(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray>;
 }
 
+/// Keep the values from `lhs` or `rhs` per row, `lhs` when both are equal
 fn keep_array<Op: GreatestLeastOperator>(
     lhs: ArrayRef,
     rhs: ArrayRef,
@@ -47,6 +178,124 @@ fn keep_array<Op: GreatestLeastOperator>(
     Ok(result)
 }
 
+/// Returns true for a dictionary array whose keys are all null, which
+/// `greatest` and `least` never keep a value from
+fn is_all_null_dictionary(array: &ArrayRef) -> bool {
+    matches!(array.data_type(), DataType::Dictionary(_, _))
+        && array.null_count() == array.len()
+}
+
+/// Sets the null keys of a dictionary array to zero.
+///
+/// The keys behind nulls can hold any value, which can overflow when the
+/// keys of two dictionaries are merged by [`zip`]
+fn zero_null_keys(array: &ArrayRef) -> Result<ArrayRef> {
+    fn zero_keys<K: ArrowDictionaryKeyType>(
+        dictionary: &DictionaryArray<K>,
+    ) -> Result<ArrayRef> {
+        let keys = dictionary.keys();
+        let Some(nulls) = keys.nulls().filter(|nulls| nulls.null_count() > 0) else {
+            return Ok(Arc::new(dictionary.clone()));
+        };
+
+        let values = keys
+            .values()
+            .iter()
+            .zip(nulls.iter())
+            .map(|(key, is_valid)| if is_valid { *key } else { Default::default() })
+            .collect::<ScalarBuffer<_>>();
+        let keys = PrimitiveArray::<K>::new(values, Some(nulls.clone()));
+        Ok(Arc::new(DictionaryArray::try_new(
+            keys,
+            Arc::clone(dictionary.values()),
+        )?))
+    }
+
+    downcast_dictionary_array!(
+        array => zero_keys(array),
+        _ => Ok(Arc::clone(array))
+    )
+}
+
+/// Folds arguments from left to right into the value `Op` keeps
+struct ConditionalAccumulator<'a, Op> {
+    result: Option<ArrayRef>,
+    pending_scalar: Option<&'a ScalarValue>,
+    /// The first all-null dictionary argument, returned when there is no
+    /// other value to keep
+    all_null: Option<ArrayRef>,
+    op: PhantomData<Op>,
+}
+
+impl<'a, Op: GreatestLeastOperator> ConditionalAccumulator<'a, Op> {
+    fn new() -> Self {
+        Self {
+            result: None,
+            pending_scalar: None,
+            all_null: None,
+            op: PhantomData,
+        }
+    }
+
+    fn update(&mut self, arg: &'a ColumnarValue) -> Result<()> {
+        match arg {
+            ColumnarValue::Scalar(scalar) => {
+                // Optimization: merge consecutive scalars into one to avoid
+                // comparing each of them against the arrays (constant folding)
+                self.pending_scalar = Some(match self.pending_scalar {
+                    Some(pending) => Op::keep_scalar(pending, scalar)?,
+                    None => scalar,
+                });
+            }
+            ColumnarValue::Array(array) if is_all_null_dictionary(array) => {
+                // every row is skipped, so don't compare nor decode the values
+                self.all_null.get_or_insert_with(|| Arc::clone(array));
+            }
+            ColumnarValue::Array(array) => {
+                let array = zero_null_keys(array)?;
+                if let Some(scalar) = self.pending_scalar.take() {
+                    let scalar = scalar.to_array_of_size(array.len())?;
+                    self.result = Some(match self.result.take() {
+                        Some(result) => keep_array::<Op>(result, scalar)?,
+                        None => scalar,
+                    });
+                }
+
+                self.result = Some(match self.result.take() {
+                    Some(result) => keep_array::<Op>(result, array)?,
+                    None => array,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<ColumnarValue> {
+        match (self.result, self.pending_scalar, self.all_null) {
+            (Some(result), Some(scalar), _) => {
+                let scalar = scalar.to_array_of_size(result.len())?;
+                Ok(ColumnarValue::Array(keep_array::<Op>(result, scalar)?))
+            }
+            (Some(result), None, _) => Ok(ColumnarValue::Array(result)),
+            (None, Some(scalar), Some(all_null)) => Ok(ColumnarValue::Array(
+                scalar.to_array_of_size(all_null.len())?,
+            )),
+            // If we only have scalars, return the one that we should keep (largest/least)
+            (None, Some(scalar), None) => Ok(ColumnarValue::Scalar(scalar.clone())),
+            (None, None, Some(all_null)) => Ok(ColumnarValue::Array(all_null)),
+            (None, None, None) => {
+                internal_err!("{} was called with no arguments", Op::NAME)
+            }
+        }
+    }
+}
+
+/// Evaluate `greatest`/`least` over `args`.
+///
+/// Arguments are folded from left to right and [`keep_array`] keeps the left
+/// value whenever the two values compare equal. When several arguments tie,
+/// the result is therefore always taken from the earliest of them, independent
+/// of whether the arguments are scalars or arrays.
 pub(super) fn execute_conditional<Op: GreatestLeastOperator>(
     args: &[ColumnarValue],
 ) -> Result<ColumnarValue> {
@@ -62,72 +311,636 @@ pub(super) fn execute_conditional<Op: GreatestLeastOperator>(
         return Ok(args[0].clone());
     }
 
-    // Split to scalars and arrays for later optimization
-    let (scalars, arrays): (Vec<_>, Vec<_>) = args.iter().partition(|x| match x {
-        ColumnarValue::Scalar(_) => true,
-        ColumnarValue::Array(_) => false,
-    });
-
-    let mut arrays_iter = arrays.iter().map(|x| match x {
-        ColumnarValue::Array(a) => a,
-        _ => unreachable!(),
-    });
+    let mut accumulator = ConditionalAccumulator::<Op>::new();
+    for arg in args {
+        accumulator.update(arg)?;
+    }
+    accumulator.finish()
+}
 
-    let first_array = arrays_iter.next();
+/// Evaluate both `Min` and `Max` over `args` in a single pass over the
+/// arguments, with the same tie breaking as [`execute_conditional`].
+pub(super) fn execute_conditional_pair<Min, Max>(
+    args: &[ColumnarValue],
+) -> Result<(ColumnarValue, ColumnarValue)>
+where
+    Min: GreatestLeastOperator,
+    Max: GreatestLeastOperator,
+{
+    if args.is_empty() {
+        return internal_err!(
+            "{} and {} were called with no arguments. They require at least 1.",
+            Min::NAME,
+            Max::NAME
+        );
+    }
 
-    let mut result: ArrayRef;
+    let mut min = ConditionalAccumulator::<Min>::new();
+    let mut max = ConditionalAccumulator::<Max>::new();
+    for arg in args {
+        min.update(arg)?;
+        max.update(arg)?;
+    }
+    Ok((min.finish()?, max.finish()?))
+}
 
-    // Optimization: merge all scalars into one to avoid recomputing (constant folding)
-    if !scalars.is_empty() {
-        let mut scalars_iter = scalars.iter().map(|x| match x {
-            ColumnarValue::Scalar(s) => s,
-            _ => unreachable!(),
+/// Sets `result` to NULL for the rows where any of `args` is NULL
+pub(super) fn propagate_nulls(
+    args: &[ColumnarValue],
+    result: ColumnarValue,
+) -> Result<ColumnarValue> {
+    let data_type = result.data_type();
+    if args
+        .iter()
+        .any(|arg| matches!(arg, ColumnarValue::Scalar(scalar) if scalar.is_null()))
+    {
+        return Ok(match result {
+            ColumnarValue::Array(array) => {
+                ColumnarValue::Array(new_null_array(&data_type, array.len()))
+            }
+            ColumnarValue::Scalar(_) => {
+                ColumnarValue::Scalar(ScalarValue::try_from(&data_type)?)
+            }
         });
+    }
 
-        // We have at least one scalar
-        let mut result_scalar = scalars_iter.next().unwrap();
-
-        for scalar in scalars_iter {
-            result_scalar = Op::keep_scalar(result_scalar, scalar)?;
-        }
+    let ColumnarValue::Array(result) = result else {
+        // all the arguments are non-null scalars
+        return Ok(result);
+    };
 
-        // If we only have scalars, return the one that we should keep (largest/least)
-        if arrays.is_empty() {
-            return Ok(ColumnarValue::Scalar(result_scalar.clone()));
+    let mut any_null: Option<BooleanArray> = None;
+    for arg in args {
+        if let ColumnarValue::Array(array) = arg {
+            let nulls = is_null(array)?;
+            any_null = Some(match any_null {
+                Some(any_null) => or(&any_null, &nulls)?,
+                None => nulls,
+            });
         }
-
-        // We have at least one array
-        let first_array = first_array.unwrap();
-
-        // Start with the result value
-        result = keep_array::<Op>(
-            Arc::clone(first_array),
-            result_scalar.to_array_of_size(first_array.len())?,
-        )?;
-    } else {
-        // If we only have arrays, start with the first array
-        // (We must have at least one array)
-        result = Arc::clone(first_array.unwrap());
     }
 
-    for array in arrays_iter {
-        result = keep_array::<Op>(Arc::clone(array), result)?;
+    match any_null {
+        Some(any_null) => Ok(ColumnarValue::Array(nullif(&result, &any_null)?)),
+        None => Ok(ColumnarValue::Array(result)),
     }
-
-    Ok(ColumnarValue::Array(result))
 }
 
+/// Returns the type all the arguments are coerced to before being compared.
+///
+/// Integers of mixed signedness are widened to a type holding every value of
+/// both, so that they keep their order: for example `UInt32` and `Int32` are
+/// compared as `Int64`, and `UInt64` and `Int64` as `Decimal128(20, 0)` since
+/// no integer type can hold both a large `UInt64` and a negative `Int64`.
+///
+/// Timestamps with and without a time zone are compared as timestamps with
+/// the time zone, see [`coerce_types`]. The time zone shared by all the
+/// timestamps that have one is kept, including when they are compared to
+/// dates.
 pub(super) fn find_coerced_type<Op: GreatestLeastOperator>(
     data_types: &[DataType],
 ) -> Result<DataType> {
     if data_types.is_empty() {
-        plan_err!(
+        return plan_err!(
             "{} was called without any arguments. It requires at least 1.",
             Op::NAME
-        )
-    } else if let Some(coerced_type) = type_union_resolution(data_types) {
-        Ok(coerced_type)
-    } else {
-        plan_err!("Cannot find a common type for arguments")
+        );
+    }
+    let Some(coerced_type) = type_union_resolution(data_types) else {
+        return plan_err!("Cannot find a common type for arguments");
+    };
+
+    match (coerced_type, common_time_zone(data_types)) {
+        (DataType::Timestamp(unit, None), Some(time_zone)) => {
+            Ok(DataType::Timestamp(unit, Some(time_zone)))
+        }
+        (coerced_type, _) => Ok(coerced_type),
+    }
+}
+
+/// Returns the time zone of the timestamps in `data_types` that have one, if
+/// they all have the same
+fn common_time_zone(data_types: &[DataType]) -> Option<Arc<str>> {
+    let mut time_zones = data_types.iter().filter_map(|data_type| match data_type {
+        DataType::Timestamp(_, Some(time_zone)) => Some(time_zone),
+        _ => None,
+    });
+    let first = time_zones.next()?;
+    time_zones
+        .all(|time_zone| time_zone == first)
+        .then(|| Arc::clone(first))
+}
+
+/// Returns the types the arguments are coerced to, which is the type returned
+/// by [`find_coerced_type`] for all of them except for timestamps without a
+/// time zone and dates compared to timestamps with one.
+///
+/// Casting those to a time zone would take their values as UTC, so they only
+/// get the common time unit here and are interpreted in the session time
+/// zone by [`normalize_timestamps`] during execution.
+pub(super) fn coerce_types<Op: GreatestLeastOperator>(
+    arg_types: &[DataType],
+) -> Result<Vec<DataType>> {
+    let coerced_type = find_coerced_type::<Op>(arg_types)?;
+
+    Ok(arg_types
+        .iter()
+        .map(|arg_type| match (arg_type, &coerced_type) {
+            (
+                DataType::Timestamp(_, None) | DataType::Date32 | DataType::Date64,
+                DataType::Timestamp(unit, Some(_)),
+            ) => DataType::Timestamp(*unit, None),
+            _ => coerced_type.clone(),
+        })
+        .collect())
+}
+
+/// Returns the types the arguments are coerced to when the result is cast to
+/// `cast_type`, which is the type they are then compared in, or `None` if
+/// any of them may lose values cast to it.
+///
+/// For example in `greatest(i, f)::double`, with an `Int32` `i` and a
+/// `Float32` `f`, the arguments are compared as `Float64` rather than as
+/// `Float32`, their common type, which can't tell large integers apart.
+pub(super) fn coerce_types_for_cast(
+    arg_types: &[DataType],
+    cast_type: &DataType,
+) -> Option<Vec<DataType>> {
+    // the options literal is passed as is
+    let values = value_types(arg_types);
+    if values.is_empty() || !values.iter().all(|t| casts_losslessly(t, cast_type)) {
+        return None;
+    }
+
+    let mut coerced = vec![cast_type.clone(); values.len()];
+    coerced.extend_from_slice(&arg_types[values.len()..]);
+    Some(coerced)
+}
+
+/// Returns true if all the values of `from` can be cast to `to` exactly.
+///
+/// Floats are never cast losslessly to integers or decimals, as they round
+/// and may be out of range, and integers only to floats whose mantissa holds
+/// all their values.
+fn casts_losslessly(from: &DataType, to: &DataType) -> bool {
+    if from.is_null() {
+        return to.is_numeric();
+    }
+    match to {
+        DataType::Decimal128(precision, scale)
+        | DataType::Decimal256(precision, scale) => {
+            let integer_digits = i16::from(*precision) - i16::from(*scale);
+            match from {
+                DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => {
+                    s <= scale && i16::from(*p) - i16::from(*s) <= integer_digits
+                }
+                DataType::Int8 | DataType::UInt8 => 3 <= integer_digits,
+                DataType::Int16 | DataType::UInt16 => 5 <= integer_digits,
+                DataType::Int32 | DataType::UInt32 => 10 <= integer_digits,
+                DataType::Int64 => 19 <= integer_digits,
+                DataType::UInt64 => 20 <= integer_digits,
+                _ => false,
+            }
+        }
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+            match (float_width(from), integer_bits(from)) {
+                (Some(from_width), _) => from_width <= float_width(to).unwrap_or(0),
+                // the magnitude bits, without the sign bit
+                (_, Some((bits, signed))) => {
+                    bits - u32::from(signed) <= mantissa_bits(to)
+                }
+                _ => false,
+            }
+        }
+        _ => match (integer_bits(from), integer_bits(to)) {
+            (Some((from_bits, from_signed)), Some((to_bits, to_signed))) => {
+                match (from_signed, to_signed) {
+                    (false, true) => from_bits < to_bits,
+                    (true, false) => false,
+                    _ => from_bits <= to_bits,
+                }
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Returns the width in bits and the signedness of an integer type
+fn integer_bits(data_type: &DataType) -> Option<(u32, bool)> {
+    match data_type {
+        DataType::Int8 => Some((8, true)),
+        DataType::Int16 => Some((16, true)),
+        DataType::Int32 => Some((32, true)),
+        DataType::Int64 => Some((64, true)),
+        DataType::UInt8 => Some((8, false)),
+        DataType::UInt16 => Some((16, false)),
+        DataType::UInt32 => Some((32, false)),
+        DataType::UInt64 => Some((64, false)),
+        _ => None,
+    }
+}
+
+/// Returns the width in bits of a float type
+fn float_width(data_type: &DataType) -> Option<u32> {
+    match data_type {
+        DataType::Float16 => Some(16),
+        DataType::Float32 => Some(32),
+        DataType::Float64 => Some(64),
+        _ => None,
+    }
+}
+
+/// Returns the number of bits of the significand of a float type, including
+/// the implicit leading bit
+fn mantissa_bits(data_type: &DataType) -> u32 {
+    match data_type {
+        DataType::Float16 => 11,
+        DataType::Float32 => 24,
+        DataType::Float64 => 53,
+        _ => 0,
+    }
+}
+
+/// Converts the arguments that are timestamps without a time zone to
+/// `return_type` when it's a timestamp with a time zone, taking their values
+/// as local times of the session time zone.
+pub(super) fn normalize_timestamps<Op: GreatestLeastOperator>(
+    args: Vec<ColumnarValue>,
+    return_type: &DataType,
+    config_options: &ConfigOptions,
+) -> Result<Vec<ColumnarValue>> {
+    let DataType::Timestamp(unit, Some(_)) = return_type else {
+        return Ok(args);
+    };
+    let is_naive =
+        |arg: &ColumnarValue| matches!(arg.data_type(), DataType::Timestamp(_, None));
+    if !args.iter().any(is_naive) {
+        return Ok(args);
+    }
+
+    let time_zone = config_options.execution.time_zone.as_str();
+    if let Err(e) = time_zone.parse::<Tz>() {
+        return exec_err!(
+            "{} cannot compare timestamps with and without a time zone as the session \
+            time zone '{time_zone}' is invalid: {e}. Set datafusion.execution.time_zone \
+            or cast the arguments to the same type",
+            Op::NAME
+        );
+    }
+
+    // cast to the session time zone first, as casting a timestamp without a
+    // time zone to one with a time zone takes it as a local time of that zone
+    let local_type = DataType::Timestamp(*unit, Some(Arc::from(time_zone)));
+    args.into_iter()
+        .map(|arg| {
+            if is_naive(&arg) {
+                arg.cast_to(&local_type, None)?.cast_to(return_type, None)
+            } else {
+                Ok(arg)
+            }
+        })
+        .collect()
+}
+
+/// Returns the arguments without the ones that can't change the result, or
+/// `None` if every argument can.
+///
+/// An argument is dropped when the bounds of its values, known from its
+/// integer type or from the type it is cast from, can't beat a non-NULL
+/// literal argument, such as `CAST(a AS Int64)` for an `Int32` column `a` in
+/// `greatest(CAST(a AS Int64), 5000000000)`. The dropped arguments are never
+/// evaluated. The literal is kept at its position, and the other literals are
+/// dropped as their bounds are their value.
+///
+/// This is only valid when NULL arguments are ignored, as a dropped argument
+/// could be NULL.
+pub(super) fn drop_dominated_args<Op: GreatestLeastOperator>(
+    args: &[Expr],
+    info: &dyn SimplifyInfo,
+) -> Result<Option<Vec<Expr>>> {
+    let data_types = args
+        .iter()
+        .map(|arg| info.get_data_type(arg))
+        .collect::<Result<Vec<_>>>()?;
+    // only the arguments that are coerced to the same integer type are compared
+    if !data_types
+        .iter()
+        .all(|data_type| data_type.is_integer() && data_type == &data_types[0])
+    {
+        return Ok(None);
+    }
+
+    let mut dominant: Option<(usize, &ScalarValue)> = None;
+    for (i, arg) in args.iter().enumerate() {
+        let Expr::Literal(value, _) = arg else {
+            continue;
+        };
+        if value.is_null() {
+            continue;
+        }
+        dominant = match dominant {
+            Some((j, kept)) if Op::keep_scalar(kept, value)? == kept => Some((j, kept)),
+            _ => Some((i, value)),
+        };
+    }
+    let Some((dominant_index, dominant)) = dominant else {
+        return Ok(None);
+    };
+
+    let mut kept = Vec::with_capacity(args.len());
+    for (i, arg) in args.iter().enumerate() {
+        let dominated = match arg {
+            _ if i == dominant_index => false,
+            // NULLs are ignored
+            Expr::Literal(value, _) if value.is_null() => true,
+            _ => match value_bounds(arg, info)? {
+                Some((min, max)) => {
+                    let best = Op::keep_scalar(&min, &max)?;
+                    Op::keep_scalar(dominant, best)? == dominant
+                }
+                None => false,
+            },
+        };
+        if !dominated {
+            kept.push(arg.clone());
+        }
+    }
+
+    Ok((kept.len() < args.len()).then_some(kept))
+}
+
+/// Returns the smallest and the largest value `expr` can evaluate to, if they
+/// are known from its value, its integer type or the type it is cast from
+fn value_bounds(
+    expr: &Expr,
+    info: &dyn SimplifyInfo,
+) -> Result<Option<(ScalarValue, ScalarValue)>> {
+    let type_bounds = |data_type: &DataType| {
+        if !data_type.is_integer() {
+            return None;
+        }
+        Some((ScalarValue::min(data_type)?, ScalarValue::max(data_type)?))
+    };
+
+    match expr {
+        Expr::Literal(value, _) if !value.is_null() => {
+            Ok(Some((value.clone(), value.clone())))
+        }
+        Expr::Cast(Cast { expr, data_type })
+        | Expr::TryCast(TryCast { expr, data_type }) => {
+            let Some((min, max)) = value_bounds(expr, info)? else {
+                return Ok(type_bounds(data_type));
+            };
+            // a narrowing cast can't exceed the bounds of the target type
+            match (min.cast_to(data_type), max.cast_to(data_type)) {
+                (Ok(min), Ok(max)) => Ok(Some((min, max))),
+                _ => Ok(type_bounds(data_type)),
+            }
+        }
+        _ => Ok(type_bounds(&info.get_data_type(expr)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::greatest::GreatestFunc;
+    use arrow::array::{AsArray, Int32Array, Int8Array, StringArray, StructArray};
+    use arrow::buffer::NullBuffer;
+    use arrow::datatypes::{Field, Fields, Int32Type, Int8Type};
+
+    /// Compares values by their tens digit only, so that values like 11 and
+    /// 12 are equal for the operator but can still be told apart
+    struct GreatestTens;
+
+    fn tens(value: &ScalarValue) -> Option<i32> {
+        match value {
+            ScalarValue::Int32(v) => v.map(|v| v / 10),
+            _ => unreachable!(),
+        }
+    }
+
+    impl GreatestLeastOperator for GreatestTens {
+        const NAME: &'static str = "greatest_tens";
+
+        fn keep_scalar<'a>(
+            lhs: &'a ScalarValue,
+            rhs: &'a ScalarValue,
+        ) -> Result<&'a ScalarValue> {
+            Ok(if tens(lhs) >= tens(rhs) { lhs } else { rhs })
+        }
+
+        fn get_indexes_to_keep(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+            let lhs = lhs.as_primitive::<Int32Type>();
+            let rhs = rhs.as_primitive::<Int32Type>();
+            Ok(lhs
+                .iter()
+                .zip(rhs.iter())
+                .map(|(l, r)| Some(l.map(|v| v / 10) >= r.map(|v| v / 10)))
+                .collect())
+        }
+    }
+
+    /// The least counterpart of [`GreatestTens`]
+    struct LeastTens;
+
+    impl GreatestLeastOperator for LeastTens {
+        const NAME: &'static str = "least_tens";
+
+        fn keep_scalar<'a>(
+            lhs: &'a ScalarValue,
+            rhs: &'a ScalarValue,
+        ) -> Result<&'a ScalarValue> {
+            Ok(if tens(lhs) <= tens(rhs) { lhs } else { rhs })
+        }
+
+        fn get_indexes_to_keep(lhs: &dyn Array, rhs: &dyn Array) -> Result<BooleanArray> {
+            let lhs = lhs.as_primitive::<Int32Type>();
+            let rhs = rhs.as_primitive::<Int32Type>();
+            Ok(lhs
+                .iter()
+                .zip(rhs.iter())
+                .map(|(l, r)| Some(l.map(|v| v / 10) <= r.map(|v| v / 10)))
+                .collect())
+        }
+    }
+
+    fn array(values: Vec<i32>) -> ColumnarValue {
+        ColumnarValue::Array(Arc::new(Int32Array::from(values)))
+    }
+
+    fn scalar(value: i32) -> ColumnarValue {
+        ColumnarValue::Scalar(ScalarValue::Int32(Some(value)))
+    }
+
+    fn execute(args: &[ColumnarValue]) -> Vec<i32> {
+        let result = execute_conditional::<GreatestTens>(args)
+            .unwrap()
+            .into_array(2)
+            .unwrap();
+        result.as_primitive::<Int32Type>().values().to_vec()
+    }
+
+    #[test]
+    fn ties_keep_first_argument() {
+        assert_eq!(
+            execute(&[array(vec![11, 25]), scalar(12), array(vec![19, 21])]),
+            vec![11, 25]
+        );
+        assert_eq!(
+            execute(&[array(vec![11, 20]), array(vec![19, 29])]),
+            vec![11, 20]
+        );
+    }
+
+    #[test]
+    fn ties_keep_first_scalar_argument() {
+        assert_eq!(
+            execute(&[scalar(12), array(vec![11, 30]), scalar(13)]),
+            vec![12, 30]
+        );
+        assert_eq!(execute(&[scalar(13), scalar(15)]), vec![13, 13]);
+    }
+
+    #[test]
+    fn pair_matches_separate_evaluation() {
+        let args = [array(vec![11, 25]), scalar(12), array(vec![19, 21])];
+        let (min, max) =
+            execute_conditional_pair::<LeastTens, GreatestTens>(&args).unwrap();
+        let values = |value: ColumnarValue| {
+            let array = value.into_array(2).unwrap();
+            array.as_primitive::<Int32Type>().values().to_vec()
+        };
+        assert_eq!(values(min), vec![11, 12]);
+        assert_eq!(values(max), execute(&args));
+    }
+
+    /// A dictionary with the given keys, where the `None` keys are null but
+    /// hold `i8::MAX` in the key buffer
+    fn dictionary(keys: Vec<Option<i8>>, values: Vec<i32>) -> ColumnarValue {
+        let nulls = NullBuffer::from_iter(keys.iter().map(Option::is_some));
+        let keys = keys.into_iter().map(|key| key.unwrap_or(i8::MAX)).collect();
+        let keys = Int8Array::new(keys, Some(nulls));
+        let values = Arc::new(Int32Array::from(values));
+        ColumnarValue::Array(Arc::new(
+            DictionaryArray::<Int8Type>::try_new(keys, values).unwrap(),
+        ))
+    }
+
+    fn decoded(value: ColumnarValue) -> Vec<Option<i32>> {
+        let array = value.into_array(2).unwrap();
+        let array = arrow::compute::cast(&array, &DataType::Int32).unwrap();
+        array.as_primitive::<Int32Type>().iter().collect()
+    }
+
+    #[test]
+    fn all_null_dictionary_is_skipped() {
+        let all_null = dictionary(vec![None, None], vec![]);
+        let plain = array(vec![3, 4]);
+
+        let result =
+            execute_conditional::<GreatestFunc>(&[all_null.clone(), plain.clone()])
+                .unwrap();
+        assert_eq!(decoded(result), vec![Some(3), Some(4)]);
+
+        let result = execute_conditional::<GreatestFunc>(&[plain, all_null]).unwrap();
+        assert_eq!(decoded(result), vec![Some(3), Some(4)]);
+    }
+
+    #[test]
+    fn only_all_null_dictionaries() {
+        let all_null = dictionary(vec![None, None], vec![]);
+        let result =
+            execute_conditional::<GreatestFunc>(&[all_null.clone(), all_null.clone()])
+                .unwrap();
+        assert_eq!(decoded(result), vec![None, None]);
+
+        let result = execute_conditional::<GreatestFunc>(&[
+            all_null,
+            ColumnarValue::Scalar(ScalarValue::Dictionary(
+                Box::new(DataType::Int8),
+                Box::new(ScalarValue::Int32(Some(5))),
+            )),
+        ])
+        .unwrap();
+        assert_eq!(decoded(result), vec![Some(5), Some(5)]);
+    }
+
+    #[test]
+    fn partially_null_dictionary_keys() {
+        let lhs = dictionary(vec![Some(0), Some(0)], vec![1]);
+        let rhs = dictionary(vec![Some(0), None], vec![3]);
+        let result = execute_conditional::<GreatestFunc>(&[lhs, rhs]).unwrap();
+        assert_eq!(decoded(result), vec![Some(3), Some(1)]);
+    }
+
+    #[test]
+    fn cast_hint_requires_lossless_casts() {
+        let hint = |arg_types: &[DataType], cast_type: &DataType| {
+            coerce_types_for_cast(arg_types, cast_type)
+        };
+        let decimal = DataType::Decimal128(24, 4);
+
+        assert_eq!(
+            hint(&[DataType::Int32, DataType::Float32], &DataType::Float64),
+            Some(vec![DataType::Float64; 2])
+        );
+        assert_eq!(
+            hint(&[DataType::Int64, DataType::Decimal128(10, 2)], &decimal),
+            Some(vec![decimal.clone(); 2])
+        );
+        // the options literal keeps its type
+        let options = NullPolicy::Last.to_scalar().data_type();
+        assert_eq!(
+            hint(
+                &[DataType::Int8, DataType::UInt8, options.clone()],
+                &DataType::Int16
+            ),
+            Some(vec![DataType::Int16, DataType::Int16, options])
+        );
+
+        // floats round cast to a decimal, and may be out of its range
+        assert_eq!(hint(&[DataType::Int64, DataType::Float64], &decimal), None);
+        // an Int64 may not fit the mantissa of a Float64
+        assert_eq!(
+            hint(&[DataType::Int64, DataType::Float32], &DataType::Float64),
+            None
+        );
+        assert_eq!(hint(&[DataType::Float64], &DataType::Float32), None);
+        assert_eq!(hint(&[DataType::Int64], &DataType::Decimal128(5, 1)), None);
+        assert_eq!(hint(&[DataType::UInt32], &DataType::Int32), None);
+        assert_eq!(hint(&[DataType::Int8], &DataType::UInt64), None);
+    }
+
+    #[test]
+    fn struct_argument_is_not_options_literal() -> Result<()> {
+        // the type of the options literal, but without its marker
+        let fields = Fields::from(vec![Field::new("nulls", DataType::Utf8, false)]);
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["last"]));
+        let array: ArrayRef = Arc::new(StructArray::new(fields, vec![values], None));
+        let arg_types = [array.data_type().clone(), array.data_type().clone()];
+        assert_eq!(value_types(&arg_types), &arg_types);
+
+        let args = vec![
+            ColumnarValue::Array(Arc::clone(&array)),
+            ColumnarValue::Array(array),
+        ];
+        let (values, null_policy) = split_null_policy::<GreatestFunc>(args)?;
+        assert_eq!(values.len(), 2);
+        assert_eq!(null_policy, NullPolicy::Ignore);
+
+        let options = NullPolicy::Last.to_scalar();
+        assert_eq!(
+            value_types(&[DataType::Int32, options.data_type()]),
+            &[DataType::Int32]
+        );
+        let args = vec![
+            ColumnarValue::Scalar(ScalarValue::Int32(Some(1))),
+            ColumnarValue::Scalar(options),
+        ];
+        let (values, null_policy) = split_null_policy::<GreatestFunc>(args)?;
+        assert_eq!(values.len(), 1);
+        assert_eq!(null_policy, NullPolicy::Last);
+        Ok(())
     }
 }
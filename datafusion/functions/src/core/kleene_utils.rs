@@ -0,0 +1,55 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Shared implementation of `any_true` and `all_true`
+
+use arrow::array::BooleanArray;
+use arrow::error::ArrowError;
+use datafusion_common::cast::as_boolean_array;
+use datafusion_common::{exec_err, Result, ScalarValue};
+use datafusion_expr::ColumnarValue;
+use std::sync::Arc;
+
+/// Combines the boolean arguments of the function `name` per row with
+/// `kernel`, `or_kleene` for `any_true` and `and_kleene` for `all_true`, so
+/// NULL is treated as unknown following SQL three-valued logic
+pub(super) fn fold_kleene(
+    name: &str,
+    args: &[ColumnarValue],
+    kernel: fn(&BooleanArray, &BooleanArray) -> Result<BooleanArray, ArrowError>,
+) -> Result<ColumnarValue> {
+    if args.is_empty() {
+        return exec_err!("{name} was called with 0 arguments. It requires at least 1.");
+    }
+    let is_scalar = args
+        .iter()
+        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+    let arrays = ColumnarValue::values_to_arrays(args)?;
+    let mut result = as_boolean_array(&arrays[0])?.clone();
+    for array in &arrays[1..] {
+        result = kernel(&result, as_boolean_array(array)?)?;
+    }
+
+    if is_scalar {
+        Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &result, 0,
+        )?))
+    } else {
+        Ok(ColumnarValue::Array(Arc::new(result)))
+    }
+}
@@ -0,0 +1,263 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{Array, ArrayRef, AsArray, UInt32Array, UnionArray};
+use arrow::buffer::ScalarBuffer;
+use arrow::compute::{cast, take};
+use arrow::datatypes::{DataType, Field, FieldRef, UnionFields, UnionMode};
+use datafusion_common::{exec_err, internal_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Union Functions"),
+    description = "Returns a dense union with a member for every name and value pair. The tag selects, for every row, the name of the member whose value is stored in the union. Rows with a _null_ tag store a _null_ value of the first member.",
+    syntax_example = "make_union_multi(tag, member1_name, member1_value[, ..., member_n_name, member_n_value])",
+    sql_example = r#"```sql
+> select * from t;
++-----+---+-----+-----+
+| tag | a | b   | c   |
++-----+---+-----+-----+
+| a   | 1 | 2.5 | foo |
+| c   | 3 | 4.5 | bar |
++-----+---+-----+-----+
+> select make_union_multi(tag, 'a', a, 'b', b, 'c', c) as u from t;
++---------+
+| u       |
++---------+
+| {a=1}   |
+| {c=bar} |
++---------+
+```"#,
+    argument(
+        name = "tag",
+        description = "String expression with the name of the member to store in each row."
+    ),
+    argument(
+        name = "member_n_name",
+        description = "Name of the union member. Must be a constant string."
+    ),
+    argument(
+        name = "member_n_value",
+        description = "Expression with the values of the union member."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct MakeUnionMultiFunc {
+    signature: Signature,
+}
+
+impl Default for MakeUnionMultiFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MakeUnionMultiFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MakeUnionMultiFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "make_union_multi"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!(
+            "make_union_multi: return_type called instead of return_field_from_args"
+        )
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let Some((tag, members)) = args.arg_fields.split_first() else {
+            return exec_err!("make_union_multi requires a tag and at least one member");
+        };
+        if members.is_empty() || members.len() % 2 != 0 {
+            return exec_err!(
+                "make_union_multi expected a tag followed by name/value pairs, got {} arguments",
+                args.arg_fields.len()
+            );
+        }
+        if !matches!(
+            tag.data_type(),
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View | DataType::Null
+        ) {
+            return exec_err!(
+                "make_union_multi tag must be a string, got {} instead",
+                tag.data_type()
+            );
+        }
+
+        let mut fields: Vec<Field> = Vec::with_capacity(members.len() / 2);
+        for (i, value) in members.iter().skip(1).step_by(2).enumerate() {
+            let name = args.scalar_arguments[1 + 2 * i]
+                .and_then(|sv| sv.try_as_str().flatten().filter(|s| !s.is_empty()));
+            let Some(name) = name else {
+                return exec_err!(
+                    "make_union_multi requires the name of member {i} (0-indexed) as non-empty constant string"
+                );
+            };
+            if fields.iter().any(|field| field.name() == name) {
+                return exec_err!(
+                    "make_union_multi member '{name}' is specified more than once"
+                );
+            }
+            fields.push(Field::new(name, value.data_type().clone(), true));
+        }
+
+        let type_ids = 0..fields.len() as i8;
+        Ok(Field::new(
+            self.name(),
+            DataType::Union(UnionFields::new(type_ids, fields), UnionMode::Dense),
+            true,
+        )
+        .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Union(fields, _) = args.return_field.data_type() else {
+            return internal_err!("incorrect make_union_multi return type");
+        };
+
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let tags = cast(&arrays[0], &DataType::Utf8)?;
+        let tags = tags.as_string::<i32>();
+        let values: Vec<&ArrayRef> = arrays.iter().skip(2).step_by(2).collect();
+
+        // the rows of every member, in order, where the offset of a row into
+        // its member is the number of rows selected for that member before it
+        let mut type_ids = Vec::with_capacity(tags.len());
+        let mut offsets = Vec::with_capacity(tags.len());
+        let mut indices: Vec<Vec<Option<u32>>> = vec![vec![]; values.len()];
+        for (row, tag) in tags.iter().enumerate() {
+            let (type_id, index) = match tag {
+                Some(tag) => {
+                    let Some((type_id, _)) =
+                        fields.iter().find(|(_, field)| field.name() == tag)
+                    else {
+                        return exec_err!(
+                            "make_union_multi tag '{tag}' does not match any member"
+                        );
+                    };
+                    (type_id, Some(row as u32))
+                }
+                None => (0, None),
+            };
+            let member = &mut indices[type_id as usize];
+            type_ids.push(type_id);
+            offsets.push(member.len() as i32);
+            member.push(index);
+        }
+
+        let children = values
+            .into_iter()
+            .zip(indices)
+            .map(|(value, indices)| take(value, &UInt32Array::from(indices), None))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ColumnarValue::Array(Arc::new(UnionArray::try_new(
+            fields.clone(),
+            ScalarBuffer::from(type_ids),
+            Some(ScalarBuffer::from(offsets)),
+            children,
+        )?)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Float64Array, Int32Array, StringArray};
+    use datafusion_common::config::ConfigOptions;
+    use datafusion_common::ScalarValue;
+
+    #[test]
+    fn make_union_multi_selects_tagged_values() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(StringArray::from(vec![
+                Some("b"),
+                Some("a"),
+                None,
+                Some("b"),
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::from("a")),
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![1, 2, 3, 4]))),
+            ColumnarValue::Scalar(ScalarValue::from("b")),
+            ColumnarValue::Array(Arc::new(Float64Array::from(vec![5.0, 6.0, 7.0, 8.0]))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("f", arg.data_type(), true).into())
+            .collect::<Vec<FieldRef>>();
+        let scalar_arguments = args
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Scalar(scalar) => Some(scalar),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        let func = MakeUnionMultiFunc::new();
+        let return_field = func.return_field_from_args(ReturnFieldArgs {
+            arg_fields: &arg_fields,
+            scalar_arguments: &scalar_arguments,
+        })?;
+        let result = func
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 4,
+                return_field,
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(4)?;
+
+        let union = result.as_union();
+        assert_eq!(union.type_ids().to_vec(), vec![1, 0, 0, 1]);
+        assert_eq!(union.offsets().unwrap().to_vec(), vec![0, 0, 1, 1]);
+        assert_eq!(
+            union.child(0).as_ref(),
+            &Int32Array::from(vec![Some(2), None]) as &dyn Array
+        );
+        assert_eq!(
+            union.child(1).as_ref(),
+            &Float64Array::from(vec![5.0, 8.0]) as &dyn Array
+        );
+        Ok(())
+    }
+}
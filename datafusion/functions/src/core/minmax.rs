@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`MinMaxFunc`]: Implementation of the `minmax` function
+
+use crate::core::greatest::GreatestFunc;
+use crate::core::greatest_least_utils::{
+    coerce_types, execute_conditional_pair, find_coerced_type, normalize_timestamps,
+};
+use crate::core::least::LeastFunc;
+use arrow::array::{ArrayRef, StructArray};
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::{internal_err, plan_err, Result, ScalarValue};
+use datafusion_doc::Documentation;
+use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns a struct with the smallest value of a list of expressions as `min` and the greatest as `max`, the same as `least` and `greatest` but computed in a single pass over the arguments. _null_ arguments are ignored, both fields are _null_ if all expressions are _null_.",
+    syntax_example = "minmax(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select minmax(4, 7, 5);
++------------------------------------+
+| minmax(Int64(4),Int64(7),Int64(5)) |
++------------------------------------+
+| {min: 4, max: 7}                   |
++------------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Expressions to compare. Can be a constant, column, or function, and any combination of arithmetic operators. Pass as many expression arguments as necessary."
+    ),
+    related_udf(name = "greatest"),
+    related_udf(name = "least")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct MinMaxFunc {
+    signature: Signature,
+}
+
+impl Default for MinMaxFunc {
+    fn default() -> Self {
+        MinMaxFunc::new()
+    }
+}
+
+impl MinMaxFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the `min` and `max` fields of the struct returned for arguments of
+/// type `value_type`
+fn min_max_fields(value_type: DataType) -> Fields {
+    Fields::from(vec![
+        Field::new("min", value_type.clone(), true),
+        Field::new("max", value_type, true),
+    ])
+}
+
+impl ScalarUDFImpl for MinMaxFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "minmax"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let value_type = find_coerced_type::<GreatestFunc>(arg_types)?;
+        Ok(DataType::Struct(min_max_fields(value_type)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
+            return internal_err!("incorrect minmax return type");
+        };
+        let value_type = fields[0].data_type();
+
+        let arguments = normalize_timestamps::<GreatestFunc>(
+            args.args,
+            value_type,
+            &args.config_options,
+        )?;
+        let (min, max) = execute_conditional_pair::<LeastFunc, GreatestFunc>(&arguments)?;
+
+        match (min, max) {
+            (ColumnarValue::Scalar(min), ColumnarValue::Scalar(max)) => {
+                let columns: Vec<ArrayRef> = vec![min.to_array()?, max.to_array()?];
+                let result = StructArray::try_new(fields.clone(), columns, None)?;
+                Ok(ColumnarValue::Scalar(ScalarValue::Struct(Arc::new(result))))
+            }
+            (min, max) => {
+                let columns = ColumnarValue::values_to_arrays(&[min, max])?;
+                let result = StructArray::try_new(fields.clone(), columns, None)?;
+                Ok(ColumnarValue::Array(Arc::new(result)))
+            }
+        }
+    }
+
+    /// Coerce the arguments to a common type, like `greatest`
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.is_empty() {
+            return plan_err!(
+                "minmax was called without any arguments. It requires at least 1."
+            );
+        }
+        coerce_types::<GreatestFunc>(arg_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{Array, AsArray, Int64Array};
+    use arrow::datatypes::Int64Type;
+    use datafusion_common::config::ConfigOptions;
+
+    #[test]
+    fn test_minmax_skips_nulls() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Int64Array::from(vec![
+                Some(3),
+                None,
+                Some(-5),
+                None,
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
+            ColumnarValue::Array(Arc::new(Int64Array::from(vec![
+                Some(2),
+                Some(7),
+                None,
+                None,
+            ]))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+        let return_type = MinMaxFunc::new().return_type(&[
+            DataType::Int64,
+            DataType::Int64,
+            DataType::Int64,
+        ])?;
+
+        let result = MinMaxFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 4,
+                return_field: Field::new("f", return_type, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(4)?;
+
+        let result = result.as_struct();
+        assert_eq!(result.null_count(), 0);
+        let values = |name: &str| {
+            result
+                .column_by_name(name)
+                .unwrap()
+                .as_primitive::<Int64Type>()
+                .iter()
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values("min"), vec![Some(1), Some(1), Some(-5), Some(1)]);
+        assert_eq!(values("max"), vec![Some(3), Some(7), Some(1), Some(1)]);
+
+        Ok(())
+    }
+}
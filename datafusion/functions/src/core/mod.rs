@@ -20,40 +20,132 @@
 use datafusion_expr::ScalarUDF;
 use std::sync::Arc;
 
+pub mod all_true;
+pub mod any_true;
 pub mod arrow_cast;
 pub mod arrowtypeof;
+pub mod assert_non_null;
+pub mod choose;
+pub mod clamp;
 pub mod coalesce;
+pub mod coalesce_finite;
+pub mod coalesce_flagged;
+pub mod coalesce_longest;
+pub mod coalesce_nonblank;
+pub mod coalesce_scan;
+pub mod coalesce_struct_widen;
+pub mod coalesce_with_source;
+mod coercion_utils;
+pub mod common_type;
+pub mod concat_struct;
+pub mod count_non_null;
+pub mod deep_eq;
 pub mod expr_ext;
+pub mod field_is_nullable;
+pub mod get_field_opt;
 pub mod getfield;
 pub mod greatest;
 mod greatest_least_utils;
+mod kleene_utils;
 pub mod least;
+pub mod make_union_multi;
+pub mod minmax;
 pub mod named_struct;
+pub mod normalize_type_string;
+pub mod nth_non_null;
+pub mod null_mask;
 pub mod nullif;
 pub mod nvl;
 pub mod nvl2;
+mod options_utils;
 pub mod overlay;
 pub mod planner;
 pub mod r#struct;
+pub mod struct_diff;
+pub mod struct_eq_except;
+pub mod struct_hash;
+pub mod struct_keys;
+pub mod struct_set;
+pub mod struct_unwrap;
+pub mod switch;
+pub mod try_get_field;
+pub mod type_byte_width;
+pub mod type_category;
 pub mod union_extract;
+pub mod union_fields;
 pub mod union_tag;
+pub mod union_type_id;
+pub mod union_value_type;
 pub mod version;
+pub mod was_null;
+
+pub use arrow_cast::OverflowMode;
+pub use greatest_least_utils::NullPolicy;
 
 // create UDFs
 make_udf_function!(arrow_cast::ArrowCastFunc, arrow_cast);
+make_udf_function!(arrow_cast::ArrowCastStrictFunc, arrow_cast_strict);
+make_udf_function!(arrow_cast::ArrowCastRangeFunc, arrow_cast_range);
 make_udf_function!(nullif::NullIfFunc, nullif);
 make_udf_function!(nvl::NVLFunc, nvl);
 make_udf_function!(nvl2::NVL2Func, nvl2);
 make_udf_function!(overlay::OverlayFunc, overlay);
+make_udf_function!(overlay::StringInsertFunc, string_insert);
 make_udf_function!(arrowtypeof::ArrowTypeOfFunc, arrow_typeof);
+make_udf_function!(common_type::CommonTypeFunc, common_type);
+make_udf_function!(type_category::TypeCategoryFunc, type_category);
+make_udf_function!(type_byte_width::TypeByteWidthFunc, type_byte_width);
+make_udf_function!(
+    normalize_type_string::NormalizeTypeStringFunc,
+    normalize_type_string
+);
+make_udf_function!(deep_eq::DeepEqFunc, deep_eq);
 make_udf_function!(r#struct::StructFunc, r#struct);
 make_udf_function!(named_struct::NamedStructFunc, named_struct);
 make_udf_function!(getfield::GetFieldFunc, get_field);
+make_udf_function!(get_field_opt::GetFieldOptFunc, get_field_opt);
+make_udf_function!(try_get_field::TryGetFieldFunc, try_get_field);
 make_udf_function!(coalesce::CoalesceFunc, coalesce);
+make_udf_function!(
+    coalesce_with_source::CoalesceWithSourceFunc,
+    coalesce_with_source
+);
+make_udf_function!(coalesce_nonblank::CoalesceNonBlankFunc, coalesce_nonblank);
+make_udf_function!(coalesce_longest::CoalesceLongestFunc, coalesce_longest);
+make_udf_function!(coalesce_scan::CoalesceScanFunc, coalesce_scan);
+make_udf_function!(coalesce_finite::CoalesceFiniteFunc, coalesce_finite);
+make_udf_function!(coalesce_flagged::CoalesceFlaggedFunc, coalesce_flagged);
+make_udf_function!(was_null::WasNullFunc, was_null);
+make_udf_function!(nth_non_null::NthNonNullFunc, nth_non_null);
+make_udf_function!(choose::ChooseFunc, choose);
+make_udf_function!(count_non_null::CountNonNullFunc, count_non_null);
+make_udf_function!(null_mask::NullMaskFunc, null_mask);
+make_udf_function!(any_true::AnyTrueFunc, any_true);
+make_udf_function!(all_true::AllTrueFunc, all_true);
+make_udf_function!(assert_non_null::AssertNonNullFunc, assert_non_null);
 make_udf_function!(greatest::GreatestFunc, greatest);
 make_udf_function!(least::LeastFunc, least);
+make_udf_function!(minmax::MinMaxFunc, minmax);
+make_udf_function!(clamp::ClampFunc, clamp);
+make_udf_function!(struct_keys::StructKeysFunc, struct_keys);
+make_udf_function!(struct_hash::StructHashFunc, struct_hash);
+make_udf_function!(struct_eq_except::StructEqExceptFunc, struct_eq_except);
+make_udf_function!(struct_diff::StructDiffFunc, struct_diff);
+make_udf_function!(field_is_nullable::FieldIsNullableFunc, field_is_nullable);
+make_udf_function!(struct_set::StructSetFunc, struct_set);
+make_udf_function!(struct_unwrap::StructUnwrapFunc, struct_unwrap);
+make_udf_function!(concat_struct::ConcatStructFunc, concat_struct);
+make_udf_function!(
+    coalesce_struct_widen::CoalesceStructWidenFunc,
+    coalesce_struct_widen
+);
+make_udf_function!(switch::SwitchFunc, switch);
 make_udf_function!(union_extract::UnionExtractFun, union_extract);
 make_udf_function!(union_tag::UnionTagFunc, union_tag);
+make_udf_function!(union_type_id::UnionTypeIdFunc, union_type_id);
+make_udf_function!(union_fields::UnionFieldsFunc, union_fields);
+make_udf_function!(union_value_type::UnionValueTypeFunc, union_value_type);
+make_udf_function!(make_union_multi::MakeUnionMultiFunc, make_union_multi);
 make_udf_function!(version::VersionFunc, version);
 
 pub mod expr_fn {
@@ -67,6 +159,14 @@ pub mod expr_fn {
         arrow_cast,
         "Returns value2 if value1 is NULL; otherwise it returns value1",
         arg1 arg2
+    ),(
+        arrow_cast_strict,
+        "Casts value1 to the Arrow type named by value2, returning an error if the cast may lose values or precision",
+        arg1 arg2
+    ),(
+        arrow_cast_range,
+        "Casts value1 to the numeric Arrow type named by value2, returning NULL for the values which cannot be cast or are outside of the range from value3 to value4",
+        arg1 arg2 arg3 arg4
     ),(
         nvl,
         "Returns value2 if value1 is NULL; otherwise it returns value1",
@@ -79,10 +179,34 @@ pub mod expr_fn {
         overlay,
         "replace the substring of string that starts at the start'th character and extends for count characters with new substring",
         args,
+    ),(
+        string_insert,
+        "insert the new substring into string before the pos'th character, without replacing any character",
+        arg1 arg2 arg3
     ),(
         arrow_typeof,
         "Returns the Arrow type of the input expression.",
         arg1
+    ),(
+        common_type,
+        "Returns the name of the Arrow type both input expressions are coerced to.",
+        arg1 arg2
+    ),(
+        type_category,
+        "Returns a coarse category of the Arrow type of the input expression, such as integer or string.",
+        arg1
+    ),(
+        type_byte_width,
+        "Returns the fixed byte width of the Arrow type of the input expression, or NULL if it has none.",
+        arg1
+    ),(
+        normalize_type_string,
+        "Returns the canonical name of the Arrow type string, or an error if it is not a valid type.",
+        arg1
+    ),(
+        deep_eq,
+        "Returns true if value1 and value2 are equal, comparing nested values recursively",
+        arg1 arg2
     ),(
         r#struct,
         "Returns a struct with the given arguments",
@@ -95,6 +219,58 @@ pub mod expr_fn {
         coalesce,
         "Returns `coalesce(args...)`, which evaluates to the value of the first expr which is not NULL",
         args,
+    ),(
+        coalesce_with_source,
+        "Returns `coalesce_with_source(args...)`, which evaluates to a struct with the value of the first expr which is not NULL and its 1-based position",
+        args,
+    ),(
+        coalesce_nonblank,
+        "Returns `coalesce_nonblank(args...)`, which evaluates to the first string expr which is not NULL, empty or only whitespace",
+        args,
+    ),(
+        coalesce_longest,
+        "Returns `coalesce_longest(args...)`, which evaluates to the first of the longest string exprs which are not NULL",
+        args,
+    ),(
+        coalesce_scan,
+        "Returns `coalesce_scan(value, default)`, which fills the NULL values of `value` with the last non-null value before them in the batch, or `default` when there is none",
+        arg1 arg2
+    ),(
+        coalesce_finite,
+        "Returns `coalesce_finite(args...)`, which evaluates to the first float expr which is not NULL, NaN or infinite",
+        args,
+    ),(
+        coalesce_flagged,
+        "Returns `coalesce_flagged(value, default)`, which evaluates to a struct with `nvl(value, default)` and whether `value` is NULL",
+        arg1 arg2
+    ),(
+        was_null,
+        "Returns `was_null(value)`, which evaluates to true if `value` is NULL and false otherwise",
+        arg1
+    ),(
+        nth_non_null,
+        "Returns `nth_non_null(args...)`, which evaluates to the value of the nth expr which is not NULL, where n is the first argument",
+        args,
+    ),(
+        choose,
+        "Returns `choose(args...)`, which evaluates to the value of the expr at the 1-based position given by the first argument, or NULL if it is out of range",
+        args,
+    ),(
+        count_non_null,
+        "Returns `count_non_null(args...)`, which evaluates to the number of exprs which are not NULL",
+        args,
+    ),(
+        null_mask,
+        "Returns `null_mask(args...)`, which evaluates to a bitmask with bit i set if the expr at 0-based position i is NULL",
+        args,
+    ),(
+        any_true,
+        "Returns `any_true(args...)`, which evaluates to true if any of the boolean exprs is true, NULL if none is true but some are NULL, and false otherwise",
+        args,
+    ),(
+        all_true,
+        "Returns `all_true(args...)`, which evaluates to true if all of the boolean exprs are true, false if any is false, and NULL otherwise",
+        args,
     ),(
         greatest,
         "Returns `greatest(args...)`, which evaluates to the greatest value in the list of expressions or NULL if all the expressions are NULL",
@@ -103,10 +279,66 @@ pub mod expr_fn {
         least,
         "Returns `least(args...)`, which evaluates to the smallest value in the list of expressions or NULL if all the expressions are NULL",
         args,
+    ),(
+        minmax,
+        "Returns `minmax(args...)`, which evaluates to a struct with the smallest value in the list of expressions as `min` and the greatest as `max`",
+        args,
+    ),(
+        clamp,
+        "Returns `clamp(value, low, high)`, which evaluates to the value bounded to the range from low to high, or NULL if the value is NULL",
+        arg1 arg2 arg3
+    ),(
+        switch,
+        "Returns the value of the field of the struct `arg2` whose name equals the key `arg1`, or NULL if there is no such field",
+        arg1 arg2
     ),(
         union_tag,
         "Returns the name of the currently selected field in the union",
         arg1
+    ),(
+        union_type_id,
+        "Returns the type id of the currently selected field in the union",
+        arg1
+    ),(
+        union_fields,
+        "Returns the names of all the fields of the union",
+        arg1
+    ),(
+        union_value_type,
+        "Returns the Arrow data type of the currently selected field in the union",
+        arg1
+    ),(
+        make_union_multi,
+        "Returns a dense union of the name/value pairs that stores, for every row, the value of the member named by the tag `args[0]`",
+        args,
+    ),(
+        struct_unwrap,
+        "Returns the value of the only field of the struct",
+        arg1
+    ),(
+        struct_keys,
+        "Returns the names of all the fields of the struct as a list",
+        arg1
+    ),(
+        struct_hash,
+        "Returns a stable Int64 hash of the value, hashing nested values recursively",
+        arg1
+    ),(
+        struct_eq_except,
+        "Returns `struct_eq_except(struct1, struct2, field_names...)`, which evaluates to true if the structs are equal when ignoring the fields with the given names",
+        args,
+    ),(
+        struct_diff,
+        "Returns the names of the fields whose values differ between the two structs",
+        arg1 arg2
+    ),(
+        concat_struct,
+        "Returns a struct with the fields of all the given structs",
+        args,
+    ),(
+        coalesce_struct_widen,
+        "Returns a struct with each field of the first struct, or of the second struct when it is NULL, widened to their common type",
+        arg1 arg2
     ));
 
     #[doc = "Returns the value of the field with the given name from the struct"]
@@ -114,10 +346,35 @@ pub mod expr_fn {
         super::get_field().call(vec![arg1, arg2.lit()])
     }
 
+    #[doc = "Returns the value of the field at the given path of dot separated field names from the struct, or NULL if a field of the path does not exist"]
+    pub fn get_field_opt(arg1: Expr, arg2: impl Literal) -> Expr {
+        super::get_field_opt().call(vec![arg1, arg2.lit()])
+    }
+
+    #[doc = "Returns a struct with the value of the field with the given name from the struct, and whether the struct is not NULL"]
+    pub fn try_get_field(arg1: Expr, arg2: impl Literal) -> Expr {
+        super::try_get_field().call(vec![arg1, arg2.lit()])
+    }
+
     #[doc = "Returns the value of the field with the given name from the union when it's selected, or NULL otherwise"]
     pub fn union_extract(arg1: Expr, arg2: impl Literal) -> Expr {
         super::union_extract().call(vec![arg1, arg2.lit()])
     }
+
+    #[doc = "Returns a copy of the struct with the value of the field with the given name replaced"]
+    pub fn struct_set(arg1: Expr, arg2: impl Literal, arg3: Expr) -> Expr {
+        super::struct_set().call(vec![arg1, arg2.lit(), arg3])
+    }
+
+    #[doc = "Returns whether the field with the given name is declared nullable in the type of the struct"]
+    pub fn field_is_nullable(arg1: Expr, arg2: impl Literal) -> Expr {
+        super::field_is_nullable().call(vec![arg1, arg2.lit()])
+    }
+
+    #[doc = "Returns the value unchanged, failing with the given message if it is NULL"]
+    pub fn assert_non_null(arg1: Expr, arg2: impl Literal) -> Expr {
+        super::assert_non_null().call(vec![arg1, arg2.lit()])
+    }
 }
 
 /// Returns all DataFusion functions defined in this package
@@ -125,10 +382,18 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
     vec![
         nullif(),
         arrow_cast(),
+        arrow_cast_strict(),
+        arrow_cast_range(),
         nvl(),
         nvl2(),
         overlay(),
+        string_insert(),
         arrow_typeof(),
+        common_type(),
+        type_category(),
+        type_byte_width(),
+        normalize_type_string(),
+        deep_eq(),
         named_struct(),
         // Note: most users invoke `get_field` indirectly via field access
         // syntax like `my_struct_col['field_name']`, which results in a call to
@@ -138,11 +403,43 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         // serializing / deserializing plans with the field access desugared to
         // calls to [`get_field`]
         get_field(),
+        get_field_opt(),
+        try_get_field(),
         coalesce(),
+        coalesce_with_source(),
+        coalesce_nonblank(),
+        coalesce_longest(),
+        coalesce_scan(),
+        coalesce_finite(),
+        coalesce_flagged(),
+        was_null(),
+        nth_non_null(),
+        choose(),
+        count_non_null(),
+        null_mask(),
+        any_true(),
+        all_true(),
+        assert_non_null(),
         greatest(),
         least(),
+        minmax(),
+        clamp(),
+        struct_keys(),
+        struct_hash(),
+        struct_eq_except(),
+        struct_diff(),
+        field_is_nullable(),
+        struct_set(),
+        struct_unwrap(),
+        concat_struct(),
+        coalesce_struct_widen(),
+        switch(),
         union_extract(),
         union_tag(),
+        union_type_id(),
+        union_fields(),
+        union_value_type(),
+        make_union_multi(),
         version(),
         r#struct(),
     ]
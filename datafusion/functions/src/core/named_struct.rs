@@ -28,7 +28,7 @@ use std::sync::Arc;
 
 #[user_doc(
     doc_section(label = "Struct Functions"),
-    description = "Returns an Arrow struct using the specified name and input expressions pairs.",
+    description = "Returns an Arrow struct using the specified name and input expressions pairs. The returned struct is never _null_, _null_ inputs become _null_ fields instead, so structs of equal inputs, including _null_ inputs, are equal and can be used as a composite key in `GROUP BY`.",
     syntax_example = "named_struct(expression1_name, expression1_input[, ..., expression_n_name, expression_n_input])",
     sql_example = r#"
 For example, this query converts two columns `a` and `b` to a single column with
@@ -106,7 +106,7 @@ impl ScalarUDFImpl for NamedStructFunc {
 
         if args.scalar_arguments.len() % 2 != 0 {
             return exec_err!(
-                "named_struct requires an even number of arguments, got {} instead",
+                "named_struct expected name/value pairs, got {} arguments",
                 args.scalar_arguments.len()
             );
         }
@@ -128,19 +128,18 @@ impl ScalarUDFImpl for NamedStructFunc {
                 )
             )
             .collect::<Result<Vec<_>>>()?;
-        let types = args
-            .arg_fields
-            .iter()
-            .skip(1)
-            .step_by(2)
-            .map(|f| f.data_type())
-            .collect::<Vec<_>>();
+        let value_fields = args.arg_fields.iter().skip(1).step_by(2);
 
+        // the struct fields keep the metadata of the values, so that it is
+        // found again on the fields returned by `get_field`
         let return_fields = names
             .into_iter()
-            .zip(types.into_iter())
-            .map(|(name, data_type)| Ok(Field::new(name, data_type.to_owned(), true)))
-            .collect::<Result<Vec<Field>>>()?;
+            .zip(value_fields)
+            .map(|(name, field)| {
+                Field::new(name, field.data_type().to_owned(), true)
+                    .with_metadata(field.metadata().to_owned())
+            })
+            .collect::<Vec<Field>>();
 
         Ok(Field::new(
             self.name(),
@@ -151,7 +150,7 @@ impl ScalarUDFImpl for NamedStructFunc {
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
-        let DataType::Struct(fields) = args.return_type() else {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
             return internal_err!("incorrect named_struct return type");
         };
 
@@ -161,20 +160,73 @@ impl ScalarUDFImpl for NamedStructFunc {
             "return type field count != argument count / 2"
         );
 
-        let values: Vec<ColumnarValue> = args
-            .args
-            .chunks_exact(2)
-            .map(|chunk| chunk[1].clone())
-            .collect();
+        // the value arrays become the struct columns as they are, without copying.
+        // The struct has no nulls of its own, so that a null input is always
+        // represented by a null field and equal inputs give equal keys
+        let values: Vec<ColumnarValue> =
+            args.args.into_iter().skip(1).step_by(2).collect();
         let arrays = ColumnarValue::values_to_arrays(&values)?;
-        Ok(ColumnarValue::Array(Arc::new(StructArray::new(
+        Ok(ColumnarValue::Array(Arc::new(StructArray::try_new(
             fields.clone(),
             arrays,
             None,
-        ))))
+        )?)))
     }
 
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, ArrayRef, Int32Array, StringArray};
+    use arrow::buffer::{NullBuffer, ScalarBuffer};
+    use arrow::row::{RowConverter, SortField};
+    use datafusion_common::config::ConfigOptions;
+    use datafusion_common::ScalarValue;
+
+    #[test]
+    fn named_struct_equal_keys_have_equal_rows() -> Result<()> {
+        // rows 0 and 1 are both (NULL, 'x'), with different values behind the null
+        let a: ArrayRef = Arc::new(Int32Array::new(
+            ScalarBuffer::from(vec![5, 7, 1]),
+            Some(NullBuffer::from(vec![false, false, true])),
+        ));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![Some("x"), Some("x"), None]));
+        let args = vec![
+            ColumnarValue::Scalar(ScalarValue::from("a")),
+            ColumnarValue::Array(a),
+            ColumnarValue::Scalar(ScalarValue::from("b")),
+            ColumnarValue::Array(b),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("f", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+
+        let result = NamedStructFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 3,
+                return_field: Field::new("f", DataType::Struct(fields.clone()), true)
+                    .into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(3)?;
+        assert_eq!(result.null_count(), 0);
+
+        let converter =
+            RowConverter::new(vec![SortField::new(DataType::Struct(fields))])?;
+        let rows = converter.convert_columns(&[result])?;
+        assert_eq!(rows.row(0).as_ref(), rows.row(1).as_ref());
+        assert_ne!(rows.row(0).as_ref(), rows.row(2).as_ref());
+        Ok(())
+    }
+}
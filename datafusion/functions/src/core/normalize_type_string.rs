@@ -0,0 +1,256 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`NormalizeTypeStringFunc`]: Implementation of the `normalize_type_string` function
+
+use arrow::array::{AsArray, StringArray};
+use arrow::compute::cast;
+use arrow::datatypes::DataType;
+use datafusion_common::{utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{ColumnarValue, Documentation, Expr, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+use super::arrow_cast::parse_data_type;
+
+/// The names of the type string grammar, which are matched case-insensitively
+/// when the type string can't be parsed as written
+const TYPE_KEYWORDS: &[&str] = &[
+    "Null",
+    "Boolean",
+    "Int8",
+    "Int16",
+    "Int32",
+    "Int64",
+    "UInt8",
+    "UInt16",
+    "UInt32",
+    "UInt64",
+    "Float16",
+    "Float32",
+    "Float64",
+    "Decimal32",
+    "Decimal64",
+    "Decimal128",
+    "Decimal256",
+    "Utf8",
+    "LargeUtf8",
+    "Utf8View",
+    "Binary",
+    "LargeBinary",
+    "BinaryView",
+    "FixedSizeBinary",
+    "Date32",
+    "Date64",
+    "Time32",
+    "Time64",
+    "Timestamp",
+    "Duration",
+    "Interval",
+    "Second",
+    "Millisecond",
+    "Microsecond",
+    "Nanosecond",
+    "YearMonth",
+    "DayTime",
+    "MonthDayNano",
+    "Some",
+    "None",
+    "List",
+    "LargeList",
+    "FixedSizeList",
+    "Dictionary",
+    "Struct",
+    "Map",
+];
+
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = "Returns the canonical name of an [Arrow data type](https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html) string, as accepted by `arrow_cast` and returned by `arrow_typeof`. The whitespace is normalized, and the casing of the type names is corrected when the string can't be parsed as written. Returns an error if the string is not a valid type.",
+    syntax_example = "normalize_type_string(type)",
+    sql_example = r#"```sql
+> select normalize_type_string('decimal128( 10 , 2 )');
++-----------------------------------------------------+
+| normalize_type_string(Utf8("decimal128( 10 , 2 )")) |
++-----------------------------------------------------+
+| Decimal128(10, 2)                                   |
++-----------------------------------------------------+
+```
+"#,
+    argument(
+        name = "type",
+        description = "String expression with the name of an Arrow data type, such as `Int32` or `Dictionary(Int32, Utf8)`."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct NormalizeTypeStringFunc {
+    signature: Signature,
+}
+
+impl Default for NormalizeTypeStringFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NormalizeTypeStringFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::string(1, Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the canonical name of the type string `value`, which is the name
+/// [`DataType`] is displayed with
+fn normalize_type_string(value: &str) -> Result<String> {
+    let data_type = match parse_data_type(value) {
+        Ok(data_type) => data_type,
+        // report the error of the type string as written
+        Err(e) => parse_data_type(&with_canonical_keywords(value)).map_err(|_| e)?,
+    };
+    Ok(data_type.to_string())
+}
+
+/// Returns `value` with the words outside of double quotes that match a type
+/// keyword case-insensitively replaced by the keyword
+fn with_canonical_keywords(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut word = String::new();
+    let mut quoted = false;
+    for c in value.chars() {
+        if !quoted && c.is_ascii_alphanumeric() {
+            word.push(c);
+            continue;
+        }
+        push_word(&mut result, &mut word);
+        if c == '"' {
+            quoted = !quoted;
+        }
+        result.push(c);
+    }
+    push_word(&mut result, &mut word);
+    result
+}
+
+fn push_word(result: &mut String, word: &mut String) {
+    match TYPE_KEYWORDS
+        .iter()
+        .find(|keyword| keyword.eq_ignore_ascii_case(word))
+    {
+        Some(keyword) => result.push_str(keyword),
+        None => result.push_str(word),
+    }
+    word.clear();
+}
+
+impl ScalarUDFImpl for NormalizeTypeStringFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "normalize_type_string"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [arg] = take_function_args(self.name(), args.args)?;
+        match arg {
+            ColumnarValue::Scalar(value) => {
+                let name = value
+                    .try_as_str()
+                    .flatten()
+                    .map(normalize_type_string)
+                    .transpose()?;
+                Ok(ColumnarValue::Scalar(ScalarValue::Utf8(name)))
+            }
+            ColumnarValue::Array(array) => {
+                let array = cast(&array, &DataType::Utf8)?;
+                let names = array
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|value| value.map(normalize_type_string).transpose())
+                    .collect::<Result<StringArray>>()?;
+                Ok(ColumnarValue::Array(Arc::new(names)))
+            }
+        }
+    }
+
+    /// A constant type string is folded into a literal, so that an invalid
+    /// type string is reported while planning
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        _info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [arg] = take_function_args(self.name(), &args)?;
+        let Expr::Literal(value, _) = arg else {
+            return Ok(ExprSimplifyResult::Original(args));
+        };
+        let Some(value) = value.try_as_str() else {
+            return Ok(ExprSimplifyResult::Original(args));
+        };
+
+        let name = value.map(normalize_type_string).transpose()?;
+        Ok(ExprSimplifyResult::Simplified(Expr::Literal(
+            ScalarValue::Utf8(name),
+            None,
+        )))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_type_string() {
+        for (value, expected) in [
+            ("Int32", "Int32"),
+            ("  int32 ", "Int32"),
+            ("decimal128( 10 , 2 )", "Decimal128(10, 2)"),
+            ("dictionary(int32,UTF8)", "Dictionary(Int32, Utf8)"),
+            ("fixedsizebinary(16)", "FixedSizeBinary(16)"),
+        ] {
+            assert_eq!(normalize_type_string(value).unwrap(), expected, "{value}");
+        }
+
+        // the quoted time zone is kept as written
+        assert_eq!(
+            with_canonical_keywords(r#"timestamp(second, some("utc"))"#),
+            r#"Timestamp(Second, Some("utc"))"#
+        );
+
+        assert!(normalize_type_string("Int32(").is_err());
+        assert!(normalize_type_string("unknown").is_err());
+    }
+}
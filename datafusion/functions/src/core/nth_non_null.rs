@@ -0,0 +1,277 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`NthNonNullFunc`]: Implementation of the `nth_non_null` function
+
+use super::coalesce::CoalesceFunc;
+use arrow::array::{new_null_array, Array, ArrayRef};
+use arrow::compute::interleave;
+use arrow::datatypes::DataType;
+use datafusion_common::cast::as_int64_array;
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::iter;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the nth of its arguments that is not _null_, counting from 1. Returns _null_ if fewer than n arguments are not _null_, so `nth_non_null(1, ...)` is the same as `coalesce(...)`. The arguments are coerced like the ones of `coalesce`.",
+    syntax_example = "nth_non_null(n, expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select nth_non_null(2, null, 'first', null, 'second');
++---------------------------------------------------------------+
+| nth_non_null(Int64(2),NULL,Utf8("first"),NULL,Utf8("second")) |
++---------------------------------------------------------------+
+| second                                                        |
++---------------------------------------------------------------+
+```"#,
+    argument(
+        name = "n",
+        description = "The 1-based position among the arguments that are not _null_ of the argument to return."
+    ),
+    argument(
+        name = "expression1, expression_n",
+        description = "Expression to count if it is not _null_. Can be a constant, column, or function, and any combination of arithmetic operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct NthNonNullFunc {
+    signature: Signature,
+}
+
+impl Default for NthNonNullFunc {
+    fn default() -> Self {
+        NthNonNullFunc::new()
+    }
+}
+
+impl NthNonNullFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for NthNonNullFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "nth_non_null"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match arg_types.get(1) {
+            Some(value_type) => Ok(value_type.clone()),
+            None => plan_err!("nth_non_null requires n and at least one argument"),
+        }
+    }
+
+    /// nth_non_null evaluates per row to the nth value which is not NULL
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        if args.args.len() < 2 {
+            return exec_err!(
+                "nth_non_null was called with {} arguments. It requires at least 2.",
+                args.args.len()
+            );
+        }
+        let is_scalar = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let n = as_int64_array(&arrays[0])?;
+        let values = &arrays[1..];
+
+        // index 0 of `sources` is a null value, for rows without an nth value
+        let nulls = new_null_array(args.return_field.data_type(), 1);
+        let sources = iter::once(&nulls)
+            .chain(values)
+            .map(|array| array.as_ref())
+            .collect::<Vec<&dyn Array>>();
+
+        // logical nulls also cover the dictionaries whose values are NULL
+        let value_nulls = values
+            .iter()
+            .map(|array| array.logical_nulls())
+            .collect::<Vec<_>>();
+
+        let indices = (0..n.len())
+            .map(|row| {
+                if n.is_null(row) {
+                    return Ok((0, 0));
+                }
+                let nth = n.value(row);
+                if nth < 1 {
+                    return exec_err!(
+                        "nth_non_null requires n to be at least 1, got {nth}"
+                    );
+                }
+                let source = value_nulls
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, nulls)| nulls.as_ref().is_none_or(|n| n.is_valid(row)))
+                    .nth(nth as usize - 1)
+                    .map_or((0, 0), |(i, _)| (i + 1, row));
+                Ok(source)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let result: ArrayRef = interleave(&sources, &indices)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    /// Coerce `n` to `Int64` and the other arguments to a single common type,
+    /// like `coalesce`
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let Some((n_type, value_types)) = arg_types.split_first() else {
+            return plan_err!("nth_non_null requires n and at least one argument");
+        };
+        if value_types.is_empty() {
+            return plan_err!("nth_non_null requires n and at least one argument");
+        }
+        if !n_type.is_integer() && !n_type.is_null() {
+            return plan_err!("nth_non_null requires an integer n, got {n_type}");
+        }
+
+        let value_types = CoalesceFunc::new().coerce_arguments(value_types)?;
+        Ok(iter::once(DataType::Int64).chain(value_types).collect())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, DictionaryArray, Int32Array, Int64Array, StringArray};
+    use arrow::compute::cast;
+    use arrow::datatypes::{Field, Int32Type};
+    use datafusion_common::config::ConfigOptions;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_nth_non_null_per_row() -> Result<()> {
+        let args = vec![
+            ColumnarValue::Array(Arc::new(Int64Array::from(vec![
+                Some(1),
+                Some(2),
+                Some(2),
+                None,
+                Some(3),
+            ]))),
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+                None,
+                Some(1),
+                None,
+                Some(1),
+                Some(1),
+            ]))),
+            ColumnarValue::Scalar(ScalarValue::Int32(Some(2))),
+            ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+                Some(3),
+                Some(3),
+                None,
+                Some(3),
+                None,
+            ]))),
+        ];
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = NthNonNullFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 5,
+                return_field: Field::new("f", DataType::Int32, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(5)?;
+
+        assert_eq!(
+            result
+                .as_primitive::<Int32Type>()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(2), Some(2), None, None, None]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nth_non_null_dictionary() -> Result<()> {
+        // the keys of the first dictionary are all valid, but its second value is NULL
+        let dictionary = |keys: Vec<i32>, values: Vec<Option<&str>>| {
+            DictionaryArray::<Int32Type>::try_new(
+                Int32Array::from(keys),
+                Arc::new(StringArray::from(values)),
+            )
+        };
+        let args = vec![
+            ColumnarValue::Scalar(ScalarValue::Int64(Some(1))),
+            ColumnarValue::Array(Arc::new(dictionary(
+                vec![0, 1],
+                vec![Some("a"), None],
+            )?)),
+            ColumnarValue::Array(Arc::new(dictionary(vec![0, 0], vec![Some("b")])?)),
+        ];
+        let return_type = args[1].data_type();
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+
+        let result = NthNonNullFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args,
+                arg_fields,
+                number_rows: 2,
+                return_field: Field::new("f", return_type, true).into(),
+                config_options: Arc::new(ConfigOptions::default()),
+            })?
+            .into_array(2)?;
+
+        let result = cast(&result, &DataType::Utf8)?;
+        assert_eq!(
+            result.as_string::<i32>().iter().collect::<Vec<_>>(),
+            vec![Some("a"), Some("b")]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`NullMaskFunc`]: Implementation of the `null_mask` function
+
+use arrow::array::{Array, Int64Array};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+/// The number of bits of the Int64 mask, one per argument
+const MAX_ARGS: usize = 64;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns a bitmask of the arguments that are _null_, where bit i (counting from the least significant bit 0) is set if the argument at 0-based position i is _null_. The arguments can be of different types, and at most 64 of them can be passed.",
+    syntax_example = "null_mask(expression1[, ..., expression_n])",
+    sql_example = r#"```sql
+> select null_mask(1, null, 'datafusion', null);
++--------------------------------------------------+
+| null_mask(Int64(1),NULL,Utf8("datafusion"),NULL) |
++--------------------------------------------------+
+| 10                                               |
++--------------------------------------------------+
+```"#,
+    argument(
+        name = "expression1, expression_n",
+        description = "Expression to check for _null_. Can be a constant, column, or function, and any combination of operators. Pass as many expression arguments as necessary."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct NullMaskFunc {
+    signature: Signature,
+}
+
+impl Default for NullMaskFunc {
+    fn default() -> Self {
+        NullMaskFunc::new()
+    }
+}
+
+impl NullMaskFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::variadic_any(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for NullMaskFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "null_mask"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        if args.arg_fields.len() > MAX_ARGS {
+            return plan_err!(
+                "null_mask supports at most {MAX_ARGS} arguments, got {}",
+                args.arg_fields.len()
+            );
+        }
+        // the mask is 0 rather than NULL when no argument is NULL
+        Ok(Field::new(self.name(), DataType::Int64, false).into())
+    }
+
+    /// null_mask evaluates per row to the bits of the arguments which are NULL
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let null_scalars = args
+            .args
+            .iter()
+            .enumerate()
+            .filter(
+                |(_, arg)| matches!(arg, ColumnarValue::Scalar(value) if value.is_null()),
+            )
+            .fold(0u64, |mask, (i, _)| mask | (1 << i));
+
+        let size = args.args.iter().find_map(|arg| match arg {
+            ColumnarValue::Array(array) => Some(array.len()),
+            _ => None,
+        });
+        let Some(size) = size else {
+            return Ok(ColumnarValue::Scalar(ScalarValue::Int64(Some(
+                null_scalars as i64,
+            ))));
+        };
+
+        let mut masks = vec![null_scalars; size];
+        for (i, arg) in args.args.iter().enumerate() {
+            let ColumnarValue::Array(array) = arg else {
+                continue;
+            };
+            // logical nulls also cover the arrays of type Null and the
+            // dictionaries whose values are NULL
+            if let Some(nulls) = array.logical_nulls() {
+                for (mask, valid) in masks.iter_mut().zip(nulls.iter()) {
+                    if !valid {
+                        *mask |= 1 << i;
+                    }
+                }
+            }
+        }
+
+        // with 64 arguments the last one sets the sign bit
+        let masks = masks
+            .into_iter()
+            .map(|mask| mask as i64)
+            .collect::<Vec<_>>();
+        Ok(ColumnarValue::Array(Arc::new(Int64Array::from(masks))))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use arrow::array::{AsArray, DictionaryArray, Int32Array, NullArray, StringArray};
+    use arrow::datatypes::{Int32Type, Int64Type};
+    use datafusion_common::config::ConfigOptions;
+
+    fn null_mask(args: Vec<ColumnarValue>, number_rows: usize) -> Result<ColumnarValue> {
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+        NullMaskFunc::new().invoke_with_args(ScalarFunctionArgs {
+            args,
+            arg_fields,
+            number_rows,
+            return_field: Field::new("f", DataType::Int64, false).into(),
+            config_options: Arc::new(ConfigOptions::default()),
+        })
+    }
+
+    fn masks(result: ColumnarValue) -> Vec<i64> {
+        let ColumnarValue::Array(array) = result else {
+            panic!("expected an array, got {result:?}");
+        };
+        assert_eq!(array.null_count(), 0);
+        array.as_primitive::<Int64Type>().values().to_vec()
+    }
+
+    #[test]
+    fn test_null_mask_logical_nulls() -> Result<()> {
+        // the keys of the dictionary are all valid, but its second value is NULL
+        let dictionary = DictionaryArray::<Int32Type>::try_new(
+            Int32Array::from(vec![0, 1, 1]),
+            Arc::new(StringArray::from(vec![Some("a"), None])),
+        )?;
+        let result = null_mask(
+            vec![
+                ColumnarValue::Array(Arc::new(dictionary)),
+                ColumnarValue::Array(Arc::new(NullArray::new(3))),
+                ColumnarValue::Scalar(ScalarValue::Int32(None)),
+            ],
+            3,
+        )?;
+
+        assert_eq!(masks(result), vec![0b110, 0b111, 0b111]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_mask_sets_sign_bit() -> Result<()> {
+        let mut args = vec![ColumnarValue::Scalar(ScalarValue::Int32(Some(1))); 63];
+        args.push(ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+            Some(1),
+            None,
+        ]))));
+
+        assert_eq!(masks(null_mask(args, 2)?), vec![0, i64::MIN]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_mask_of_scalars() -> Result<()> {
+        let result = null_mask(
+            vec![
+                ColumnarValue::Scalar(ScalarValue::Null),
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some("a".to_string()))),
+                ColumnarValue::Scalar(ScalarValue::Int32(None)),
+            ],
+            1,
+        )?;
+
+        let ColumnarValue::Scalar(mask) = result else {
+            panic!("expected a scalar, got {result:?}");
+        };
+        assert_eq!(mask, ScalarValue::Int64(Some(0b101)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_null_mask_max_args() -> Result<()> {
+        let return_field = |number_args: usize| {
+            let arg_fields =
+                vec![Field::new("a", DataType::Int32, true).into(); number_args];
+            let scalar_arguments = vec![None; number_args];
+            NullMaskFunc::new().return_field_from_args(ReturnFieldArgs {
+                arg_fields: &arg_fields,
+                scalar_arguments: &scalar_arguments,
+            })
+        };
+
+        assert!(!return_field(MAX_ARGS)?.is_nullable());
+        let err = return_field(MAX_ARGS + 1).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: null_mask supports at most 64 arguments, got 65"
+        );
+
+        Ok(())
+    }
+}
@@ -15,15 +15,26 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use arrow::array::{make_comparator, Array, ArrayRef, AsArray, BooleanArray, Datum};
+use arrow::compute::SortOptions;
 use arrow::datatypes::DataType;
+use datafusion_expr::type_coercion::binary::{
+    comparison_coercion, comparison_coercion_numeric,
+};
+use datafusion_expr::type_coercion::functions::data_types;
 use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
 
+use super::coalesce::is_binary_type;
+use super::coercion_utils::coerce_with_signature;
+use super::deep_eq::deep_eq;
+
 use arrow::compute::kernels::cmp::eq;
 use arrow::compute::kernels::nullif::nullif;
-use datafusion_common::{utils::take_function_args, Result, ScalarValue};
+use datafusion_common::{plan_err, utils::take_function_args, Result, ScalarValue};
 use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
 use datafusion_macros::user_doc;
 use std::any::Any;
+use std::sync::Arc;
 
 #[user_doc(
     doc_section(label = "Conditional Functions"),
@@ -80,7 +91,9 @@ impl NullIfFunc {
             // In Postgres, nullif('2', 2) returns Null but nullif('2::varchar', 2) returns error.
             // While in DuckDB both query returns Null. We follow DuckDB in this case since I think they are equivalent thing and should
             // have the same result as well.
-            signature: Signature::comparable(2, Volatility::Immutable),
+            //
+            // A dictionary encoded first argument keeps its encoding, see `coerce_types`.
+            signature: Signature::user_defined(Volatility::Immutable),
         }
     }
 }
@@ -105,6 +118,43 @@ impl ScalarUDFImpl for NullIfFunc {
         nullif_func(&args.args)
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_with_signature(self.name(), "T, T", arg_types, || {
+            let [lhs, rhs] = take_function_args(self.name(), arg_types)?;
+
+            // binary types, including `BinaryView`, are compared without conversion
+            if is_binary_type(lhs) || is_binary_type(rhs) {
+                if let Some(coerced_type) = comparison_coercion(lhs, rhs) {
+                    return Ok(vec![coerced_type.clone(), coerced_type]);
+                }
+            }
+
+            let DataType::Dictionary(key_type, value_type) = lhs else {
+                return data_types(
+                    self.name(),
+                    arg_types,
+                    &Signature::comparable(2, Volatility::Immutable),
+                );
+            };
+
+            // compare against the dictionary values, so the result stays encoded
+            let rhs = match rhs {
+                DataType::Dictionary(_, rhs_value_type) => rhs_value_type.as_ref(),
+                rhs => rhs,
+            };
+            let Some(value_type) = comparison_coercion_numeric(value_type, rhs) else {
+                return plan_err!(
+                    "For function '{}' {lhs} and {rhs} is not comparable",
+                    self.name()
+                );
+            };
+            Ok(vec![
+                DataType::Dictionary(key_type.clone(), Box::new(value_type.clone())),
+                value_type,
+            ])
+        })
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
@@ -117,14 +167,41 @@ impl ScalarUDFImpl for NullIfFunc {
 fn nullif_func(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     let [lhs, rhs] = take_function_args("nullif", args)?;
 
+    // the comparison kernels do not support nested types, see `deep_eq`
+    if lhs.data_type().is_nested() {
+        let arrays = ColumnarValue::values_to_arrays(args)?;
+        let array = nullif(&arrays[0], &deep_eq(&arrays[0], &arrays[1])?)?;
+        return match (lhs, rhs) {
+            (ColumnarValue::Scalar(_), ColumnarValue::Scalar(_)) => Ok(
+                ColumnarValue::Scalar(ScalarValue::try_from_array(&array, 0)?),
+            ),
+            _ => Ok(ColumnarValue::Array(array)),
+        };
+    }
+
     match (lhs, rhs) {
+        (ColumnarValue::Array(lhs), ColumnarValue::Scalar(rhs))
+            if matches!(lhs.data_type(), DataType::Dictionary(_, _)) =>
+        {
+            let array = nullif_dictionary(lhs, &rhs.to_scalar()?)?;
+            Ok(ColumnarValue::Array(array))
+        }
         (ColumnarValue::Array(lhs), ColumnarValue::Scalar(rhs)) => {
             let rhs = rhs.to_scalar()?;
             let array = nullif(lhs, &eq(&lhs, &rhs)?)?;
 
             Ok(ColumnarValue::Array(array))
         }
+        (ColumnarValue::Array(lhs), ColumnarValue::Array(rhs))
+            if matches!(lhs.data_type(), DataType::Dictionary(_, _))
+                && !matches!(rhs.data_type(), DataType::Dictionary(_, _)) =>
+        {
+            let array = nullif_dictionary_rows(lhs, rhs)?;
+            Ok(ColumnarValue::Array(array))
+        }
         (ColumnarValue::Array(lhs), ColumnarValue::Array(rhs)) => {
+            // `eq` compares all the rows at once, including the strings, and
+            // `nullif` only rebuilds the validity of `lhs` from the mask
             let array = nullif(lhs, &eq(&lhs, &rhs)?)?;
             Ok(ColumnarValue::Array(array))
         }
@@ -139,7 +216,11 @@ fn nullif_func(args: &[ColumnarValue]) -> Result<ColumnarValue> {
             Ok(ColumnarValue::Array(array))
         }
         (ColumnarValue::Scalar(lhs), ColumnarValue::Scalar(rhs)) => {
-            let val: ScalarValue = match lhs.eq(rhs) {
+            let lhs_value = match lhs {
+                ScalarValue::Dictionary(_, value) => value.as_ref(),
+                lhs => lhs,
+            };
+            let val: ScalarValue = match lhs_value.eq(rhs) {
                 true => lhs.data_type().try_into()?,
                 false => lhs.clone(),
             };
@@ -149,11 +230,56 @@ fn nullif_func(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     }
 }
 
+/// Nullifies the rows of a dictionary array whose value equals `rhs`.
+///
+/// The dictionary values are compared once, and rows are nullified through
+/// their keys, so the result keeps the dictionary encoding of `lhs`.
+fn nullif_dictionary(lhs: &ArrayRef, rhs: &dyn Datum) -> Result<ArrayRef> {
+    let dictionary = lhs.as_any_dictionary();
+    // without values every key is null, such as in an all-null dictionary
+    if dictionary.values().is_empty() {
+        return Ok(Arc::clone(lhs));
+    }
+    let matches = eq(dictionary.values(), rhs)?;
+    // null keys are arbitrary but in range, and their rows stay null anyway
+    let mask = dictionary
+        .normalized_keys()
+        .into_iter()
+        .map(|key| Some(matches.is_valid(key) && matches.value(key)))
+        .collect::<BooleanArray>();
+    Ok(nullif(lhs, &mask)?)
+}
+
+/// Nullifies the rows of a dictionary array whose value equals the value of
+/// the plain array `rhs` at the same row.
+///
+/// Each row compares the dictionary value its key points to with `rhs`, so
+/// the dictionary is never decoded and the result keeps the encoding of `lhs`.
+fn nullif_dictionary_rows(lhs: &ArrayRef, rhs: &ArrayRef) -> Result<ArrayRef> {
+    let dictionary = lhs.as_any_dictionary();
+    let values = dictionary.values();
+    // without values every key is null, such as in an all-null dictionary
+    if values.is_empty() {
+        return Ok(Arc::clone(lhs));
+    }
+    let compare = make_comparator(values.as_ref(), rhs.as_ref(), SortOptions::default())?;
+    let mask = dictionary
+        .normalized_keys()
+        .into_iter()
+        .enumerate()
+        .map(|(row, key)| {
+            Some(values.is_valid(key) && rhs.is_valid(row) && compare(key, row).is_eq())
+        })
+        .collect::<BooleanArray>();
+    Ok(nullif(lhs, &mask)?)
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
     use arrow::array::*;
+    use arrow::datatypes::Int32Type;
 
     use super::*;
 
@@ -303,4 +429,139 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn nullif_dictionary() -> Result<()> {
+        let a: DictionaryArray<Int32Type> =
+            vec![Some("a"), Some("b"), None, Some("a"), Some("c")]
+                .into_iter()
+                .collect();
+        let a = ColumnarValue::Array(Arc::new(a));
+
+        let lit_array = ColumnarValue::Scalar(ScalarValue::from("a"));
+
+        let result = nullif_func(&[a, lit_array])?;
+        let result = result.into_array(0).expect("Failed to convert to array");
+
+        assert_eq!(
+            result.data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+        // the dictionary values are untouched, only the keys gain nulls
+        let result = result.as_dictionary::<Int32Type>();
+        let expected_keys = Int32Array::from(vec![None, Some(1), None, None, Some(2)]);
+        assert_eq!(result.keys(), &expected_keys);
+        let expected_values =
+            Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef;
+        assert_eq!(expected_values.as_ref(), result.values().as_ref());
+        Ok(())
+    }
+
+    #[test]
+    fn nullif_all_null_dictionary() -> Result<()> {
+        // an all-null dictionary has no values for its keys to point to
+        let a = DictionaryArray::<Int32Type>::try_new(
+            Int32Array::new_null(3),
+            new_empty_array(&DataType::Utf8),
+        )?;
+        let a: ArrayRef = Arc::new(a);
+
+        let lit_array = ColumnarValue::Scalar(ScalarValue::from("a"));
+
+        let result = nullif_func(&[ColumnarValue::Array(Arc::clone(&a)), lit_array])?;
+        let result = result.into_array(0).expect("Failed to convert to array");
+        assert_eq!(a.as_ref(), result.as_ref());
+        Ok(())
+    }
+
+    #[test]
+    fn nullif_dictionary_and_plain_array() -> Result<()> {
+        let a: DictionaryArray<Int32Type> =
+            vec![Some("a"), Some("b"), None, Some("a"), Some("c"), Some("b")]
+                .into_iter()
+                .collect();
+        let a = ColumnarValue::Array(Arc::new(a));
+        let b = ColumnarValue::Array(Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("a"),
+            Some("a"),
+            None,
+            Some("c"),
+            Some("x"),
+        ])));
+
+        let result = nullif_func(&[a, b])?;
+        let result = result.into_array(0).expect("Failed to convert to array");
+
+        assert_eq!(
+            result.data_type(),
+            &DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        );
+        // the dictionary values are untouched, only the keys gain nulls
+        let result = result.as_dictionary::<Int32Type>();
+        let expected_keys =
+            Int32Array::from(vec![None, Some(1), None, Some(0), None, Some(1)]);
+        assert_eq!(result.keys(), &expected_keys);
+        let expected_values =
+            Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef;
+        assert_eq!(expected_values.as_ref(), result.values().as_ref());
+        Ok(())
+    }
+
+    #[test]
+    fn nullif_all_null_dictionary_and_plain_array() -> Result<()> {
+        let a = DictionaryArray::<Int32Type>::try_new(
+            Int32Array::new_null(3),
+            new_empty_array(&DataType::Utf8),
+        )?;
+        let a: ArrayRef = Arc::new(a);
+        let b = ColumnarValue::Array(Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+        ])));
+
+        let result = nullif_func(&[ColumnarValue::Array(Arc::clone(&a)), b])?;
+        let result = result.into_array(0).expect("Failed to convert to array");
+        assert_eq!(a.as_ref(), result.as_ref());
+        Ok(())
+    }
+
+    #[test]
+    fn nullif_utf8_arrays() -> Result<()> {
+        let a = ColumnarValue::Array(Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("d"),
+            Some("e"),
+            None,
+            Some("g"),
+        ])));
+        let b = ColumnarValue::Array(Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("x"),
+            Some("c"),
+            None,
+            Some("e"),
+            None,
+            Some("gg"),
+        ])));
+
+        let result = nullif_func(&[a, b])?;
+        let result = result.into_array(0).expect("Failed to convert to array");
+
+        // equal rows are NULL, and a NULL is never equal to another value
+        let expected = Arc::new(StringArray::from(vec![
+            None,
+            Some("b"),
+            None,
+            Some("d"),
+            None,
+            None,
+            Some("g"),
+        ])) as ArrayRef;
+        assert_eq!(expected.as_ref(), result.as_ref());
+        Ok(())
+    }
 }
@@ -19,18 +19,22 @@ use arrow::array::Array;
 use arrow::compute::is_not_null;
 use arrow::compute::kernels::zip::zip;
 use arrow::datatypes::DataType;
-use datafusion_common::{utils::take_function_args, Result};
+use datafusion_common::{plan_err, utils::take_function_args, Result};
 use datafusion_expr::{
-    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
-    Volatility,
+    type_coercion::functions::data_types, ColumnarValue, Documentation,
+    ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
 };
 use datafusion_macros::user_doc;
 use std::sync::Arc;
 
+use super::coalesce::{CoalesceCoercion, CoalesceFunc};
+use super::coercion_utils::coerce_with_signature;
+use super::nvl2::NVL2Func;
+
 #[user_doc(
     doc_section(label = "Conditional Functions"),
-    description = "Returns _expression2_ if _expression1_ is NULL otherwise it returns _expression1_.",
-    syntax_example = "nvl(expression1, expression2)",
+    description = "Returns _expression2_ if _expression1_ is NULL otherwise it returns _expression1_. When _expression3_ is given, behaves like [nvl2](#nvl2): returns _expression3_ if _expression1_ is NULL otherwise it returns _expression2_.",
+    syntax_example = "nvl(expression1, expression2[, expression3])",
     sql_example = r#"```sql
 > select nvl(null, 'a');
 +---------------------+
@@ -44,27 +48,40 @@ use std::sync::Arc;
 +--------------------------+
 | b                        |
 +--------------------------+
+> select nvl('b', 'a', 'c');
++------------------------------------+
+| nvl(Utf8("b"),Utf8("a"),Utf8("c")) |
++------------------------------------+
+| a                                  |
++------------------------------------+
 ```
 "#,
     argument(
         name = "expression1",
-        description = "Expression to return if not null. Can be a constant, column, or function, and any combination of operators."
+        description = "Expression to test for null, returned if not null when expression3 is not given. Can be a constant, column, or function, and any combination of operators."
     ),
     argument(
         name = "expression2",
-        description = "Expression to return if expr1 is null. Can be a constant, column, or function, and any combination of operators."
+        description = "Expression to return if expr1 is null, or if expr1 is not null when expression3 is given. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "expression3",
+        description = "Optional expression to return if expr1 is null. Can be a constant, column, or function, and any combination of operators."
     )
 )]
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub struct NVLFunc {
     signature: Signature,
     aliases: Vec<String>,
+    /// The three argument form is evaluated as nvl2
+    nvl2: NVL2Func,
+    coercion: CoalesceCoercion,
 }
 
 /// Currently supported types by the nvl/ifnull function.
 /// The order of these types correspond to the order on which coercion applies
 /// This should thus be from least informative to most informative
-static SUPPORTED_NVL_TYPES: &[DataType] = &[
+pub(super) static SUPPORTED_NVL_TYPES: &[DataType] = &[
     DataType::Boolean,
     DataType::UInt8,
     DataType::UInt16,
@@ -89,13 +106,18 @@ impl Default for NVLFunc {
 
 impl NVLFunc {
     pub fn new() -> Self {
+        Self::new_with_coercion(CoalesceCoercion::default())
+    }
+
+    /// Create a new `nvl` function coercing the arguments of its two argument
+    /// form as `coercion`. With [`CoalesceCoercion::Spark`] they are coerced
+    /// like the arguments of `coalesce`
+    pub fn new_with_coercion(coercion: CoalesceCoercion) -> Self {
         Self {
-            signature: Signature::uniform(
-                2,
-                SUPPORTED_NVL_TYPES.to_vec(),
-                Volatility::Immutable,
-            ),
-            aliases: vec![String::from("ifnull")],
+            signature: Signature::user_defined(Volatility::Immutable),
+            aliases: vec![String::from("ifnull"), String::from("default_if_null")],
+            nvl2: NVL2Func::new(),
+            coercion,
         }
     }
 }
@@ -114,13 +136,42 @@ impl ScalarUDFImpl for NVLFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        if arg_types.len() == 3 {
+            return self.nvl2.return_type(arg_types);
+        }
         Ok(arg_types[0].clone())
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        if args.args.len() == 3 {
+            return self.nvl2.invoke_with_args(args);
+        }
         nvl_func(&args.args)
     }
 
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_with_signature(self.name(), "T, T[, T]", arg_types, || {
+            match (arg_types.len(), self.coercion) {
+                // Spark evaluates nvl as coalesce of the two arguments
+                (2, CoalesceCoercion::Spark) => {
+                    CoalesceFunc::new_with_coercion(self.coercion)
+                        .coerce_arguments(arg_types)
+                }
+                (2, CoalesceCoercion::Default) => data_types(
+                    self.name(),
+                    arg_types,
+                    &Signature::uniform(
+                        2,
+                        SUPPORTED_NVL_TYPES.to_vec(),
+                        Volatility::Immutable,
+                    ),
+                ),
+                (3, _) => self.nvl2.coerce_arguments(arg_types),
+                (n, _) => plan_err!("nvl expects 2 or 3 arguments, got {n}"),
+            }
+        })
+    }
+
     fn aliases(&self) -> &[String] {
         &self.aliases
     }
@@ -130,8 +181,14 @@ impl ScalarUDFImpl for NVLFunc {
     }
 }
 
-fn nvl_func(args: &[ColumnarValue]) -> Result<ColumnarValue> {
+pub(super) fn nvl_func(args: &[ColumnarValue]) -> Result<ColumnarValue> {
     let [lhs, rhs] = take_function_args("nvl/ifnull", args)?;
+    // Nothing to replace, so return the first argument without merging
+    if let ColumnarValue::Array(lhs) = lhs {
+        if lhs.logical_null_count() == 0 {
+            return Ok(ColumnarValue::Array(Arc::clone(lhs)));
+        }
+    }
     let (lhs_array, rhs_array) = match (lhs, rhs) {
         (ColumnarValue::Array(lhs), ColumnarValue::Scalar(rhs)) => {
             (Arc::clone(lhs), rhs.to_array_of_size(lhs.len())?)
@@ -225,6 +282,27 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn nvl_non_null_first_argument_is_returned_unchanged() -> Result<()> {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b = ColumnarValue::Array(Arc::new(Int32Array::from(vec![
+            Some(4),
+            None,
+            Some(6),
+        ])));
+
+        let result = nvl_func(&[ColumnarValue::Array(Arc::clone(&a)), b])?;
+        let ColumnarValue::Array(result) = result else {
+            panic!("Expected an array result");
+        };
+        assert!(Arc::ptr_eq(&a, &result));
+        assert_eq!(
+            a.to_data().buffers()[0].as_ptr(),
+            result.to_data().buffers()[0].as_ptr()
+        );
+        Ok(())
+    }
+
     #[test]
     fn nvl_boolean() -> Result<()> {
         let a = BooleanArray::from(vec![Some(true), Some(false), None]);
@@ -315,4 +393,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn nvl_coerce_types() -> Result<()> {
+        let nvl = NVLFunc::new();
+
+        let coerced = nvl.coerce_types(&[DataType::Int32, DataType::Int64])?;
+        assert_eq!(coerced, vec![DataType::Int64, DataType::Int64]);
+        assert_eq!(nvl.return_type(&coerced)?, DataType::Int64);
+
+        let coerced =
+            nvl.coerce_types(&[DataType::Int32, DataType::Utf8, DataType::Int64])?;
+        assert_eq!(coerced, vec![DataType::Utf8; 3]);
+        assert_eq!(nvl.return_type(&coerced)?, DataType::Utf8);
+
+        assert!(nvl.coerce_types(&[DataType::Int32]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn nvl_spark_coercion() -> Result<()> {
+        let default = NVLFunc::new();
+        let spark = NVLFunc::new_with_coercion(CoalesceCoercion::Spark);
+
+        let arg_types = [DataType::Date32, DataType::Utf8View];
+        assert_eq!(default.coerce_types(&arg_types)?, vec![DataType::Utf8; 2]);
+        assert_eq!(spark.coerce_types(&arg_types)?, vec![DataType::Utf8View; 2]);
+
+        let arg_types = [DataType::Int32, DataType::Decimal128(10, 2)];
+        assert_eq!(
+            spark.coerce_types(&arg_types)?,
+            vec![DataType::Decimal128(12, 2); 2]
+        );
+        Ok(())
+    }
 }
@@ -19,14 +19,17 @@ use arrow::array::Array;
 use arrow::compute::is_not_null;
 use arrow::compute::kernels::zip::zip;
 use arrow::datatypes::DataType;
-use datafusion_common::{internal_err, utils::take_function_args, Result};
+use datafusion_common::{internal_err, plan_err, utils::take_function_args, Result};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
 use datafusion_expr::{
-    type_coercion::binary::comparison_coercion, ColumnarValue, Documentation,
+    type_coercion::binary::comparison_coercion, ColumnarValue, Documentation, Expr,
     ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
 };
 use datafusion_macros::user_doc;
 use std::sync::Arc;
 
+use super::coercion_utils::coerce_with_signature;
+
 #[user_doc(
     doc_section(label = "Conditional Functions"),
     description = "Returns _expression2_ if _expression1_ is not NULL; otherwise it returns _expression3_.",
@@ -76,6 +79,31 @@ impl NVL2Func {
             signature: Signature::user_defined(Volatility::Immutable),
         }
     }
+
+    /// Returns the types the arguments are coerced to, see
+    /// [`ScalarUDFImpl::coerce_types`]
+    pub(super) fn coerce_arguments(
+        &self,
+        arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        let [tested, if_non_null, if_null] = take_function_args(self.name(), arg_types)?;
+        let new_type =
+            [if_non_null, if_null]
+                .iter()
+                .try_fold(tested.clone(), |acc, x| {
+                    // The coerced types found by `comparison_coercion` are not guaranteed to be
+                    // coercible for the arguments. `comparison_coercion` returns more loose
+                    // types that can be coerced to both `acc` and `x` for comparison purpose.
+                    // See `maybe_data_types` for the actual coercion.
+                    let coerced_type = comparison_coercion(&acc, x);
+                    if let Some(coerced_type) = coerced_type {
+                        Ok(coerced_type)
+                    } else {
+                        plan_err!("Coercion from {acc} to {x} failed.")
+                    }
+                })?;
+        Ok(vec![new_type; arg_types.len()])
+    }
 }
 
 impl ScalarUDFImpl for NVL2Func {
@@ -99,24 +127,29 @@ impl ScalarUDFImpl for NVL2Func {
         nvl2_func(&args.args)
     }
 
+    /// A literal tested value selects one of the other arguments at plan time
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        _info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let is_null = match args.first() {
+            Some(Expr::Literal(tested, _)) => tested.is_null(),
+            _ => return Ok(ExprSimplifyResult::Original(args)),
+        };
+        let [_, if_non_null, if_null] = take_function_args(self.name(), args)?;
+        // the arguments are already coerced to the same type
+        Ok(ExprSimplifyResult::Simplified(if is_null {
+            if_null
+        } else {
+            if_non_null
+        }))
+    }
+
     fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
-        let [tested, if_non_null, if_null] = take_function_args(self.name(), arg_types)?;
-        let new_type =
-            [if_non_null, if_null]
-                .iter()
-                .try_fold(tested.clone(), |acc, x| {
-                    // The coerced types found by `comparison_coercion` are not guaranteed to be
-                    // coercible for the arguments. `comparison_coercion` returns more loose
-                    // types that can be coerced to both `acc` and `x` for comparison purpose.
-                    // See `maybe_data_types` for the actual coercion.
-                    let coerced_type = comparison_coercion(&acc, x);
-                    if let Some(coerced_type) = coerced_type {
-                        Ok(coerced_type)
-                    } else {
-                        internal_err!("Coercion from {acc:?} to {x:?} failed.")
-                    }
-                })?;
-        Ok(vec![new_type; arg_types.len()])
+        coerce_with_signature(self.name(), "T, T, T", arg_types, || {
+            self.coerce_arguments(arg_types)
+        })
     }
 
     fn documentation(&self) -> Option<&Documentation> {
@@ -0,0 +1,68 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Options literals, which pass the named arguments of functions such as
+//! `least(a, b, nulls => 'first')` as a regular argument
+
+use arrow::array::{Array, ArrayRef, StringArray, StructArray};
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::cast::as_string_array;
+use datafusion_common::{plan_err, Result, ScalarValue};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The metadata key of the field of an options literal, whose value is the
+/// name of the named argument. It tells the options literals built by the
+/// planner apart from struct arguments of the same type.
+const OPTIONS_METADATA_KEY: &str = "datafusion.options";
+
+/// Returns the options literal `{<name>: '<value>'}` for the named argument
+/// `<name> => '<value>'`
+pub(super) fn options_literal(name: &str, value: &str) -> ScalarValue {
+    let metadata = HashMap::from([(OPTIONS_METADATA_KEY.to_string(), name.to_string())]);
+    let field = Arc::new(Field::new(name, DataType::Utf8, false).with_metadata(metadata));
+    let value: ArrayRef = Arc::new(StringArray::from(vec![value]));
+    ScalarValue::Struct(Arc::new(StructArray::from(vec![(field, value)])))
+}
+
+/// Returns true for the type of the options literals of the named argument
+/// `name`, which is only the type of [`options_literal`]
+pub(super) fn is_options_type(data_type: &DataType, name: &str) -> bool {
+    matches!(
+        data_type,
+        DataType::Struct(fields) if fields.len() == 1
+            && fields[0].name() == name
+            && fields[0].data_type() == &DataType::Utf8
+            && fields[0]
+                .metadata()
+                .get(OPTIONS_METADATA_KEY)
+                .is_some_and(|option| option == name)
+    )
+}
+
+/// Returns the value of the named argument `name` held by the options literal
+/// `options`
+pub(super) fn option_value<'a>(options: &'a ScalarValue, name: &str) -> Result<&'a str> {
+    match options {
+        ScalarValue::Struct(options)
+            if is_options_type(options.data_type(), name) && options.is_valid(0) =>
+        {
+            Ok(as_string_array(options.column(0))?.value(0))
+        }
+        _ => plan_err!("Invalid {name} option {options}"),
+    }
+}
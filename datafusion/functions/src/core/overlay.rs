@@ -18,15 +18,18 @@
 use std::any::Any;
 use std::sync::Arc;
 
-use arrow::array::{ArrayRef, GenericStringArray, OffsetSizeTrait};
+use arrow::array::{
+    ArrayRef, AsArray, GenericStringArray, Int64Array, OffsetSizeTrait, StringArrayType,
+    StringViewArray,
+};
 use arrow::datatypes::DataType;
 
-use crate::utils::{make_scalar_function, utf8_to_str_type};
+use crate::utils::make_scalar_function;
 use datafusion_common::cast::{
     as_generic_string_array, as_int64_array, as_string_view_array,
 };
-use datafusion_common::{exec_err, Result};
-use datafusion_expr::{ColumnarValue, Documentation, TypeSignature, Volatility};
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::{ColumnarValue, Documentation, Volatility};
 use datafusion_expr::{ScalarFunctionArgs, ScalarUDFImpl, Signature};
 use datafusion_macros::user_doc;
 
@@ -66,19 +69,8 @@ impl Default for OverlayFunc {
 
 impl OverlayFunc {
     pub fn new() -> Self {
-        use DataType::*;
         Self {
-            signature: Signature::one_of(
-                vec![
-                    TypeSignature::Exact(vec![Utf8View, Utf8View, Int64, Int64]),
-                    TypeSignature::Exact(vec![Utf8, Utf8, Int64, Int64]),
-                    TypeSignature::Exact(vec![LargeUtf8, LargeUtf8, Int64, Int64]),
-                    TypeSignature::Exact(vec![Utf8View, Utf8View, Int64]),
-                    TypeSignature::Exact(vec![Utf8, Utf8, Int64]),
-                    TypeSignature::Exact(vec![LargeUtf8, LargeUtf8, Int64]),
-                ],
-                Volatility::Immutable,
-            ),
+            signature: Signature::user_defined(Volatility::Immutable),
         }
     }
 }
@@ -96,8 +88,9 @@ impl ScalarUDFImpl for OverlayFunc {
         &self.signature
     }
 
+    /// The strings are coerced to the same type, which is also the return type
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        utf8_to_str_type(&arg_types[0], "overlay")
+        Ok(arg_types[0].clone())
     }
 
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
@@ -112,11 +105,134 @@ impl ScalarUDFImpl for OverlayFunc {
         }
     }
 
+    /// Coerce the strings to the widest string type among them, and the
+    /// position and count to `Int64`, see [`coerce_overlay_types`].
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !matches!(arg_types.len(), 3 | 4) {
+            return plan_err!(
+                "overlay was called with {} arguments. It requires 3 or 4.",
+                arg_types.len()
+            );
+        }
+
+        coerce_overlay_types(self.name(), arg_types)
+    }
+
     fn documentation(&self) -> Option<&Documentation> {
         self.doc()
     }
 }
 
+#[user_doc(
+    doc_section(label = "String Functions"),
+    description = "Returns the string with another string inserted before the character at the specified position, without replacing any character. A position past the end of the string appends to it.",
+    syntax_example = "string_insert(str, substr, pos)",
+    sql_example = r#"```sql
+> select string_insert('Thas', 'om', 3);
++-------------------------------------------------+
+| string_insert(Utf8("Thas"),Utf8("om"),Int64(3)) |
++-------------------------------------------------+
+| Thomas                                          |
++-------------------------------------------------+
+```"#,
+    standard_argument(name = "str", prefix = "String"),
+    argument(name = "substr", description = "Substring to insert in str."),
+    argument(
+        name = "pos",
+        description = "The 1-based character position of str at which to insert substr. Must be at least 1."
+    ),
+    related_udf(name = "overlay")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct StringInsertFunc {
+    signature: Signature,
+}
+
+impl Default for StringInsertFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StringInsertFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StringInsertFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "string_insert"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// The strings are coerced to the same type, which is also the return type
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(string_insert, vec![])(&args.args)
+    }
+
+    /// Coerced like the arguments of `overlay` without a count
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.len() != 3 {
+            return plan_err!(
+                "string_insert was called with {} arguments. It requires 3.",
+                arg_types.len()
+            );
+        }
+
+        coerce_overlay_types(self.name(), arg_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Coerce the two leading strings to the widest string type among them:
+/// `LargeUtf8` if any is large, else `Utf8View` if any is a view, else `Utf8`.
+/// The remaining position and count arguments are coerced to `Int64`.
+fn coerce_overlay_types(name: &str, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+    let (strings, integers) = arg_types.split_at(2);
+    for string in strings {
+        if !matches!(
+            string,
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View | DataType::Null
+        ) {
+            return plan_err!("{name} requires string arguments, got {string}");
+        }
+    }
+    let string_type = if strings.contains(&DataType::LargeUtf8) {
+        DataType::LargeUtf8
+    } else if strings.contains(&DataType::Utf8View) {
+        DataType::Utf8View
+    } else {
+        DataType::Utf8
+    };
+
+    let mut coerced = vec![string_type.clone(), string_type];
+    for integer in integers {
+        if !integer.is_integer() && !integer.is_null() {
+            return plan_err!("{name} requires integer positions, got {integer}");
+        }
+        coerced.push(DataType::Int64);
+    }
+
+    Ok(coerced)
+}
+
 macro_rules! process_overlay {
     // For the three-argument case
     ($string_array:expr, $characters_array:expr, $pos_num:expr) => {{
@@ -149,7 +265,7 @@ macro_rules! process_overlay {
                 _ => Ok(None),
             }
         })
-        .collect::<Result<GenericStringArray<T>>>()
+        .collect::<Result<_>>()
     }};
 
     // For the four-argument case
@@ -184,7 +300,7 @@ macro_rules! process_overlay {
                 _ => Ok(None),
             }
         })
-        .collect::<Result<GenericStringArray<T>>>()
+        .collect::<Result<_>>()
     }};
 }
 
@@ -208,7 +324,8 @@ pub fn string_overlay<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
             let characters_array = as_generic_string_array::<T>(&args[1])?;
             let pos_num = as_int64_array(&args[2])?;
 
-            let result = process_overlay!(string_array, characters_array, pos_num)?;
+            let result: GenericStringArray<T> =
+                process_overlay!(string_array, characters_array, pos_num)?;
             Ok(Arc::new(result) as ArrayRef)
         }
         4 => {
@@ -217,7 +334,7 @@ pub fn string_overlay<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef>
             let pos_num = as_int64_array(&args[2])?;
             let len_num = as_int64_array(&args[3])?;
 
-            let result =
+            let result: GenericStringArray<T> =
                 process_overlay!(string_array, characters_array, pos_num, len_num)?;
             Ok(Arc::new(result) as ArrayRef)
         }
@@ -234,7 +351,8 @@ pub fn string_view_overlay<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<Arra
             let characters_array = as_string_view_array(&args[1])?;
             let pos_num = as_int64_array(&args[2])?;
 
-            let result = process_overlay!(string_array, characters_array, pos_num)?;
+            let result: StringViewArray =
+                process_overlay!(string_array, characters_array, pos_num)?;
             Ok(Arc::new(result) as ArrayRef)
         }
         4 => {
@@ -243,7 +361,7 @@ pub fn string_view_overlay<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<Arra
             let pos_num = as_int64_array(&args[2])?;
             let len_num = as_int64_array(&args[3])?;
 
-            let result =
+            let result: StringViewArray =
                 process_overlay!(string_array, characters_array, pos_num, len_num)?;
             Ok(Arc::new(result) as ArrayRef)
         }
@@ -253,9 +371,84 @@ pub fn string_view_overlay<T: OffsetSizeTrait>(args: &[ArrayRef]) -> Result<Arra
     }
 }
 
+/// Returns `string` with `characters` inserted before its character at the
+/// 1-based `pos`, or appended if `pos` is past its end
+fn insert_chars(string: &str, characters: &str, pos: i64) -> String {
+    let start = string
+        .char_indices()
+        .nth((pos - 1) as usize)
+        .map_or(string.len(), |(start, _)| start);
+
+    let mut res = String::with_capacity(string.len() + characters.len());
+    res.push_str(&string[..start]);
+    res.push_str(characters);
+    res.push_str(&string[start..]);
+    res
+}
+
+fn string_insert_impl<'a, S, R>(
+    string_array: S,
+    characters_array: S,
+    pos_num: &Int64Array,
+) -> Result<R>
+where
+    S: StringArrayType<'a>,
+    R: FromIterator<Option<String>>,
+{
+    string_array
+        .iter()
+        .zip(characters_array.iter())
+        .zip(pos_num.iter())
+        .map(
+            |((string, characters), pos)| match (string, characters, pos) {
+                (Some(_), Some(_), Some(pos)) if pos < 1 => {
+                    exec_err!("string_insert position must be at least 1, got {pos}")
+                }
+                (Some(string), Some(characters), Some(pos)) => {
+                    Ok(Some(insert_chars(string, characters, pos)))
+                }
+                _ => Ok(None),
+            },
+        )
+        .collect()
+}
+
+/// STRING_INSERT(string1, string2, integer)
+/// Inserts string2 into string1 before the integer'th character
+/// string_insert('Thas', 'om', 3) → Thomas
+/// string_insert('Thom', 'as', 5) → Thomas
+fn string_insert(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let pos_num = as_int64_array(&args[2])?;
+    let result: ArrayRef = match args[0].data_type() {
+        DataType::Utf8View => Arc::new(string_insert_impl::<_, StringViewArray>(
+            args[0].as_string_view(),
+            args[1].as_string_view(),
+            pos_num,
+        )?),
+        DataType::Utf8 => Arc::new(string_insert_impl::<_, GenericStringArray<i32>>(
+            args[0].as_string::<i32>(),
+            args[1].as_string::<i32>(),
+            pos_num,
+        )?),
+        DataType::LargeUtf8 => {
+            Arc::new(string_insert_impl::<_, GenericStringArray<i64>>(
+                args[0].as_string::<i64>(),
+                args[1].as_string::<i64>(),
+                pos_num,
+            )?)
+        }
+        other => {
+            return exec_err!(
+                "Unsupported data type {other:?} for function string_insert"
+            )
+        }
+    };
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
-    use arrow::array::{Int64Array, StringArray};
+    use arrow::array::StringArray;
 
     use super::*;
 
@@ -275,4 +468,67 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn overlay_return_type() -> Result<()> {
+        use DataType::*;
+        let overlay = OverlayFunc::new();
+        let cases = [
+            (vec![Utf8, Utf8, Int64], Utf8),
+            (vec![Utf8, Utf8, Int32, Int64], Utf8),
+            (vec![LargeUtf8, LargeUtf8, Int64], LargeUtf8),
+            (vec![Utf8, LargeUtf8, Int64], LargeUtf8),
+            (vec![Utf8View, LargeUtf8, Int64], LargeUtf8),
+            (vec![Utf8View, Utf8View, Int64], Utf8View),
+            (vec![Utf8, Utf8View, Int64, Int64], Utf8View),
+            (vec![Null, Utf8View, Int64], Utf8View),
+        ];
+        for (arg_types, expected) in cases {
+            let coerced = overlay.coerce_types(&arg_types)?;
+            assert_eq!(coerced[0], expected, "{arg_types:?}");
+            assert_eq!(coerced[1], expected, "{arg_types:?}");
+            assert_eq!(overlay.return_type(&coerced)?, expected, "{arg_types:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_string_insert() -> Result<()> {
+        let string = Arc::new(StringArray::from(vec![
+            Some("Thas"),
+            Some("omas"),
+            Some("Thom"),
+            Some("Thom"),
+            Some("dé"),
+            None,
+        ]));
+        let characters = Arc::new(StringArray::from(vec![
+            Some("om"),
+            Some("Th"),
+            Some("as"),
+            Some("as"),
+            Some("ç"),
+            Some("x"),
+        ]));
+        let pos = Arc::new(Int64Array::from(vec![3, 1, 5, 100, 2, 1]));
+
+        let res = string_insert(&[string, characters, pos])?;
+        let expected = StringArray::from(vec![
+            Some("Thomas"),
+            Some("Thomas"),
+            Some("Thomas"),
+            Some("Thomas"),
+            Some("dçé"),
+            None,
+        ]);
+        assert_eq!(&expected, as_generic_string_array::<i32>(&res)?);
+
+        let string = Arc::new(StringArray::from(vec!["abc"]));
+        let characters = Arc::new(StringArray::from(vec!["x"]));
+        let pos = Arc::new(Int64Array::from(vec![0]));
+        assert!(string_insert(&[string, characters, pos]).is_err());
+
+        Ok(())
+    }
 }
@@ -16,13 +16,18 @@
 // under the License.
 
 use arrow::datatypes::Field;
-use datafusion_common::Result;
+use datafusion_common::{plan_err, Result};
 use datafusion_common::{Column, DFSchema, ScalarValue, TableReference};
 use datafusion_expr::expr::ScalarFunction;
-use datafusion_expr::planner::{ExprPlanner, PlannerResult, RawDictionaryExpr};
+use datafusion_expr::planner::{
+    ExprPlanner, PlannerResult, RawDictionaryExpr, RawScalarFunctionExpr,
+};
 use datafusion_expr::{lit, Expr};
 
-use super::named_struct;
+use super::arrow_cast::ArrowCastFunc;
+use super::greatest::GreatestFunc;
+use super::least::LeastFunc;
+use super::{named_struct, NullPolicy, OverflowMode};
 
 #[derive(Default, Debug)]
 pub struct CoreFunctionPlanner {}
@@ -64,6 +69,53 @@ impl ExprPlanner for CoreFunctionPlanner {
         )))
     }
 
+    fn plan_named_arguments(
+        &self,
+        expr: RawScalarFunctionExpr,
+    ) -> Result<PlannerResult<RawScalarFunctionExpr>> {
+        let func = expr.func.inner().as_any();
+        let named_arg = if func.is::<GreatestFunc>() || func.is::<LeastFunc>() {
+            "nulls"
+        } else if func.is::<ArrowCastFunc>() {
+            "overflow"
+        } else {
+            return Ok(PlannerResult::Original(expr));
+        };
+        let name = expr.func.name();
+
+        // the only named argument is the trailing `<named_arg> => '<option>'`
+        let mut args = expr.args;
+        let option = match expr.arg_names.as_slice() {
+            [positional @ .., Some(arg_name)]
+                if arg_name == named_arg && positional.iter().all(Option::is_none) =>
+            {
+                match args.pop() {
+                    Some(Expr::Literal(ScalarValue::Utf8(Some(option)), _)) => option,
+                    other => {
+                        return plan_err!(
+                            "{name} expects the {named_arg} argument to be a string literal, got {other:?}"
+                        )
+                    }
+                }
+            }
+            _ => {
+                return plan_err!(
+                    "{name} only supports the named argument {named_arg} after its other arguments"
+                )
+            }
+        };
+
+        // the option is passed to the function as a trailing options literal
+        let options = match named_arg {
+            "nulls" => option.parse::<NullPolicy>()?.to_scalar(),
+            _ => option.parse::<OverflowMode>()?.to_scalar(),
+        };
+        args.push(lit(options));
+        Ok(PlannerResult::Planned(Expr::ScalarFunction(
+            ScalarFunction::new_udf(expr.func, args),
+        )))
+    }
+
     fn plan_compound_identifier(
         &self,
         field: &Field,
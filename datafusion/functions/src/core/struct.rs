@@ -17,9 +17,9 @@
 
 use arrow::array::StructArray;
 use arrow::datatypes::{DataType, Field};
-use datafusion_common::{exec_err, internal_err, Result};
+use datafusion_common::{internal_err, Result};
 use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
-use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_expr::{ScalarUDFImpl, Signature, TypeSignature, Volatility};
 use datafusion_macros::user_doc;
 use std::any::Any;
 use std::sync::Arc;
@@ -28,7 +28,8 @@ use std::sync::Arc;
     doc_section(label = "Struct Functions"),
     description = "Returns an Arrow struct using the specified input expressions optionally named.
 Fields in the returned struct use the optional name or the `cN` naming convention.
-For example: `c0`, `c1`, `c2`, etc.",
+For example: `c0`, `c1`, `c2`, etc.
+Called without arguments, returns a struct with no fields.",
     syntax_example = "struct(expression1[, ..., expression_n])",
     sql_example = r#"For example, this query converts two columns `a` and `b` to a single column with
 a struct type of fields `field_a` and `c1`:
@@ -79,7 +80,10 @@ impl Default for StructFunc {
 impl StructFunc {
     pub fn new() -> Self {
         Self {
-            signature: Signature::variadic_any(Volatility::Immutable),
+            signature: Signature::one_of(
+                vec![TypeSignature::Nullary, TypeSignature::VariadicAny],
+                Volatility::Immutable,
+            ),
             aliases: vec![String::from("row")],
         }
     }
@@ -102,10 +106,7 @@ impl ScalarUDFImpl for StructFunc {
     }
 
     fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
-        if arg_types.is_empty() {
-            return exec_err!("struct requires at least one argument, got 0 instead");
-        }
-
+        // with no arguments the result is the empty struct
         let fields = arg_types
             .iter()
             .enumerate()
@@ -127,6 +128,12 @@ impl ScalarUDFImpl for StructFunc {
             "return type field count != argument count"
         );
 
+        if args.args.is_empty() {
+            return Ok(ColumnarValue::Array(Arc::new(
+                StructArray::new_empty_fields(args.number_rows, None),
+            )));
+        }
+
         let arrays = ColumnarValue::values_to_arrays(&args.args)?;
         Ok(ColumnarValue::Array(Arc::new(StructArray::new(
             fields.clone(),
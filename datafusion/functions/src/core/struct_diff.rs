@@ -0,0 +1,229 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StructDiffFunc`]: Implementation of the `struct_diff` function
+
+use crate::core::deep_eq::deep_not_distinct;
+use arrow::array::{Array, ArrayRef, ListArray, ListBuilder, StringBuilder};
+use arrow::datatypes::DataType;
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns the names of the fields whose values differ between two structs with the same fields, in declaration order. The fields are compared like `deep_eq`, except that _null_ values are equal to each other and differ from any other value. Returns _null_ if either struct is _null_.",
+    syntax_example = "struct_diff(old_struct, new_struct)",
+    sql_example = r#"```sql
+> select struct_diff(named_struct('id', 1, 'name', 'a'), named_struct('id', 1, 'name', 'b'));
++--------------------------------------------------------------------------------------------------------------------------------+
+| struct_diff(named_struct(Utf8("id"),Int64(1),Utf8("name"),Utf8("a")),named_struct(Utf8("id"),Int64(1),Utf8("name"),Utf8("b"))) |
++--------------------------------------------------------------------------------------------------------------------------------+
+| [name]                                                                                                                         |
++--------------------------------------------------------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "old_struct",
+        description = "Struct expression to compare. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "new_struct",
+        description = "Struct expression to compare to old_struct. Must have the same field names and types, in the same order, as old_struct."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct StructDiffFunc {
+    signature: Signature,
+}
+
+impl Default for StructDiffFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructDiffFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StructDiffFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_diff"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::new_list(DataType::Utf8, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let is_scalar = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let [old, new] = take_function_args(self.name(), &arrays)?;
+
+        let result = struct_diff(old, new)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+
+    /// The structs are not coerced, they must have the same fields
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [old, new] = take_function_args(self.name(), arg_types)?;
+        let (DataType::Struct(old_fields), DataType::Struct(new_fields)) = (old, new)
+        else {
+            return plan_err!(
+                "struct_diff requires struct arguments, got {old} and {new}"
+            );
+        };
+
+        let same_fields = old_fields.len() == new_fields.len()
+            && old_fields.iter().zip(new_fields.iter()).all(|(old, new)| {
+                old.name() == new.name()
+                    && old.data_type().equals_datatype(new.data_type())
+            });
+        if !same_fields {
+            return plan_err!(
+                "struct_diff requires two structs with the same fields, got {old} and {new}"
+            );
+        }
+
+        Ok(arg_types.to_vec())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns, for every row, the names of the fields that differ between the
+/// `old` and `new` structs, or NULL when either struct is NULL
+fn struct_diff(old: &ArrayRef, new: &ArrayRef) -> Result<ListArray> {
+    let old = as_struct_array(old)?;
+    let new = as_struct_array(new)?;
+
+    let unchanged = old
+        .columns()
+        .iter()
+        .zip(new.columns())
+        .map(|(old, new)| deep_not_distinct(old, new))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    for row in 0..old.len() {
+        if old.is_null(row) || new.is_null(row) {
+            builder.append_null();
+            continue;
+        }
+        for (field, same) in old.fields().iter().zip(&unchanged) {
+            if !same.value(row) {
+                builder.values().append_value(field.name());
+            }
+        }
+        builder.append(true);
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{AsArray, Int32Array, StringArray, StructArray};
+    use arrow::buffer::NullBuffer;
+    use arrow::datatypes::{Field, Fields};
+
+    fn struct_of(ids: Vec<Option<i32>>, names: Vec<Option<&str>>) -> ArrayRef {
+        let fields = Fields::from(vec![
+            Field::new("id", DataType::Int32, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let nulls = NullBuffer::from(vec![true, true, true, true, false]);
+        Arc::new(StructArray::new(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(ids)) as ArrayRef,
+                Arc::new(StringArray::from(names)) as ArrayRef,
+            ],
+            Some(nulls),
+        ))
+    }
+
+    #[test]
+    fn struct_diff_lists_changed_fields() -> Result<()> {
+        let old = struct_of(
+            vec![Some(1), Some(1), None, None, Some(1)],
+            vec![Some("a"), Some("a"), None, Some("a"), Some("a")],
+        );
+        let new = struct_of(
+            vec![Some(1), Some(2), None, Some(1), Some(1)],
+            vec![Some("a"), Some("b"), None, None, Some("a")],
+        );
+
+        let result = struct_diff(&old, &new)?;
+        let rows = result
+            .iter()
+            .map(|names| {
+                names.map(|names| {
+                    names
+                        .as_string::<i32>()
+                        .iter()
+                        .map(|name| name.unwrap().to_string())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            vec![
+                Some(vec![]),
+                Some(vec!["id".to_string(), "name".to_string()]),
+                // NULL values are equal
+                Some(vec![]),
+                // a NULL value differs from any other value
+                Some(vec!["id".to_string(), "name".to_string()]),
+                // the last structs are NULL
+                None,
+            ]
+        );
+        Ok(())
+    }
+}
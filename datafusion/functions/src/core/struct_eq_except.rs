@@ -0,0 +1,252 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StructEqExceptFunc`]: Implementation of the `struct_eq_except` function
+
+use crate::core::deep_eq::deep_eq;
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::type_coercion::binary::comparison_coercion;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns true if two structs are equal when ignoring the fields with the given names, comparing the other fields like `deep_eq`. Returns false if any other field differs, otherwise NULL if either struct or any value compared is NULL.",
+    syntax_example = "struct_eq_except(struct1, struct2[, field_name1, ..., field_name_n])",
+    sql_example = r#"```sql
+> select struct_eq_except(named_struct('id', 1, 'ts', 10), named_struct('id', 1, 'ts', 20), 'ts');
++--------------------------------------------------------------------------------------------------------------------------------------------+
+| struct_eq_except(named_struct(Utf8("id"),Int64(1),Utf8("ts"),Int64(10)),named_struct(Utf8("id"),Int64(1),Utf8("ts"),Int64(20)),Utf8("ts")) |
++--------------------------------------------------------------------------------------------------------------------------------------------+
+| true                                                                                                                                       |
++--------------------------------------------------------------------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "struct1",
+        description = "Struct expression to compare. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "struct2",
+        description = "Struct expression to compare to struct1. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "field_name1, field_name_n",
+        description = "Constant strings with the names of the fields to ignore, which must exist in the structs."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct StructEqExceptFunc {
+    signature: Signature,
+}
+
+impl Default for StructEqExceptFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructEqExceptFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StructEqExceptFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_eq_except"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let DataType::Struct(fields) = args.arg_fields[0].data_type() else {
+            return internal_err!("struct_eq_except should have been coerced to structs");
+        };
+
+        for name in &args.scalar_arguments[2..] {
+            let Some(name) = name.and_then(|sv| sv.try_as_str().flatten()) else {
+                return plan_err!(
+                    "struct_eq_except requires the names of the ignored fields as constant strings"
+                );
+            };
+            if fields.find(name).is_none() {
+                return plan_err!(
+                    "struct_eq_except cannot ignore field '{name}', which does not exist in the struct"
+                );
+            }
+        }
+
+        Ok(Arc::new(Field::new(self.name(), DataType::Boolean, true)))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let ignored = args.args[2..]
+            .iter()
+            .map(|arg| match arg {
+                ColumnarValue::Scalar(name) => name.try_as_str().flatten(),
+                ColumnarValue::Array(_) => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        let Some(ignored) = ignored else {
+            return exec_err!(
+                "struct_eq_except requires the names of the ignored fields as constant strings"
+            );
+        };
+
+        let is_scalar = args.args[..2]
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+        let arrays = ColumnarValue::values_to_arrays(&args.args[..2])?;
+        let result = deep_eq(
+            &without_fields(&arrays[0], &ignored)?,
+            &without_fields(&arrays[1], &ignored)?,
+        )?;
+
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+
+    /// Coerce the structs to a common struct type like `deep_eq`, keeping the
+    /// names of the ignored fields
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [lhs, rhs, names @ ..] = arg_types else {
+            return plan_err!(
+                "struct_eq_except requires at least 2 arguments, got {}",
+                arg_types.len()
+            );
+        };
+
+        let Some(struct_type @ DataType::Struct(_)) = comparison_coercion(lhs, rhs)
+        else {
+            return plan_err!(
+                "struct_eq_except requires two structs of comparable types, got {lhs} and {rhs}"
+            );
+        };
+        let is_string = |data_type: &&DataType| {
+            matches!(
+                data_type,
+                DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+            )
+        };
+        if let Some(name) = names.iter().find(|name| !is_string(name)) {
+            return plan_err!(
+                "struct_eq_except requires the names of the ignored fields as strings, got {name}"
+            );
+        }
+
+        Ok([struct_type.clone(), struct_type]
+            .into_iter()
+            .chain(names.iter().cloned())
+            .collect())
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the struct `array` without the fields named in `ignored`, keeping
+/// its NULL structs
+fn without_fields(array: &ArrayRef, ignored: &[&str]) -> Result<ArrayRef> {
+    let array = as_struct_array(array)?;
+    let (fields, columns): (Vec<_>, Vec<_>) = array
+        .fields()
+        .iter()
+        .zip(array.columns())
+        .filter(|(field, _)| !ignored.contains(&field.name().as_str()))
+        .map(|(field, column)| (Arc::clone(field), Arc::clone(column)))
+        .unzip();
+
+    if fields.is_empty() {
+        return Ok(Arc::new(StructArray::new_empty_fields(
+            array.len(),
+            array.nulls().cloned(),
+        )));
+    }
+    Ok(Arc::new(StructArray::try_new(
+        fields.into(),
+        columns,
+        array.nulls().cloned(),
+    )?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{BooleanArray, Int32Array};
+
+    fn struct_of(ids: Vec<i32>, timestamps: Vec<i32>) -> ArrayRef {
+        Arc::new(StructArray::from(vec![
+            (
+                Arc::new(Field::new("id", DataType::Int32, true)),
+                Arc::new(Int32Array::from(ids)) as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("ts", DataType::Int32, true)),
+                Arc::new(Int32Array::from(timestamps)) as ArrayRef,
+            ),
+        ]))
+    }
+
+    #[test]
+    fn struct_eq_except_ignores_fields() -> Result<()> {
+        let left = struct_of(vec![1, 1, 2], vec![10, 20, 30]);
+        let right = struct_of(vec![1, 2, 2], vec![11, 20, 31]);
+
+        let result = deep_eq(
+            &without_fields(&left, &["ts"])?,
+            &without_fields(&right, &["ts"])?,
+        )?;
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), Some(false), Some(true)])
+        );
+
+        // ignoring all the fields leaves empty structs, which are equal
+        let result = deep_eq(
+            &without_fields(&left, &["id", "ts"])?,
+            &without_fields(&right, &["id", "ts"])?,
+        )?;
+        assert_eq!(result, BooleanArray::from(vec![true, true, true]));
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StructHashFunc`]: Implementation of the `struct_hash` function
+
+use ahash::RandomState;
+use arrow::array::{Array, ArrayRef, Int64Array};
+use arrow::datatypes::DataType;
+use datafusion_common::hash_utils::create_hashes;
+use datafusion_common::{utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+/// Fixed seeds, so that the same value hashes the same across sessions and processes
+const SEED: RandomState = RandomState::with_seeds(0, 0, 0, 0);
+
+/// The hash of a _null_ value, whatever its type
+const NULL_HASH: i64 = 0;
+
+#[user_doc(
+    doc_section(label = "Hashing Functions"),
+    description = "Returns an `Int64` hash of a value of any type, hashing the fields of structs and the elements of lists and maps recursively. Equal values have equal hashes, and the hash does not change between sessions, but it may change between DataFusion versions. A _null_ value hashes to `0`, while a _null_ nested in a value contributes to its hash.",
+    syntax_example = "struct_hash(expression)",
+    sql_example = r#"```sql
+> select struct_hash([1, 2]) = struct_hash([1, 2]), struct_hash(null);
++-----------------------------------------------------------------------------------------+-------------------+
+| struct_hash(make_array(Int64(1),Int64(2))) = struct_hash(make_array(Int64(1),Int64(2))) | struct_hash(NULL) |
++-----------------------------------------------------------------------------------------+-------------------+
+| true                                                                                    | 0                 |
++-----------------------------------------------------------------------------------------+-------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Expression to hash. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct StructHashFunc {
+    signature: Signature,
+}
+
+impl Default for StructHashFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructHashFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the hash of every row of `array`, with [`NULL_HASH`] for null rows
+fn struct_hash(array: &ArrayRef) -> Result<Int64Array> {
+    let mut hashes = vec![0; array.len()];
+    create_hashes(&[Arc::clone(array)], &SEED, &mut hashes)?;
+
+    let nulls = array.logical_nulls();
+    Ok(hashes
+        .into_iter()
+        .enumerate()
+        .map(|(i, hash)| match &nulls {
+            Some(nulls) if nulls.is_null(i) => NULL_HASH,
+            _ => hash as i64,
+        })
+        .collect())
+}
+
+impl ScalarUDFImpl for StructHashFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_hash"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [arg] = take_function_args(self.name(), args.args)?;
+
+        match arg {
+            ColumnarValue::Array(array) => {
+                Ok(ColumnarValue::Array(Arc::new(struct_hash(&array)?)))
+            }
+            ColumnarValue::Scalar(value) => {
+                let hashes = struct_hash(&value.to_array()?)?;
+                Ok(ColumnarValue::Scalar(ScalarValue::Int64(Some(
+                    hashes.value(0),
+                ))))
+            }
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Int32Array, StructArray};
+    use arrow::buffer::NullBuffer;
+    use arrow::datatypes::{Field, Fields};
+
+    #[test]
+    fn test_struct_hash() -> Result<()> {
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Int32, true),
+        ]);
+        // {1, 2}, {1, 2}, {1, NULL}, {1, NULL}, {2, 1}, NULL, NULL
+        let array: ArrayRef = Arc::new(StructArray::try_new(
+            fields,
+            vec![
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    Some(1),
+                    Some(1),
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                    Some(4),
+                ])),
+                Arc::new(Int32Array::from(vec![
+                    Some(2),
+                    Some(2),
+                    None,
+                    None,
+                    Some(1),
+                    Some(3),
+                    Some(4),
+                ])),
+            ],
+            Some(NullBuffer::from(vec![
+                true, true, true, true, true, false, false,
+            ])),
+        )?);
+
+        let hashes = struct_hash(&array)?;
+        assert_eq!(hashes.null_count(), 0);
+        assert_eq!(hashes.value(0), hashes.value(1));
+        assert_eq!(hashes.value(2), hashes.value(3));
+        assert_ne!(hashes.value(0), hashes.value(2));
+        assert_ne!(hashes.value(0), hashes.value(4));
+        // the values behind null structs are ignored
+        assert_eq!(hashes.value(5), NULL_HASH);
+        assert_eq!(hashes.value(6), NULL_HASH);
+
+        // hashes are stable across calls
+        assert_eq!(struct_hash(&array)?, hashes);
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StructKeysFunc`]: Implementation of the `struct_keys` function
+
+use arrow::datatypes::{DataType, Fields};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{
+    lit, ColumnarValue, Documentation, Expr, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns the names of all the fields of a struct, in declaration order. The result is a constant that only depends on the type of the struct, also for _null_ structs.",
+    syntax_example = "struct_keys(struct)",
+    sql_example = r#"```sql
+> select struct_keys(named_struct('a', 1, 'b', 'x'));
++-------------------------------------------------------------------+
+| struct_keys(named_struct(Utf8("a"),Int64(1),Utf8("b"),Utf8("x"))) |
++-------------------------------------------------------------------+
+| [a, b]                                                            |
++-------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "struct",
+        description = "Struct expression. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct StructKeysFunc {
+    signature: Signature,
+}
+
+impl Default for StructKeysFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructKeysFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StructKeysFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_keys"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [base] = take_function_args(self.name(), arg_types)?;
+
+        if !matches!(base, DataType::Struct(_)) {
+            return plan_err!("struct_keys requires a struct argument, got {base}");
+        }
+
+        Ok(DataType::new_list(DataType::Utf8, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [base] = take_function_args(self.name(), args.args)?;
+
+        match base.data_type() {
+            DataType::Struct(fields) => {
+                Ok(ColumnarValue::Scalar(struct_field_names(&fields)))
+            }
+            other => exec_err!("struct_keys requires a struct argument, got {other}"),
+        }
+    }
+
+    /// The field names only depend on the struct type, so the call is replaced
+    /// by a list literal at planning time.
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [base] = take_function_args(self.name(), &args)?;
+
+        match info.get_data_type(base)? {
+            DataType::Struct(fields) => Ok(ExprSimplifyResult::Simplified(lit(
+                struct_field_names(&fields),
+            ))),
+            _ => Ok(ExprSimplifyResult::Original(args)),
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns a `List<Utf8>` scalar with the names of `fields`, in declaration order
+fn struct_field_names(fields: &Fields) -> ScalarValue {
+    let names = fields
+        .iter()
+        .map(|field| ScalarValue::from(field.name().as_str()))
+        .collect::<Vec<_>>();
+
+    ScalarValue::List(ScalarValue::new_list_nullable(&names, &DataType::Utf8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StructKeysFunc;
+    use arrow::datatypes::{DataType, Field, Fields, Schema};
+    use datafusion_common::{DFSchema, ScalarValue};
+    use datafusion_expr::execution_props::ExecutionProps;
+    use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyContext};
+    use datafusion_expr::{col, lit, ScalarUDFImpl};
+    use std::sync::Arc;
+
+    #[test]
+    fn struct_keys_simplifies_to_literal() {
+        let fields = Fields::from(vec![
+            Field::new("z", DataType::Utf8, false),
+            Field::new("a", DataType::Int32, true),
+            Field::new("m", DataType::Boolean, true),
+        ]);
+        let schema = Schema::new(vec![Field::new("s", DataType::Struct(fields), true)]);
+        let schema = Arc::new(DFSchema::try_from(schema).unwrap());
+        let props = ExecutionProps::new();
+        let context = SimplifyContext::new(&props).with_schema(schema);
+
+        let result = StructKeysFunc::new()
+            .simplify(vec![col("s")], &context)
+            .unwrap();
+        let ExprSimplifyResult::Simplified(expr) = result else {
+            panic!("Expected ExprSimplifyResult::Simplified")
+        };
+
+        let expected = ScalarValue::List(ScalarValue::new_list_nullable(
+            &[
+                ScalarValue::from("z"),
+                ScalarValue::from("a"),
+                ScalarValue::from("m"),
+            ],
+            &DataType::Utf8,
+        ));
+        assert_eq!(expr, lit(expected));
+    }
+}
@@ -0,0 +1,202 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StructSetFunc`]: Implementation of the `struct_set` function
+
+use arrow::array::{ArrayRef, StructArray};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, FieldRef, Fields};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{
+    exec_err, internal_err, plan_datafusion_err, Result, ScalarValue,
+};
+use datafusion_expr::binary::type_union_resolution;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns a copy of the struct with the value of the named field replaced. The field type is widened when the new value does not fit in it.",
+    syntax_example = "struct_set(struct, field_name, value)",
+    sql_example = r#"```sql
+> select struct_set(named_struct('a', 1, 'b', 'x'), 'a', 2);
++-------------------------------------------------------------------------------------+
+| struct_set(named_struct(Utf8("a"),Int64(1),Utf8("b"),Utf8("x")),Utf8("a"),Int64(2)) |
++-------------------------------------------------------------------------------------+
+| {a: 2, b: x}                                                                        |
++-------------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "struct",
+        description = "Struct expression to operate on. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "field_name",
+        description = "Name of the field to replace. Must be a constant string."
+    ),
+    argument(
+        name = "value",
+        description = "New value of the field. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct StructSetFunc {
+    signature: Signature,
+}
+
+impl Default for StructSetFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructSetFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(3, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StructSetFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_set"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [base, _, value] = take_function_args(self.name(), args.arg_fields)?;
+
+        let DataType::Struct(fields) = base.data_type() else {
+            return exec_err!(
+                "struct_set requires a struct as first argument, got {}",
+                base.data_type()
+            );
+        };
+
+        let field_name = args.scalar_arguments[1]
+            .and_then(|sv| sv.try_as_str().flatten().filter(|s| !s.is_empty()));
+        let Some(field_name) = field_name else {
+            return exec_err!(
+                "struct_set requires the field name as a non-empty constant string"
+            );
+        };
+
+        if !fields.iter().any(|f| f.name() == field_name) {
+            return Err(plan_datafusion_err!(
+                "Field {field_name} not found in struct"
+            ));
+        }
+
+        // the replaced field takes a type holding both its values and the new ones
+        let fields = fields
+            .iter()
+            .map(|field| {
+                if field.name() != field_name {
+                    return Ok(Arc::clone(field));
+                }
+
+                let data_type = type_union_resolution(&[
+                    field.data_type().clone(),
+                    value.data_type().clone(),
+                ])
+                .ok_or_else(|| {
+                    plan_datafusion_err!(
+                        "struct_set cannot coerce {} to the type {} of field {field_name}",
+                        value.data_type(),
+                        field.data_type()
+                    )
+                })?;
+
+                Ok(Arc::new(
+                    field
+                        .as_ref()
+                        .clone()
+                        .with_data_type(data_type)
+                        .with_nullable(field.is_nullable() || value.is_nullable()),
+                ))
+            })
+            .collect::<Result<Fields>>()?;
+
+        Ok(base
+            .as_ref()
+            .clone()
+            .with_data_type(DataType::Struct(fields))
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_type() else {
+            return internal_err!("incorrect struct_set return type");
+        };
+        let is_scalar = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+        let [base, field_name, value] = take_function_args(self.name(), &args.args)?;
+        let field_name = match field_name {
+            ColumnarValue::Scalar(name) => name.try_as_str().flatten(),
+            _ => None,
+        };
+        let Some(field_name) = field_name else {
+            return exec_err!(
+                "struct_set requires the field name as a non-empty constant string"
+            );
+        };
+
+        let arrays = ColumnarValue::values_to_arrays(&[base.clone(), value.clone()])?;
+        let (_, mut columns, nulls) = as_struct_array(&arrays[0])?.clone().into_parts();
+
+        let Some(index) = fields.iter().position(|f| f.name() == field_name) else {
+            return exec_err!("Field {field_name} not found in struct");
+        };
+        // the other columns are moved into the new struct as they are
+        columns[index] = cast(&arrays[1], fields[index].data_type())?;
+
+        let result: ArrayRef =
+            Arc::new(StructArray::try_new(fields.clone(), columns, nulls)?);
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
@@ -0,0 +1,149 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`StructUnwrapFunc`]: Implementation of the `struct_unwrap` function
+
+use arrow::array::{make_array, Array};
+use arrow::buffer::NullBuffer;
+use arrow::datatypes::{DataType, FieldRef};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns the value of the only field of a struct. The struct must have exactly one field.",
+    syntax_example = "struct_unwrap(struct)",
+    sql_example = r#"```sql
+> select struct_unwrap(named_struct('a', 1));
++-------------------------------------------------+
+| struct_unwrap(named_struct(Utf8("a"),Int64(1))) |
++-------------------------------------------------+
+| 1                                               |
++-------------------------------------------------+
+```"#,
+    argument(
+        name = "struct",
+        description = "Struct expression with a single field. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct StructUnwrapFunc {
+    signature: Signature,
+}
+
+impl Default for StructUnwrapFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StructUnwrapFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for StructUnwrapFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "struct_unwrap"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [base] = take_function_args(self.name(), args.arg_fields)?;
+
+        let DataType::Struct(fields) = base.data_type() else {
+            return plan_err!(
+                "struct_unwrap requires a struct argument, got {}",
+                base.data_type()
+            );
+        };
+
+        let [field] = fields.as_ref() else {
+            return plan_err!(
+                "struct_unwrap requires a struct with exactly one field, got {} fields",
+                fields.len()
+            );
+        };
+
+        // a NULL struct unwraps to NULL
+        Ok(field
+            .as_ref()
+            .clone()
+            .with_nullable(field.is_nullable() || base.is_nullable())
+            .into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [base] = take_function_args(self.name(), args.args)?;
+
+        match base {
+            ColumnarValue::Array(array) => {
+                let struct_array = as_struct_array(&array)?;
+                let [column] = struct_array.columns() else {
+                    return exec_err!(
+                        "struct_unwrap requires a struct with exactly one field"
+                    );
+                };
+
+                let nulls = NullBuffer::union(struct_array.nulls(), column.nulls());
+                let data = column.to_data().into_builder().nulls(nulls).build()?;
+                Ok(ColumnarValue::Array(make_array(data)))
+            }
+            ColumnarValue::Scalar(ScalarValue::Struct(array)) => {
+                let [column] = array.columns() else {
+                    return exec_err!(
+                        "struct_unwrap requires a struct with exactly one field"
+                    );
+                };
+
+                if array.is_null(0) {
+                    ScalarValue::try_from(column.data_type()).map(ColumnarValue::Scalar)
+                } else {
+                    ScalarValue::try_from_array(column, 0).map(ColumnarValue::Scalar)
+                }
+            }
+            ColumnarValue::Scalar(other) => {
+                exec_err!("struct_unwrap requires a struct argument, got {other:?}")
+            }
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
@@ -0,0 +1,229 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`SwitchFunc`]: Implementation of the `switch` function
+
+use arrow::array::{new_null_array, Array, ArrayRef};
+use arrow::compute::{cast, interleave};
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::cast::{as_string_array, as_struct_array};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::binary::type_union_resolution;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::collections::HashMap;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns the value of the field of _options_ whose name is equal to _key_, or _null_ if no field has that name. The values of _options_ are coerced to a common type.",
+    syntax_example = "switch(key, options)",
+    sql_example = r#"```sql
+> select switch('b', named_struct('a', 1, 'b', 2.5));
++---------------------------------------------------------------------------+
+| switch(Utf8("b"),named_struct(Utf8("a"),Int64(1),Utf8("b"),Float64(2.5))) |
++---------------------------------------------------------------------------+
+| 2.5                                                                       |
++---------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "key",
+        description = "String expression with the name of the field to return. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "options",
+        description = "Struct expression whose field names are the candidate keys and whose values are the results, for example created with `named_struct`."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct SwitchFunc {
+    signature: Signature,
+}
+
+impl Default for SwitchFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SwitchFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SwitchFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "switch"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [_, options] = take_function_args(self.name(), arg_types)?;
+        let DataType::Struct(fields) = options else {
+            return plan_err!("switch requires a struct of options, got {options}");
+        };
+
+        common_value_type(fields)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let is_scalar = args
+            .args
+            .iter()
+            .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+        let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+        let [keys, options] = take_function_args(self.name(), &arrays)?;
+
+        let result = switch(keys, options, args.return_type())?;
+
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [key, options] = take_function_args(self.name(), arg_types)?;
+
+        if !matches!(
+            key,
+            DataType::Null | DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+        ) {
+            return plan_err!("switch requires a string key, got {key}");
+        }
+
+        let DataType::Struct(fields) = options else {
+            return plan_err!("switch requires a struct of options, got {options}");
+        };
+
+        // cast every option to the common type so they can be interleaved
+        let value_type = common_value_type(fields)?;
+        let fields = fields
+            .iter()
+            .map(|f| Field::new(f.name(), value_type.clone(), true))
+            .collect::<Fields>();
+
+        Ok(vec![DataType::Utf8, DataType::Struct(fields)])
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the type all the options of the struct are coerced to
+fn common_value_type(fields: &Fields) -> Result<DataType> {
+    if fields.is_empty() {
+        return plan_err!("switch requires a struct with at least one option");
+    }
+
+    let types = fields
+        .iter()
+        .map(|f| f.data_type().clone())
+        .collect::<Vec<_>>();
+
+    match type_union_resolution(&types) {
+        Some(data_type) => Ok(data_type),
+        None => plan_err!(
+            "switch cannot coerce the options of type {} to a common type",
+            DataType::Struct(fields.clone())
+        ),
+    }
+}
+
+/// For each row, pick the value of the option whose name equals the key
+fn switch(
+    keys: &ArrayRef,
+    options: &ArrayRef,
+    return_type: &DataType,
+) -> Result<ArrayRef> {
+    let keys = cast(keys, &DataType::Utf8)?;
+    let keys = as_string_array(&keys)?;
+    let options = as_struct_array(options)?;
+
+    // the first field wins when several fields have the same name
+    let mut positions = HashMap::with_capacity(options.num_columns());
+    for (i, field) in options.fields().iter().enumerate() {
+        positions.entry(field.name().as_str()).or_insert(i);
+    }
+
+    let mut values = options
+        .columns()
+        .iter()
+        .map(|column| column.as_ref())
+        .collect::<Vec<_>>();
+    if values.iter().any(|v| v.data_type() != return_type) {
+        return exec_err!("switch options must all be of type {return_type}");
+    }
+
+    // rows without a matching option take the single null value after the options
+    let nulls = new_null_array(return_type, 1);
+    let null_index = (values.len(), 0);
+    values.push(nulls.as_ref());
+
+    let indices = keys
+        .iter()
+        .enumerate()
+        .map(|(row, key)| match key {
+            Some(key) if options.is_valid(row) => positions
+                .get(key)
+                .map_or(null_index, |&column| (column, row)),
+            _ => null_index,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(interleave(&values, &indices)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switch_coerces_options_to_common_type() {
+        let fields = Fields::from(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+        ]);
+        let coerced = SwitchFunc::new()
+            .coerce_types(&[DataType::Utf8View, DataType::Struct(fields)])
+            .unwrap();
+
+        let expected = Fields::from(vec![
+            Field::new("a", DataType::Float64, true),
+            Field::new("b", DataType::Float64, true),
+        ]);
+        assert_eq!(coerced, vec![DataType::Utf8, DataType::Struct(expected)]);
+    }
+}
@@ -0,0 +1,211 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`TryGetFieldFunc`]: Implementation of the `try_get_field` function
+
+use arrow::array::{Array, ArrayRef, BooleanArray, StructArray};
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::cast::as_struct_array;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+use super::concat_struct::with_parent_nulls;
+
+#[user_doc(
+    doc_section(label = "Struct Functions"),
+    description = "Returns a struct with the field of a struct with the given name as `value`, and whether the struct is not _null_ as `ok`. When the struct is _null_, `ok` is false and `value` is _null_, which tells it apart from a _null_ field of a struct that is not _null_, for which `ok` is true.",
+    syntax_example = "try_get_field(struct, name)",
+    sql_example = r#"```sql
+> select * from t;
++--------+
+| s      |
++--------+
+| {a: 1} |
+| {a: }  |
+|        |
++--------+
+> select try_get_field(s, 'a') from t;
++------------------------------+
+| try_get_field(t.s,Utf8("a")) |
++------------------------------+
+| {ok: true, value: 1}         |
+| {ok: true, value: }          |
+| {ok: false, value: }         |
++------------------------------+
+```"#,
+    argument(
+        name = "struct",
+        description = "Struct expression to retrieve the field from. Can be a constant, column, or function, and any combination of operators."
+    ),
+    argument(
+        name = "name",
+        description = "Constant string with the name of the field."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct TryGetFieldFunc {
+    signature: Signature,
+}
+
+impl Default for TryGetFieldFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TryGetFieldFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the fields of the struct returned for a field of type `value_type`
+fn result_fields(value_type: DataType) -> Fields {
+    Fields::from(vec![
+        Field::new("ok", DataType::Boolean, false),
+        Field::new("value", value_type, true),
+    ])
+}
+
+impl ScalarUDFImpl for TryGetFieldFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "try_get_field"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [base, _] = take_function_args(self.name(), args.arg_fields)?;
+        let Some(name) =
+            args.scalar_arguments[1].and_then(|name| name.try_as_str().flatten())
+        else {
+            return plan_err!("try_get_field requires the name to be a constant string");
+        };
+        let DataType::Struct(fields) = base.data_type() else {
+            return plan_err!(
+                "try_get_field requires a struct, got {}",
+                base.data_type()
+            );
+        };
+        let Some((_, field)) = fields.find(name) else {
+            return plan_err!(
+                "try_get_field did not find the field {name} in {}",
+                base.data_type()
+            );
+        };
+
+        let fields = result_fields(field.data_type().clone());
+        Ok(Field::new(self.name(), DataType::Struct(fields), false).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
+            return internal_err!("incorrect try_get_field return type");
+        };
+        let [base, name] = take_function_args(self.name(), args.args)?;
+        let ColumnarValue::Scalar(name) = name else {
+            return exec_err!("try_get_field requires the name to be a constant string");
+        };
+        let Some(name) = name.try_as_str().flatten() else {
+            return exec_err!("try_get_field requires the name to be a constant string");
+        };
+
+        let is_scalar = matches!(base, ColumnarValue::Scalar(_));
+        let array = base.into_array(1)?;
+        let result = try_get_field(&array, name, fields)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+                &result, 0,
+            )?))
+        } else {
+            Ok(ColumnarValue::Array(Arc::new(result)))
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns, for every row of the struct `base`, whether the struct is not NULL
+/// and its field `name`, which is NULL when the struct is
+fn try_get_field(base: &ArrayRef, name: &str, fields: &Fields) -> Result<StructArray> {
+    let base = as_struct_array(base)?;
+    let Some(column) = base.column_by_name(name) else {
+        return exec_err!("try_get_field did not find the field {name}");
+    };
+
+    let ok = match base.nulls() {
+        Some(nulls) => BooleanArray::new(nulls.inner().clone(), None),
+        None => BooleanArray::from(vec![true; base.len()]),
+    };
+    let value = with_parent_nulls(column, base.nulls())?;
+
+    let columns: Vec<ArrayRef> = vec![Arc::new(ok), value];
+    Ok(StructArray::try_new(fields.clone(), columns, None)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{AsArray, Int32Array};
+    use arrow::buffer::NullBuffer;
+    use arrow::datatypes::Int32Type;
+
+    #[test]
+    fn try_get_field_tells_null_parent_from_null_field() -> Result<()> {
+        let base: ArrayRef = Arc::new(StructArray::new(
+            Fields::from(vec![Field::new("a", DataType::Int32, true)]),
+            vec![Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef],
+            Some(NullBuffer::from(vec![true, true, false])),
+        ));
+
+        let result = try_get_field(&base, "a", &result_fields(DataType::Int32))?;
+        let ok = result.column(0).as_boolean();
+        let value = result.column(1).as_primitive::<Int32Type>();
+
+        // the field is NULL in the second row, and the struct in the third
+        assert_eq!(
+            ok.iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(true), Some(false)]
+        );
+        assert_eq!(value.iter().collect::<Vec<_>>(), vec![Some(1), None, None]);
+        assert_eq!(result.null_count(), 0);
+
+        assert!(try_get_field(&base, "b", &result_fields(DataType::Int32)).is_err());
+        Ok(())
+    }
+}
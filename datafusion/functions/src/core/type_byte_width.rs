@@ -0,0 +1,143 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`TypeByteWidthFunc`]: Implementation of the `type_byte_width` function
+
+use arrow::datatypes::DataType;
+use datafusion_common::{utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{ColumnarValue, Documentation, Expr, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = "Returns the fixed width in bytes of a value of the [Arrow data type](https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html) of the expression, such as 4 for `Int32` and 16 for `Decimal128`. Returns _null_ for the types whose values are not stored as fixed-width primitives, such as strings, lists, structs and dictionaries, and for booleans, which are stored as bits.",
+    syntax_example = "type_byte_width(expression)",
+    sql_example = r#"```sql
+> select type_byte_width(1), type_byte_width(arrow_cast(1, 'Int32')), type_byte_width('datafusion');
++---------------------------+-----------------------------------------------------+-------------------------------------+
+| type_byte_width(Int64(1)) | type_byte_width(arrow_cast(Int64(1),Utf8("Int32"))) | type_byte_width(Utf8("datafusion")) |
++---------------------------+-----------------------------------------------------+-------------------------------------+
+| 8                         | 4                                                   |                                     |
++---------------------------+-----------------------------------------------------+-------------------------------------+
+```
+"#,
+    argument(
+        name = "expression",
+        description = "Expression to evaluate. The expression can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct TypeByteWidthFunc {
+    signature: Signature,
+}
+
+impl Default for TypeByteWidthFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeByteWidthFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the byte width of the values of `data_type`, if it is fixed
+fn type_byte_width(data_type: &DataType) -> ScalarValue {
+    ScalarValue::Int64(data_type.primitive_width().map(|width| width as i64))
+}
+
+impl ScalarUDFImpl for TypeByteWidthFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "type_byte_width"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int64)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [arg] = take_function_args(self.name(), args.args)?;
+        Ok(ColumnarValue::Scalar(type_byte_width(&arg.data_type())))
+    }
+
+    /// The result only depends on the argument type, so it is folded into a literal
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [arg] = take_function_args(self.name(), &args)?;
+        let width = type_byte_width(&info.get_data_type(arg)?);
+        Ok(ExprSimplifyResult::Simplified(Expr::Literal(width, None)))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TypeByteWidthFunc;
+    use arrow::datatypes::{DataType, Field, Schema};
+    use datafusion_common::{DFSchema, ScalarValue};
+    use datafusion_expr::execution_props::ExecutionProps;
+    use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyContext};
+    use datafusion_expr::{col, lit, ScalarUDFImpl};
+    use std::sync::Arc;
+
+    #[test]
+    fn type_byte_width_simplifies_to_literal() {
+        let schema = Schema::new(vec![
+            Field::new("i", DataType::Int32, true),
+            Field::new("f", DataType::Float64, true),
+            Field::new("s", DataType::Utf8, true),
+        ]);
+        let schema = Arc::new(DFSchema::try_from(schema).unwrap());
+        let props = ExecutionProps::new();
+        let context = SimplifyContext::new(&props).with_schema(schema);
+
+        for (column, expected) in [
+            ("i", ScalarValue::Int64(Some(4))),
+            ("f", ScalarValue::Int64(Some(8))),
+            ("s", ScalarValue::Int64(None)),
+        ] {
+            let result = TypeByteWidthFunc::new()
+                .simplify(vec![col(column)], &context)
+                .unwrap();
+            let ExprSimplifyResult::Simplified(expr) = result else {
+                panic!("Expected ExprSimplifyResult::Simplified")
+            };
+            assert_eq!(expr, lit(expected), "for {column}");
+        }
+    }
+}
@@ -0,0 +1,176 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! [`TypeCategoryFunc`]: Implementation of the `type_category` function
+
+use arrow::datatypes::DataType;
+use datafusion_common::{utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{ColumnarValue, Documentation, Expr, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "Other Functions"),
+    description = "Returns a coarse category of the [Arrow data type](https://docs.rs/arrow/latest/arrow/datatypes/enum.DataType.html) of the expression, one of `null`, `boolean`, `integer`, `float`, `decimal`, `string`, `binary`, `temporal`, `nested` or `other`. Dictionary and run-end encoded types have the category of their values.",
+    syntax_example = "type_category(expression)",
+    sql_example = r#"```sql
+> select type_category(1), type_category(2.5), type_category(now());
++-------------------------+-----------------------------+----------------------+
+| type_category(Int64(1)) | type_category(Float64(2.5)) | type_category(now()) |
++-------------------------+-----------------------------+----------------------+
+| integer                 | float                       | temporal             |
++-------------------------+-----------------------------+----------------------+
+```
+"#,
+    argument(
+        name = "expression",
+        description = "Expression to evaluate. The expression can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct TypeCategoryFunc {
+    signature: Signature,
+}
+
+impl Default for TypeCategoryFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeCategoryFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+/// Returns the category of `data_type`
+fn type_category(data_type: &DataType) -> &'static str {
+    match data_type {
+        DataType::Null => "null",
+        DataType::Boolean => "boolean",
+        DataType::Decimal32(_, _)
+        | DataType::Decimal64(_, _)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => "decimal",
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => "string",
+        DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::FixedSizeBinary(_) => "binary",
+        DataType::Dictionary(_, value_type) => type_category(value_type),
+        DataType::RunEndEncoded(_, values) => type_category(values.data_type()),
+        t if t.is_integer() => "integer",
+        t if t.is_floating() => "float",
+        t if t.is_temporal() => "temporal",
+        t if t.is_nested() => "nested",
+        _ => "other",
+    }
+}
+
+impl ScalarUDFImpl for TypeCategoryFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "type_category"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [arg] = take_function_args(self.name(), args.args)?;
+        let category = type_category(&arg.data_type());
+        Ok(ColumnarValue::Scalar(ScalarValue::from(category)))
+    }
+
+    /// The result only depends on the argument type, so it is folded into a literal
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [arg] = take_function_args(self.name(), &args)?;
+        let category = type_category(&info.get_data_type(arg)?);
+        Ok(ExprSimplifyResult::Simplified(Expr::Literal(
+            ScalarValue::from(category),
+            None,
+        )))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::type_category;
+    use arrow::datatypes::{DataType, Field, IntervalUnit, TimeUnit};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_type_category() {
+        let cases = [
+            (DataType::Null, "null"),
+            (DataType::Boolean, "boolean"),
+            (DataType::Int8, "integer"),
+            (DataType::UInt64, "integer"),
+            (DataType::Float16, "float"),
+            (DataType::Float64, "float"),
+            (DataType::Decimal128(10, 2), "decimal"),
+            (DataType::Utf8View, "string"),
+            (DataType::LargeBinary, "binary"),
+            (DataType::Date32, "temporal"),
+            (
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+                "temporal",
+            ),
+            (DataType::Interval(IntervalUnit::MonthDayNano), "temporal"),
+            (DataType::new_list(DataType::Int32, true), "nested"),
+            (
+                DataType::Struct(vec![Field::new("a", DataType::Int32, true)].into()),
+                "nested",
+            ),
+            (
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                "string",
+            ),
+            (
+                DataType::RunEndEncoded(
+                    Arc::new(Field::new("run_ends", DataType::Int32, false)),
+                    Arc::new(Field::new("values", DataType::Float32, true)),
+                ),
+                "float",
+            ),
+        ];
+
+        for (data_type, expected) in cases {
+            assert_eq!(type_category(&data_type), expected, "for {data_type}");
+        }
+    }
+}
@@ -0,0 +1,169 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::datatypes::{DataType, UnionFields};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, Result, ScalarValue};
+use datafusion_doc::Documentation;
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{lit, ColumnarValue, Expr, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+
+#[user_doc(
+    doc_section(label = "Union Functions"),
+    description = "Returns the names of all the fields of the union, in declaration order. The result is a constant that does not depend on which field each row has selected.",
+    syntax_example = "union_fields(union_expression)",
+    sql_example = r#"```sql
+❯ select union_column, union_fields(union_column) from table_with_union;
++--------------+----------------------------+
+| union_column | union_fields(union_column) |
++--------------+----------------------------+
+| {a=1}        | [a, b]                     |
+| {b=3.0}      | [a, b]                     |
+| {a=4}        | [a, b]                     |
++--------------+----------------------------+
+```"#,
+    standard_argument(name = "union", prefix = "Union")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct UnionFieldsFunc {
+    signature: Signature,
+}
+
+impl Default for UnionFieldsFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UnionFieldsFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for UnionFieldsFunc {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "union_fields"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [union_] = take_function_args(self.name(), arg_types)?;
+
+        if !matches!(union_, DataType::Union(_, _)) {
+            return exec_err!("union_fields only support unions, got {union_}");
+        }
+
+        Ok(DataType::new_list(DataType::Utf8, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [union_] = take_function_args(self.name(), args.args)?;
+
+        match union_.data_type() {
+            DataType::Union(fields, _) => {
+                Ok(ColumnarValue::Scalar(union_field_names(&fields)))
+            }
+            other => exec_err!("union_fields only support unions, got {other}"),
+        }
+    }
+
+    /// The field names only depend on the union type, so the call is replaced
+    /// by a list literal at planning time.
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [union_] = take_function_args(self.name(), &args)?;
+
+        match info.get_data_type(union_)? {
+            DataType::Union(fields, _) => Ok(ExprSimplifyResult::Simplified(lit(
+                union_field_names(&fields),
+            ))),
+            _ => Ok(ExprSimplifyResult::Original(args)),
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns a `List<Utf8>` scalar with the names of `fields`, in declaration order
+fn union_field_names(fields: &UnionFields) -> ScalarValue {
+    let names = fields
+        .iter()
+        .map(|(_, field)| ScalarValue::from(field.name().as_str()))
+        .collect::<Vec<_>>();
+
+    ScalarValue::List(ScalarValue::new_list_nullable(&names, &DataType::Utf8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFieldsFunc;
+    use arrow::datatypes::{DataType, Field, Schema, UnionFields, UnionMode};
+    use datafusion_common::{DFSchema, ScalarValue};
+    use datafusion_expr::execution_props::ExecutionProps;
+    use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyContext};
+    use datafusion_expr::{col, lit, ScalarUDFImpl};
+    use std::sync::Arc;
+
+    #[test]
+    fn union_fields_simplifies_to_literal() {
+        let fields = UnionFields::new(
+            vec![3, 1],
+            vec![
+                Field::new("str", DataType::Utf8, false),
+                Field::new("int", DataType::Int32, false),
+            ],
+        );
+        let schema = Schema::new(vec![Field::new(
+            "u",
+            DataType::Union(fields, UnionMode::Sparse),
+            true,
+        )]);
+        let schema = Arc::new(DFSchema::try_from(schema).unwrap());
+        let props = ExecutionProps::new();
+        let context = SimplifyContext::new(&props).with_schema(schema);
+
+        let result = UnionFieldsFunc::new()
+            .simplify(vec![col("u")], &context)
+            .unwrap();
+        let ExprSimplifyResult::Simplified(expr) = result else {
+            panic!("Expected ExprSimplifyResult::Simplified")
+        };
+
+        let expected = ScalarValue::List(ScalarValue::new_list_nullable(
+            &[ScalarValue::from("str"), ScalarValue::from("int")],
+            &DataType::Utf8,
+        ));
+        assert_eq!(expr, lit(expected));
+    }
+}
@@ -0,0 +1,201 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use arrow::array::{AsArray, Int8Array};
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, Result, ScalarValue};
+use datafusion_doc::Documentation;
+use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Union Functions"),
+    description = "Returns the type id of the currently selected field in the union",
+    syntax_example = "union_type_id(union_expression)",
+    sql_example = r#"```sql
+❯ select union_column, union_type_id(union_column) from table_with_union;
++--------------+-----------------------------+
+| union_column | union_type_id(union_column) |
++--------------+-----------------------------+
+| {a=1}        | 0                           |
+| {b=3.0}      | 1                           |
+| {a=4}        | 0                           |
+| {b=}         | 1                           |
+| {a=}         | 0                           |
++--------------+-----------------------------+
+```"#,
+    standard_argument(name = "union", prefix = "Union")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct UnionTypeIdFunc {
+    signature: Signature,
+}
+
+impl Default for UnionTypeIdFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UnionTypeIdFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for UnionTypeIdFunc {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "union_type_id"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Int8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [union_] = take_function_args("union_type_id", args.args)?;
+
+        match union_ {
+            ColumnarValue::Array(array)
+                if matches!(array.data_type(), DataType::Union(_, _)) =>
+            {
+                // the type ids of dense and sparse unions are both one per row
+                let type_ids = array.as_union().type_ids().clone();
+                Ok(ColumnarValue::Array(Arc::new(Int8Array::try_new(
+                    type_ids, None,
+                )?)))
+            }
+            ColumnarValue::Scalar(ScalarValue::Union(value, _, _)) => {
+                Ok(ColumnarValue::Scalar(ScalarValue::Int8(
+                    value.map(|(type_id, _)| type_id),
+                )))
+            }
+            v => exec_err!("union_type_id only support unions, got {:?}", v.data_type()),
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionTypeIdFunc;
+    use arrow::array::{
+        Array, ArrayRef, Float64Array, Int32Array, Int8Array, UnionArray,
+    };
+    use arrow::buffer::ScalarBuffer;
+    use arrow::datatypes::{DataType, Field, UnionFields, UnionMode};
+    use datafusion_common::config::ConfigOptions;
+    use datafusion_common::ScalarValue;
+    use datafusion_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl};
+    use std::sync::Arc;
+
+    fn union_fields() -> UnionFields {
+        UnionFields::new(
+            vec![2, 5],
+            vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Float64, false),
+            ],
+        )
+    }
+
+    fn invoke(arg: ColumnarValue) -> ColumnarValue {
+        UnionTypeIdFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args: vec![arg],
+                number_rows: 1,
+                return_field: Field::new("res", DataType::Int8, true).into(),
+                arg_fields: vec![],
+                config_options: Arc::new(ConfigOptions::default()),
+            })
+            .unwrap()
+    }
+
+    fn assert_type_ids(value: ColumnarValue, expected: Vec<i8>) {
+        match value {
+            ColumnarValue::Array(array) => {
+                assert_eq!(array.as_ref(), &Int8Array::from(expected) as &dyn Array)
+            }
+            ColumnarValue::Scalar(scalar) => panic!("expected array got {scalar:?}"),
+        }
+    }
+
+    #[test]
+    fn dense_union() {
+        let union = UnionArray::try_new(
+            union_fields(),
+            ScalarBuffer::from(vec![2, 5, 5, 2]),
+            Some(ScalarBuffer::from(vec![0, 0, 1, 1])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![3.0, 4.0])),
+            ],
+        )
+        .unwrap();
+
+        let result = invoke(ColumnarValue::Array(Arc::new(union)));
+        assert_type_ids(result, vec![2, 5, 5, 2]);
+    }
+
+    #[test]
+    fn sparse_union() {
+        let union = UnionArray::try_new(
+            union_fields(),
+            ScalarBuffer::from(vec![5, 2, 5]),
+            None,
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![4.0, 5.0, 6.0])),
+            ],
+        )
+        .unwrap();
+
+        let result = invoke(ColumnarValue::Array(Arc::new(union)));
+        assert_type_ids(result, vec![5, 2, 5]);
+    }
+
+    #[test]
+    fn union_scalar() {
+        let scalar = ScalarValue::Union(
+            Some((5, Box::new(ScalarValue::Float64(Some(1.0))))),
+            union_fields(),
+            UnionMode::Dense,
+        );
+
+        match invoke(ColumnarValue::Scalar(scalar)) {
+            ColumnarValue::Scalar(scalar) => {
+                assert_eq!(scalar, ScalarValue::Int8(Some(5)))
+            }
+            ColumnarValue::Array(array) => panic!("expected scalar got {array:?}"),
+        }
+    }
+}
@@ -0,0 +1,238 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use arrow::array::{Array, AsArray, DictionaryArray, Int8Array, StringArray};
+use arrow::datatypes::{DataType, UnionFields};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_datafusion_err, exec_err, Result, ScalarValue};
+use datafusion_doc::Documentation;
+use datafusion_expr::{ColumnarValue, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Union Functions"),
+    description = "Returns the Arrow data type of the currently selected field in the union, in the format returned by `arrow_typeof`",
+    syntax_example = "union_value_type(union_expression)",
+    sql_example = r#"```sql
+❯ select union_column, union_value_type(union_column) from table_with_union;
++--------------+--------------------------------+
+| union_column | union_value_type(union_column) |
++--------------+--------------------------------+
+| {a=1}        | Int32                          |
+| {b=3.0}      | Float64                        |
+| {a=4}        | Int32                          |
+| {b=}         | Float64                        |
+| {a=}         | Int32                          |
++--------------+--------------------------------+
+```"#,
+    standard_argument(name = "union", prefix = "Union")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct UnionValueTypeFunc {
+    signature: Signature,
+}
+
+impl Default for UnionValueTypeFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UnionValueTypeFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for UnionValueTypeFunc {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "union_value_type"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Dictionary(
+            Box::new(DataType::Int8),
+            Box::new(DataType::Utf8),
+        ))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [union_] = take_function_args("union_value_type", args.args)?;
+
+        match union_ {
+            ColumnarValue::Array(array)
+                if matches!(array.data_type(), DataType::Union(_, _)) =>
+            {
+                let union_array = array.as_union();
+
+                let keys = Int8Array::try_new(union_array.type_ids().clone(), None)?;
+
+                let fields = match union_array.data_type() {
+                    DataType::Union(fields, _) => fields,
+                    _ => unreachable!(),
+                };
+                let values = Arc::new(member_types(fields));
+
+                // SAFETY: union type_ids are validated to not be smaller than zero.
+                // `member_types` has a value for every type id up to the biggest one,
+                // therefore, `keys[i] >= values.len() || keys[i] < 0` never occurs
+                let dict = unsafe { DictionaryArray::new_unchecked(keys, values) };
+
+                Ok(ColumnarValue::Array(Arc::new(dict)))
+            }
+            ColumnarValue::Scalar(ScalarValue::Union(value, fields, _)) => match value {
+                Some((value_type_id, _)) => fields
+                    .iter()
+                    .find(|(type_id, _)| value_type_id == *type_id)
+                    .map(|(_, field)| {
+                        ColumnarValue::Scalar(ScalarValue::Dictionary(
+                            Box::new(DataType::Int8),
+                            Box::new(field.data_type().to_string().into()),
+                        ))
+                    })
+                    .ok_or_else(|| {
+                        exec_datafusion_err!(
+                            "union_value_type: union scalar with unknown type_id {value_type_id}"
+                        )
+                    }),
+                None => Ok(ColumnarValue::Scalar(ScalarValue::try_new_null(
+                    args.return_field.data_type(),
+                )?)),
+            },
+            v => exec_err!(
+                "union_value_type only support unions, got {:?}",
+                v.data_type()
+            ),
+        }
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Returns the data types of the union fields as strings, at the index of
+/// their type id like the values of [`UnionTagFunc`](super::union_tag::UnionTagFunc)
+fn member_types(fields: &UnionFields) -> StringArray {
+    let values_len = fields
+        .iter()
+        .map(|(type_id, _)| type_id + 1)
+        .max()
+        .unwrap_or_default() as usize;
+
+    let mut values = vec![String::new(); values_len];
+    for (type_id, field) in fields.iter() {
+        values[type_id as usize] = field.data_type().to_string();
+    }
+
+    StringArray::from(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionValueTypeFunc;
+    use arrow::array::{
+        ArrayRef, AsArray, Float64Array, Int32Array, StringArray, UnionArray,
+    };
+    use arrow::buffer::ScalarBuffer;
+    use arrow::datatypes::{DataType, Field, UnionFields, UnionMode};
+    use datafusion_common::config::ConfigOptions;
+    use datafusion_common::ScalarValue;
+    use datafusion_expr::{ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl};
+    use std::sync::Arc;
+
+    fn union_fields() -> UnionFields {
+        UnionFields::new(
+            vec![2, 5],
+            vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Float64, false),
+            ],
+        )
+    }
+
+    fn invoke(arg: ColumnarValue) -> ColumnarValue {
+        let return_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+
+        UnionValueTypeFunc::new()
+            .invoke_with_args(ScalarFunctionArgs {
+                args: vec![arg],
+                number_rows: 1,
+                return_field: Field::new("res", return_type, true).into(),
+                arg_fields: vec![],
+                config_options: Arc::new(ConfigOptions::default()),
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn dense_union() {
+        let union = UnionArray::try_new(
+            union_fields(),
+            ScalarBuffer::from(vec![2, 5, 5, 2]),
+            Some(ScalarBuffer::from(vec![0, 0, 1, 1])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])) as ArrayRef,
+                Arc::new(Float64Array::from(vec![3.0, 4.0])),
+            ],
+        )
+        .unwrap();
+
+        let ColumnarValue::Array(result) = invoke(ColumnarValue::Array(Arc::new(union)))
+        else {
+            panic!("expected array");
+        };
+        let types = arrow::compute::cast(&result, &DataType::Utf8).unwrap();
+        assert_eq!(
+            types.as_string::<i32>(),
+            &StringArray::from(vec!["Int32", "Float64", "Float64", "Int32"])
+        );
+    }
+
+    #[test]
+    fn union_scalar() {
+        let scalar = ScalarValue::Union(
+            Some((5, Box::new(ScalarValue::Float64(Some(1.0))))),
+            union_fields(),
+            UnionMode::Dense,
+        );
+
+        match invoke(ColumnarValue::Scalar(scalar)) {
+            ColumnarValue::Scalar(scalar) => assert_eq!(
+                scalar,
+                ScalarValue::Dictionary(
+                    Box::new(DataType::Int8),
+                    Box::new("Float64".into())
+                )
+            ),
+            ColumnarValue::Array(array) => panic!("expected scalar got {array:?}"),
+        }
+    }
+}
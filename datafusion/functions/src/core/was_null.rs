@@ -0,0 +1,114 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`WasNullFunc`]: Implementation of the `was_null` function
+
+use arrow::compute::is_null;
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::{utils::take_function_args, Result, ScalarValue};
+use datafusion_expr::simplify::{ExprSimplifyResult, SimplifyInfo};
+use datafusion_expr::{
+    ColumnarValue, Documentation, Expr, ReturnFieldArgs, ScalarFunctionArgs,
+};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+#[user_doc(
+    doc_section(label = "Conditional Functions"),
+    description = "Returns true if the expression is _null_, and false otherwise, like `expression IS NULL`. It is the flag [coalesce_flagged](#coalesce_flagged) returns with the value replacing the _null_ values.",
+    syntax_example = "was_null(expression)",
+    sql_example = r#"```sql
+> select was_null(null), was_null('datafusion');
++----------------+------------------------------+
+| was_null(NULL) | was_null(Utf8("datafusion")) |
++----------------+------------------------------+
+| true           | false                        |
++----------------+------------------------------+
+```"#,
+    argument(
+        name = "expression",
+        description = "Expression to test for _null_. Can be a constant, column, or function, and any combination of operators."
+    )
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct WasNullFunc {
+    signature: Signature,
+}
+
+impl Default for WasNullFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WasNullFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for WasNullFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "was_null"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn return_field_from_args(&self, _args: ReturnFieldArgs) -> Result<FieldRef> {
+        Ok(Field::new(self.name(), DataType::Boolean, false).into())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [arg] = take_function_args(self.name(), args.args)?;
+        match arg {
+            ColumnarValue::Scalar(value) => Ok(ColumnarValue::Scalar(
+                ScalarValue::Boolean(Some(value.is_null())),
+            )),
+            ColumnarValue::Array(array) => {
+                Ok(ColumnarValue::Array(Arc::new(is_null(&array)?)))
+            }
+        }
+    }
+
+    /// `was_null(expr)` is planned as `expr IS NULL`
+    fn simplify(
+        &self,
+        args: Vec<Expr>,
+        _info: &dyn SimplifyInfo,
+    ) -> Result<ExprSimplifyResult> {
+        let [arg] = take_function_args(self.name(), args)?;
+        Ok(ExprSimplifyResult::Simplified(arg.is_null()))
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
@@ -32,6 +32,7 @@ pub mod log;
 pub mod monotonicity;
 pub mod nans;
 pub mod nanvl;
+pub mod normalize_float;
 pub mod pi;
 pub mod power;
 pub mod random;
@@ -184,6 +185,7 @@ make_math_unary_udf!(
     super::get_log10_doc
 );
 make_udf_function!(nanvl::NanvlFunc, nanvl);
+make_udf_function!(normalize_float::NormalizeFloatFunc, normalize_float);
 make_udf_function!(pi::PiFunc, pi);
 make_udf_function!(power::PowerFunc, power);
 make_math_unary_udf!(
@@ -267,6 +269,7 @@ pub mod expr_fn {
         (log2, "base 2 logarithm of a number", num),
         (log10, "base 10 logarithm of a number", num),
         (nanvl, "returns x if x is not NaN otherwise returns y", x y),
+        (normalize_float, "returns the number with -0.0 replaced by 0.0 and every NaN replaced by the same NaN", num),
         (pi, "Returns an approximate value of π",),
         (power, "`base` raised to the power of `exponent`", base exponent),
         (radians, "converts degrees to radians", num),
@@ -311,6 +314,7 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         log2(),
         log10(),
         nanvl(),
+        normalize_float(),
         pi(),
         power(),
         radians(),
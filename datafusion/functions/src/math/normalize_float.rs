@@ -0,0 +1,205 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, AsArray};
+use arrow::datatypes::DataType::{Float32, Float64};
+use arrow::datatypes::{DataType, Float32Type, Float64Type};
+
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::TypeSignature::Exact;
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+use datafusion_macros::user_doc;
+
+use crate::utils::make_scalar_function;
+
+#[user_doc(
+    doc_section(label = "Math Functions"),
+    description = "Returns the number with -0.0 replaced by 0.0 and every _NaN_ replaced by the same _NaN_, so that values which are numerically the same are also equal when compared, grouped or hashed.",
+    syntax_example = "normalize_float(numeric_expression)",
+    standard_argument(name = "numeric_expression", prefix = "Numeric")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct NormalizeFloatFunc {
+    signature: Signature,
+}
+
+impl Default for NormalizeFloatFunc {
+    fn default() -> Self {
+        NormalizeFloatFunc::new()
+    }
+}
+
+impl NormalizeFloatFunc {
+    pub fn new() -> Self {
+        use DataType::*;
+        Self {
+            signature: Signature::one_of(
+                vec![Exact(vec![Float32]), Exact(vec![Float64])],
+                Volatility::Immutable,
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for NormalizeFloatFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "normalize_float"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match &arg_types[0] {
+            Float32 => Ok(Float32),
+            _ => Ok(Float64),
+        }
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(normalize_float, vec![])(&args.args)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Normalize_float SQL function
+pub fn normalize_float(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args[0].data_type() {
+        Float64 => Ok(Arc::new(
+            args[0]
+                .as_primitive::<Float64Type>()
+                .unary::<_, Float64Type>(|x| {
+                    if x.is_nan() {
+                        f64::NAN
+                    } else if x == 0.0 {
+                        // -0.0 == 0.0
+                        0.0
+                    } else {
+                        x
+                    }
+                }),
+        ) as ArrayRef),
+
+        Float32 => Ok(Arc::new(
+            args[0]
+                .as_primitive::<Float32Type>()
+                .unary::<_, Float32Type>(|x| {
+                    if x.is_nan() {
+                        f32::NAN
+                    } else if x == 0.0 {
+                        0.0
+                    } else {
+                        x
+                    }
+                }),
+        ) as ArrayRef),
+
+        other => {
+            exec_err!("Unsupported data type {other:?} for function normalize_float")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use arrow::array::{ArrayRef, Float32Array, Float64Array};
+
+    use datafusion_common::cast::{as_float32_array, as_float64_array};
+
+    use crate::math::normalize_float::normalize_float;
+
+    #[test]
+    fn test_normalize_float_f64() {
+        let other_nan = f64::from_bits(f64::NAN.to_bits() | 1);
+        assert_ne!(other_nan.to_bits(), f64::NAN.to_bits());
+        let args: Vec<ArrayRef> = vec![Arc::new(Float64Array::from(vec![
+            1.5,
+            -0.0,
+            0.0,
+            f64::NAN,
+            other_nan,
+            -f64::NAN,
+        ]))];
+
+        let result = normalize_float(&args).expect("failed to normalize floats");
+        let floats = as_float64_array(&result).expect("failed to normalize floats");
+
+        let bits = floats
+            .values()
+            .iter()
+            .map(|x| x.to_bits())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bits,
+            vec![
+                1.5_f64.to_bits(),
+                0.0_f64.to_bits(),
+                0.0_f64.to_bits(),
+                f64::NAN.to_bits(),
+                f64::NAN.to_bits(),
+                f64::NAN.to_bits(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_normalize_float_f32() {
+        let other_nan = f32::from_bits(f32::NAN.to_bits() | 1);
+        let args: Vec<ArrayRef> = vec![Arc::new(Float32Array::from(vec![
+            1.5,
+            -0.0,
+            f32::NAN,
+            other_nan,
+            -f32::NAN,
+        ]))];
+
+        let result = normalize_float(&args).expect("failed to normalize floats");
+        let floats = as_float32_array(&result).expect("failed to normalize floats");
+
+        let bits = floats
+            .values()
+            .iter()
+            .map(|x| x.to_bits())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bits,
+            vec![
+                1.5_f32.to_bits(),
+                0.0_f32.to_bits(),
+                f32::NAN.to_bits(),
+                f32::NAN.to_bits(),
+                f32::NAN.to_bits(),
+            ]
+        );
+    }
+}
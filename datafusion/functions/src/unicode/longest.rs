@@ -0,0 +1,250 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! [`LongestFunc`]: Implementation of the `longest` function
+
+use arrow::array::{new_null_array, Array, ArrayRef, AsArray, StringArrayType};
+use arrow::compute::interleave;
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::binary::try_type_union_resolution;
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use itertools::Itertools;
+use std::any::Any;
+use std::iter;
+
+#[user_doc(
+    doc_section(label = "String Functions"),
+    description = "Returns the argument with the most characters, skipping _null_ arguments. Returns the first of them if several arguments have the most characters, and _null_ if all arguments are _null_.",
+    syntax_example = "longest(str1[, ..., str_n])",
+    sql_example = r#"```sql
+> select longest('data', null, 'fusion', 'ångström');
++------------------------------------------------------------+
+| longest(Utf8("data"),NULL,Utf8("fusion"),Utf8("ångström")) |
++------------------------------------------------------------+
+| ångström                                                   |
++------------------------------------------------------------+
+```"#,
+    argument(
+        name = "str1, str_n",
+        description = "String expression to compare. Can be a constant, column, or function, and any combination of string operators. Pass as many expression arguments as necessary."
+    ),
+    related_udf(name = "shortest"),
+    related_udf(name = "character_length")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct LongestFunc {
+    signature: Signature,
+}
+
+impl Default for LongestFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LongestFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for LongestFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "longest"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        select_by_char_length(self.name(), args, |length, best| length > best)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_string_types(self.name(), arg_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Coerces all arguments of `longest` or `shortest` to a single string type
+pub(super) fn coerce_string_types(
+    name: &str,
+    arg_types: &[DataType],
+) -> Result<Vec<DataType>> {
+    if arg_types.is_empty() {
+        return plan_err!("{name} must have at least one argument");
+    }
+
+    let coerced_types = try_type_union_resolution(arg_types)?;
+    match &coerced_types[0] {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => Ok(coerced_types),
+        DataType::Null => Ok(vec![DataType::Utf8; arg_types.len()]),
+        _ => plan_err!(
+            "{name} requires string arguments, got {}",
+            arg_types.iter().join(", ")
+        ),
+    }
+}
+
+/// Returns per row the first argument that is not null and whose character
+/// length is preferred by `is_better(length, best_length)` over the ones of
+/// all the previous arguments, or null if all arguments are null
+pub(super) fn select_by_char_length(
+    name: &str,
+    args: ScalarFunctionArgs,
+    is_better: fn(usize, usize) -> bool,
+) -> Result<ColumnarValue> {
+    if args.args.is_empty() {
+        return exec_err!("{name} was called without arguments. It requires at least 1.");
+    }
+    let is_scalar = args
+        .args
+        .iter()
+        .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
+
+    let arrays = ColumnarValue::values_to_arrays(&args.args)?;
+    let lengths = arrays
+        .iter()
+        .map(|array| char_lengths(name, array))
+        .collect::<Result<Vec<_>>>()?;
+
+    // index 0 of `sources` is a null value, for rows where all arguments are null
+    let nulls = new_null_array(args.return_field.data_type(), 1);
+    let sources = iter::once(&nulls)
+        .chain(&arrays)
+        .map(|array| array.as_ref())
+        .collect::<Vec<&dyn Array>>();
+
+    let indices = (0..arrays[0].len())
+        .map(|row| {
+            let mut best: Option<(usize, usize)> = None;
+            for (i, lengths) in lengths.iter().enumerate() {
+                let Some(length) = lengths[row] else {
+                    continue;
+                };
+                if best.is_none_or(|(_, best_length)| is_better(length, best_length)) {
+                    best = Some((i, length));
+                }
+            }
+            best.map_or((0, 0), |(i, _)| (i + 1, row))
+        })
+        .collect::<Vec<_>>();
+
+    let result: ArrayRef = interleave(&sources, &indices)?;
+    if is_scalar {
+        Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(
+            &result, 0,
+        )?))
+    } else {
+        Ok(ColumnarValue::Array(result))
+    }
+}
+
+/// Returns the number of characters of every value of the string `array`
+fn char_lengths(name: &str, array: &ArrayRef) -> Result<Vec<Option<usize>>> {
+    fn count<'a>(array: impl StringArrayType<'a>) -> Vec<Option<usize>> {
+        array
+            .iter()
+            .map(|value| value.map(|value| value.chars().count()))
+            .collect()
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => Ok(count(array.as_string::<i32>())),
+        DataType::LargeUtf8 => Ok(count(array.as_string::<i64>())),
+        DataType::Utf8View => Ok(count(array.as_string_view())),
+        other => exec_err!("{name} does not support type {other}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unicode::shortest::ShortestFunc;
+    use arrow::array::StringArray;
+    use arrow::datatypes::Field;
+    use datafusion_common::config::ConfigOptions;
+    use std::sync::Arc;
+
+    fn invoke(func: &dyn ScalarUDFImpl, args: Vec<ColumnarValue>) -> Result<ArrayRef> {
+        let arg_fields = args
+            .iter()
+            .map(|arg| Field::new("a", arg.data_type(), true).into())
+            .collect::<Vec<_>>();
+        func.invoke_with_args(ScalarFunctionArgs {
+            args,
+            arg_fields,
+            number_rows: 4,
+            return_field: Field::new("f", DataType::Utf8, true).into(),
+            config_options: Arc::new(ConfigOptions::default()),
+        })?
+        .into_array(4)
+    }
+
+    #[test]
+    fn test_longest_and_shortest() -> Result<()> {
+        let args = || {
+            vec![
+                ColumnarValue::Array(Arc::new(StringArray::from(vec![
+                    Some("ab"),
+                    Some("ångström"),
+                    None,
+                    None,
+                ]))),
+                ColumnarValue::Scalar(ScalarValue::Utf8(Some("cd".to_string()))),
+                ColumnarValue::Array(Arc::new(StringArray::from(vec![
+                    Some("e"),
+                    Some("abcdefghi"),
+                    Some("x"),
+                    None,
+                ]))),
+            ]
+        };
+
+        let longest = invoke(&LongestFunc::new(), args())?;
+        let expected = StringArray::from(vec![
+            Some("ab"),
+            Some("abcdefghi"),
+            Some("cd"),
+            Some("cd"),
+        ]);
+        assert_eq!(longest.as_string::<i32>(), &expected);
+
+        let shortest = invoke(&ShortestFunc::new(), args())?;
+        let expected =
+            StringArray::from(vec![Some("e"), Some("cd"), Some("x"), Some("cd")]);
+        assert_eq!(shortest.as_string::<i32>(), &expected);
+
+        Ok(())
+    }
+}
@@ -25,11 +25,13 @@ pub mod character_length;
 pub mod find_in_set;
 pub mod initcap;
 pub mod left;
+pub mod longest;
 pub mod lpad;
 pub mod planner;
 pub mod reverse;
 pub mod right;
 pub mod rpad;
+pub mod shortest;
 pub mod strpos;
 pub mod substr;
 pub mod substrindex;
@@ -40,10 +42,12 @@ make_udf_function!(character_length::CharacterLengthFunc, character_length);
 make_udf_function!(find_in_set::FindInSetFunc, find_in_set);
 make_udf_function!(initcap::InitcapFunc, initcap);
 make_udf_function!(left::LeftFunc, left);
+make_udf_function!(longest::LongestFunc, longest);
 make_udf_function!(lpad::LPadFunc, lpad);
 make_udf_function!(right::RightFunc, right);
 make_udf_function!(reverse::ReverseFunc, reverse);
 make_udf_function!(rpad::RPadFunc, rpad);
+make_udf_function!(shortest::ShortestFunc, shortest);
 make_udf_function!(strpos::StrposFunc, strpos);
 make_udf_function!(substr::SubstrFunc, substr);
 make_udf_function!(substr::SubstrFunc, substring);
@@ -105,6 +109,14 @@ pub mod expr_fn {
         find_in_set,
         "Returns a value in the range of 1 to N if the string `str` is in the string list `strlist` consisting of N substrings",
         string strlist
+    ),(
+        longest,
+        "Returns the argument with the most characters, skipping nulls",
+        args,
+    ),(
+        shortest,
+        "Returns the argument with the fewest characters, skipping nulls",
+        args,
     ));
 
     #[doc = "the number of characters in the `string`"]
@@ -135,10 +147,12 @@ pub fn functions() -> Vec<Arc<ScalarUDF>> {
         find_in_set(),
         initcap(),
         left(),
+        longest(),
         lpad(),
         reverse(),
         right(),
         rpad(),
+        shortest(),
         strpos(),
         substr(),
         substr_index(),
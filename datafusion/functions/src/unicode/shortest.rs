@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! [`ShortestFunc`]: Implementation of the `shortest` function
+
+use super::longest::{coerce_string_types, select_by_char_length};
+use arrow::datatypes::DataType;
+use datafusion_common::Result;
+use datafusion_expr::{ColumnarValue, Documentation, ScalarFunctionArgs};
+use datafusion_expr::{ScalarUDFImpl, Signature, Volatility};
+use datafusion_macros::user_doc;
+use std::any::Any;
+
+#[user_doc(
+    doc_section(label = "String Functions"),
+    description = "Returns the argument with the fewest characters, skipping _null_ arguments. Returns the first of them if several arguments have the fewest characters, and _null_ if all arguments are _null_.",
+    syntax_example = "shortest(str1[, ..., str_n])",
+    sql_example = r#"```sql
+> select shortest('data', null, 'fusion', 'ångström');
++-------------------------------------------------------------+
+| shortest(Utf8("data"),NULL,Utf8("fusion"),Utf8("ångström")) |
++-------------------------------------------------------------+
+| data                                                        |
++-------------------------------------------------------------+
+```"#,
+    argument(
+        name = "str1, str_n",
+        description = "String expression to compare. Can be a constant, column, or function, and any combination of string operators. Pass as many expression arguments as necessary."
+    ),
+    related_udf(name = "longest"),
+    related_udf(name = "character_length")
+)]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ShortestFunc {
+    signature: Signature,
+}
+
+impl Default for ShortestFunc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShortestFunc {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for ShortestFunc {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "shortest"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        select_by_char_length(self.name(), args, |length, best| length < best)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_string_types(self.name(), arg_types)
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
@@ -33,8 +33,8 @@ use datafusion_common::{
     DFSchema, DFSchemaRef, DataFusionError, Result, ScalarValue, TableReference,
 };
 use datafusion_expr::expr::{
-    self, AggregateFunctionParams, Alias, Between, BinaryExpr, Case, Exists, InList,
-    InSubquery, Like, ScalarFunction, Sort, WindowFunction,
+    self, AggregateFunctionParams, Alias, Between, BinaryExpr, Case, Cast, Exists,
+    InList, InSubquery, Like, ScalarFunction, Sort, WindowFunction,
 };
 use datafusion_expr::expr_rewriter::coerce_plan_expr_for_schema;
 use datafusion_expr::expr_schema::cast_subquery;
@@ -307,6 +307,36 @@ impl<'a> TypeCoercionRewriter<'a> {
 impl TreeNodeRewriter for TypeCoercionRewriter<'_> {
     type Node = Expr;
 
+    fn f_down(&mut self, expr: Expr) -> Result<Transformed<Expr>> {
+        let Expr::Cast(Cast {
+            expr: inner,
+            data_type,
+        }) = &expr
+        else {
+            return Ok(Transformed::no(expr));
+        };
+        let Some(arg_types) = coerce_types_for_cast(inner, data_type, self.schema)?
+        else {
+            return Ok(Transformed::no(expr));
+        };
+
+        let Expr::Cast(Cast { expr, data_type }) = expr else {
+            return internal_err!("Expect cast expression");
+        };
+        let Expr::ScalarFunction(ScalarFunction { func, args }) = *expr else {
+            return internal_err!("Expect scalar function");
+        };
+        let args = args
+            .into_iter()
+            .zip(arg_types)
+            .map(|(arg, arg_type)| arg.cast_to(&arg_type, self.schema))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Transformed::yes(Expr::Cast(Cast::new(
+            Box::new(Expr::ScalarFunction(ScalarFunction::new_udf(func, args))),
+            data_type,
+        ))))
+    }
+
     fn f_up(&mut self, expr: Expr) -> Result<Transformed<Expr>> {
         match expr {
             Expr::Unnest(_) => not_impl_err!(
@@ -785,6 +815,31 @@ fn get_casted_expr_for_bool_op(expr: Expr, schema: &DFSchema) -> Result<Expr> {
     expr.cast_to(&DataType::Boolean, schema)
 }
 
+/// Returns the types the arguments of `expr` are coerced to when it is a call
+/// to a scalar function which takes the outer cast to `cast_type` as a hint,
+/// see [`ScalarUDFImpl::coerce_types_for_cast`].
+///
+/// [`ScalarUDFImpl::coerce_types_for_cast`]: datafusion_expr::ScalarUDFImpl::coerce_types_for_cast
+fn coerce_types_for_cast(
+    expr: &Expr,
+    cast_type: &DataType,
+    schema: &DFSchema,
+) -> Result<Option<Vec<DataType>>> {
+    let Expr::ScalarFunction(ScalarFunction { func, args }) = expr else {
+        return Ok(None);
+    };
+    // the arguments are not coerced yet, leave the ones without a type to
+    // the usual coercion
+    let Some(arg_types) = args
+        .iter()
+        .map(|arg| arg.get_type(schema).ok())
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Ok(None);
+    };
+    func.coerce_types_for_cast(&arg_types, cast_type)
+}
+
 /// Returns `expressions` coerced to types compatible with
 /// `signature`, if possible.
 ///
@@ -29,7 +29,7 @@ use datafusion_common::format::DEFAULT_FORMAT_OPTIONS;
 use datafusion_common::{not_impl_err, Result};
 use datafusion_expr_common::columnar_value::ColumnarValue;
 use datafusion_expr_common::interval_arithmetic::Interval;
-use datafusion_expr_common::sort_properties::ExprProperties;
+use datafusion_expr_common::sort_properties::{cast_preserves_ordering, ExprProperties};
 
 const DEFAULT_CAST_OPTIONS: CastOptions<'static> = CastOptions {
     safe: false,
@@ -190,17 +190,13 @@ impl PhysicalExpr for CastExpr {
     }
 
     /// A [`CastExpr`] preserves the ordering of its child if the cast is done
-    /// under the same datatype family.
+    /// under the same datatype family, see [`cast_preserves_ordering`].
     fn get_properties(&self, children: &[ExprProperties]) -> Result<ExprProperties> {
         let source_datatype = children[0].range.data_type();
         let target_type = &self.cast_type;
 
         let unbounded = Interval::make_unbounded(target_type)?;
-        if (source_datatype.is_numeric() || source_datatype == Boolean)
-            && target_type.is_numeric()
-            || source_datatype.is_temporal() && target_type.is_temporal()
-            || source_datatype.eq(target_type)
-        {
+        if cast_preserves_ordering(&source_datatype, target_type) {
             Ok(children[0].clone().with_range(unbounded))
         } else {
             Ok(ExprProperties::new_unknown().with_range(unbounded))
@@ -254,6 +250,7 @@ mod tests {
 
     use crate::expressions::column::col;
 
+    use arrow::compute::SortOptions;
     use arrow::{
         array::{
             Array, Decimal128Array, Float32Array, Float64Array, Int16Array, Int32Array,
@@ -263,6 +260,7 @@ mod tests {
         datatypes::*,
     };
     use datafusion_common::assert_contains;
+    use datafusion_expr_common::sort_properties::SortProperties;
     use datafusion_physical_expr_common::physical_expr::fmt_sql;
 
     // runs an end-to-end test of physical type cast
@@ -809,4 +807,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_cast_dictionary_ordering() -> Result<()> {
+        let dictionary = |value: DataType| Dictionary(Box::new(Int32), Box::new(value));
+        let ordered = SortProperties::Ordered(SortOptions::default());
+        let cases = [
+            // decoding keeps the order of the values
+            (dictionary(Utf8), Utf8, ordered),
+            (dictionary(Int32), Int64, ordered),
+            // encoding keeps the order if the values are unchanged
+            (Utf8, dictionary(Utf8), ordered),
+            (Int32, dictionary(Int64), ordered),
+            (Int32, dictionary(Utf8), SortProperties::Unordered),
+            (
+                dictionary(Int32),
+                dictionary(Utf8),
+                SortProperties::Unordered,
+            ),
+        ];
+
+        let schema = Schema::new(vec![Field::new("a", Int32, true)]);
+        for (from, to, expected) in cases {
+            let expr = CastExpr::new(col("a", &schema)?, to.clone(), None);
+            let child = ExprProperties::new_unknown()
+                .with_order(ordered)
+                .with_range(Interval::make_unbounded(&from)?);
+            let properties = expr.get_properties(&[child])?;
+            assert_eq!(properties.sort_properties, expected, "{from} to {to}");
+        }
+
+        Ok(())
+    }
 }
@@ -855,6 +855,43 @@ async fn roundtrip_logical_plan_unnest() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn roundtrip_logical_plan_named_struct_field_metadata() -> Result<()> {
+    let ctx = SessionContext::new();
+    let metadata = HashMap::from([(String::from("k1"), String::from("v1"))]);
+    let schema = Schema::new(vec![
+        Field::new("a", DataType::Int64, false).with_metadata(metadata.clone()),
+        Field::new("b", DataType::Int64, true),
+    ]);
+    ctx.register_csv(
+        "t1",
+        "tests/testdata/test.csv",
+        CsvReadOptions::default().schema(&schema),
+    )
+    .await?;
+    let query = "SELECT named_struct('x', a, 'y', b) AS s, \
+        get_field(named_struct('x', a, 'y', b), 'x') AS x FROM t1";
+    let plan = ctx.sql(query).await?.into_optimized_plan()?;
+
+    // the metadata of `a` is kept by the struct field and the field taken out of it
+    let DataType::Struct(fields) = plan.schema().field(0).data_type() else {
+        panic!("expected a struct");
+    };
+    assert_eq!(fields[0].metadata(), &metadata);
+    assert_eq!(plan.schema().field(1).metadata(), &metadata);
+
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+    assert_eq!(plan.schema(), logical_round_trip.schema());
+
+    let schema_bytes = |plan: &LogicalPlan| -> Result<Vec<u8>> {
+        let schema: protobuf::DfSchema = plan.schema().try_into()?;
+        Ok(schema.encode_to_vec())
+    };
+    assert_eq!(schema_bytes(&plan)?, schema_bytes(&logical_round_trip)?);
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_expr_api() -> Result<()> {
     let ctx = SessionContext::new();
@@ -2608,6 +2645,77 @@ async fn roundtrip_recursive_query() {
     );
 }
 
+#[tokio::test]
+async fn roundtrip_greatest_least_null_policy() -> Result<()> {
+    let query = "SELECT least(column1, column2, nulls => 'first') AS l, \
+        greatest(column1, column2, nulls => 'last') AS g \
+        FROM (VALUES (1, NULL), (3, 4))";
+
+    let ctx = SessionContext::new();
+    let plan = ctx.sql(query).await?.into_unoptimized_plan();
+    let bytes = logical_plan_to_bytes(&plan)?;
+
+    // the policy is an argument of the functions, so it survives the round trip
+    let ctx = SessionContext::new();
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+    assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+    let output_round_trip = ctx
+        .execute_logical_plan(logical_round_trip)
+        .await?
+        .collect()
+        .await?;
+
+    let expected = [
+        "+---+---+",
+        "| l | g |",
+        "+---+---+",
+        "|   |   |",
+        "| 3 | 4 |",
+        "+---+---+",
+    ];
+    assert_eq!(
+        pretty_format_batches(&output_round_trip)?.to_string(),
+        expected.join("\n")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn roundtrip_arrow_cast_overflow_mode() -> Result<()> {
+    let query = "SELECT arrow_cast(column1, 'Int8', overflow => 'saturate') AS s, \
+        arrow_cast(column1, 'Int8', overflow => 'null') AS n \
+        FROM (VALUES (5), (300), (-300))";
+
+    let ctx = SessionContext::new();
+    let plan = ctx.sql(query).await?.into_unoptimized_plan();
+    let bytes = logical_plan_to_bytes(&plan)?;
+
+    // the mode is an argument of the function, so it survives the round trip
+    let ctx = SessionContext::new();
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+    assert_eq!(format!("{plan:?}"), format!("{logical_round_trip:?}"));
+    let output_round_trip = ctx
+        .execute_logical_plan(logical_round_trip)
+        .await?
+        .collect()
+        .await?;
+
+    let expected = [
+        "+------+---+",
+        "| s    | n |",
+        "+------+---+",
+        "| 5    | 5 |",
+        "| 127  |   |",
+        "| -128 |   |",
+        "+------+---+",
+    ];
+    assert_eq!(
+        pretty_format_batches(&output_round_trip)?.to_string(),
+        expected.join("\n")
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_union_query() -> Result<()> {
     let query = "SELECT a FROM t1
@@ -16,19 +16,45 @@
 // under the License.
 
 use datafusion_expr::ScalarUDF;
+use datafusion_functions::core::coalesce::{CoalesceCoercion, CoalesceFunc};
+use datafusion_functions::core::nvl::NVLFunc;
 use datafusion_functions::make_udf_function;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 
 mod r#if;
 
 make_udf_function!(r#if::SparkIf, r#if);
 
+/// Return a [`ScalarUDF`] implementation of coalesce, coercing numeric and
+/// temporal arguments mixed with strings to strings like Spark
+pub fn coalesce() -> Arc<ScalarUDF> {
+    static INSTANCE: LazyLock<Arc<ScalarUDF>> = LazyLock::new(|| {
+        Arc::new(ScalarUDF::new_from_impl(CoalesceFunc::new_with_coercion(
+            CoalesceCoercion::Spark,
+        )))
+    });
+    Arc::clone(&INSTANCE)
+}
+
+/// Return a [`ScalarUDF`] implementation of nvl and its alias ifnull, coercing
+/// numeric and temporal arguments mixed with strings to strings like Spark
+pub fn nvl() -> Arc<ScalarUDF> {
+    static INSTANCE: LazyLock<Arc<ScalarUDF>> = LazyLock::new(|| {
+        Arc::new(ScalarUDF::new_from_impl(NVLFunc::new_with_coercion(
+            CoalesceCoercion::Spark,
+        )))
+    });
+    Arc::clone(&INSTANCE)
+}
+
 pub mod expr_fn {
     use datafusion_functions::export_functions;
 
     export_functions!((r#if, "If arg1 evaluates to true, then returns arg2; otherwise returns arg3", arg1 arg2 arg3));
+    export_functions!((coalesce, "Returns the first of the arguments which is not null, coercing numbers mixed with strings to strings", args,));
+    export_functions!((nvl, "Returns arg2 if arg1 is null, otherwise arg1, coercing numbers mixed with strings to strings", arg1 arg2));
 }
 
 pub fn functions() -> Vec<Arc<ScalarUDF>> {
-    vec![r#if()]
+    vec![r#if(), coalesce(), nvl()]
 }
@@ -23,7 +23,9 @@ use datafusion_common::{
     DFSchema, Dependency, Diagnostic, Result, Span,
 };
 use datafusion_expr::expr::{ScalarFunction, Unnest, WildcardOptions};
-use datafusion_expr::planner::{PlannerResult, RawAggregateExpr, RawWindowExpr};
+use datafusion_expr::planner::{
+    PlannerResult, RawAggregateExpr, RawScalarFunctionExpr, RawWindowExpr,
+};
 use datafusion_expr::{
     expr, Expr, ExprFunctionExt, ExprSchemable, WindowFrame, WindowFunctionDefinition,
 };
@@ -269,7 +271,34 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         }
         // User-defined function (UDF) should have precedence
         if let Some(fm) = self.context_provider.get_function_meta(&name) {
+            let arg_names = args
+                .iter()
+                .map(|arg| match arg {
+                    FunctionArg::Named { name, .. } => {
+                        Some(crate::utils::normalize_ident(name.clone()))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
             let args = self.function_args_to_expr(args, schema, planner_context)?;
+
+            if arg_names.iter().any(Option::is_some) {
+                let mut expr = RawScalarFunctionExpr {
+                    func: fm,
+                    args,
+                    arg_names,
+                };
+                for planner in self.context_provider.get_expr_planners() {
+                    match planner.plan_named_arguments(expr)? {
+                        PlannerResult::Planned(expr) => return Ok(expr),
+                        PlannerResult::Original(original) => expr = original,
+                    }
+                }
+                return Ok(Expr::ScalarFunction(ScalarFunction::new_udf(
+                    expr.func, expr.args,
+                )));
+            }
+
             return Ok(Expr::ScalarFunction(ScalarFunction::new_udf(fm, args)));
         }
 